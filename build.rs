@@ -1,6 +1,180 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+/// Environment variables naming a CUDA toolkit root
+const ROOT_VARS: &[&str] = &["CUDA_PATH", "CUDA_HOME"];
+
 fn main() {
-    println!("cargo:rustc-link-lib=dylib=cuda");
-    let cuda_path = std::env::var("CUDA_LIB_PATH")
-        .unwrap_or_else(|_| "/usr/local/cuda-11.3/lib64/".to_string());
-    println!("cargo:rustc-link-search=native={}", cuda_path);
+    println!("cargo:rerun-if-changed=build.rs");
+    for var in ["CUDA_LIB_PATH", "CUDA_OXIDE_STUB", "NVCC", "DOCS_RS"]
+        .iter()
+        .chain(ROOT_VARS)
+    {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+
+    // the driver library is opened at runtime instead
+    if env::var_os("CARGO_FEATURE_DYNAMIC_LOADING").is_some() {
+        return;
+    }
+
+    // docs.rs and CI machines without CUDA can build the crate without linking the driver.
+    // Anything linking the final binary, i.e. tests, still needs the driver library.
+    if env::var_os("DOCS_RS").is_some() || env::var_os("CUDA_OXIDE_STUB").is_some() {
+        return;
+    }
+
+    let windows = env::var("CARGO_CFG_TARGET_OS").is_ok_and(|x| x == "windows");
+    let library = if windows { "cuda.lib" } else { "libcuda.so" };
+
+    let mut searched: Vec<(String, Vec<PathBuf>)> = vec![];
+    for (source, dirs) in candidates(windows) {
+        for dir in &dirs {
+            if dir.join(library).is_file() {
+                println!("cargo:rustc-link-search=native={}", dir.display());
+                println!("cargo:rustc-link-lib=dylib=cuda");
+                return;
+            }
+            // drivers installed without development files only ship the versioned library
+            if !windows && dir.join("libcuda.so.1").is_file() {
+                println!("cargo:rustc-link-search=native={}", dir.display());
+                println!("cargo:rustc-link-lib=dylib:+verbatim=libcuda.so.1");
+                return;
+            }
+        }
+        searched.push((source, dirs));
+    }
+
+    eprintln!(
+        "error: could not find the CUDA driver library ({}), searched:",
+        library
+    );
+    for (source, dirs) in searched {
+        if dirs.is_empty() {
+            let missing = if source == "nvcc" {
+                "not found"
+            } else {
+                "not set"
+            };
+            eprintln!("  {}: {}", source, missing);
+        } else {
+            eprintln!("  {}:", source);
+            for dir in dirs {
+                eprintln!("    {}", dir.display());
+            }
+        }
+    }
+    eprintln!(
+        "set CUDA_LIB_PATH to the directory containing {}, or set CUDA_OXIDE_STUB=1 to build without linking it (i.e. for docs or CI without a GPU)",
+        library
+    );
+    std::process::exit(1);
+}
+
+/// Lists the directories to search for the driver library, in order, with where they came from
+fn candidates(windows: bool) -> Vec<(String, Vec<PathBuf>)> {
+    let mut out = vec![];
+    out.push((
+        "CUDA_LIB_PATH".to_string(),
+        env::var_os("CUDA_LIB_PATH")
+            .map(|x| vec![PathBuf::from(x)])
+            .unwrap_or_default(),
+    ));
+    for var in ROOT_VARS {
+        out.push((
+            var.to_string(),
+            env::var_os(var)
+                .map(|x| library_dirs(Path::new(&x), windows))
+                .unwrap_or_default(),
+        ));
+    }
+    out.push((
+        "nvcc".to_string(),
+        find_nvcc()
+            .and_then(|nvcc| Some(library_dirs(nvcc.parent()?.parent()?, windows)))
+            .unwrap_or_default(),
+    ));
+    if !windows {
+        out.push((
+            "/usr/local/cuda*".to_string(),
+            local_toolkits()
+                .iter()
+                .flat_map(|root| library_dirs(root, windows))
+                .collect(),
+        ));
+        let mut distro = vec![];
+        if let Some(triple) = multiarch_triple() {
+            distro.push(Path::new("/usr/lib").join(triple));
+        }
+        distro.push("/usr/lib64".into());
+        distro.push("/usr/lib".into());
+        // WSL exposes the Windows driver here
+        distro.push("/usr/lib/wsl/lib".into());
+        out.push(("distribution paths".to_string(), distro));
+    }
+    out
+}
+
+/// Gets the directories under a toolkit root that may hold the driver library, including the toolkit's link stubs
+fn library_dirs(root: &Path, windows: bool) -> Vec<PathBuf> {
+    if windows {
+        return vec![root.join("lib").join("x64")];
+    }
+    let mut dirs = vec![root.join("lib64"), root.join("lib")];
+    if let Ok(arch) = env::var("CARGO_CFG_TARGET_ARCH") {
+        dirs.push(
+            root.join("targets")
+                .join(format!("{}-linux", arch))
+                .join("lib"),
+        );
+    }
+    let stubs = dirs.iter().map(|x| x.join("stubs")).collect::<Vec<_>>();
+    dirs.extend(stubs);
+    dirs
+}
+
+/// Finds nvcc from `NVCC` or `PATH`
+fn find_nvcc() -> Option<PathBuf> {
+    if let Some(nvcc) = env::var_os("NVCC") {
+        return std::fs::canonicalize(nvcc).ok();
+    }
+    let name = if cfg!(windows) { "nvcc.exe" } else { "nvcc" };
+    env::split_paths(&env::var_os("PATH")?)
+        .map(|dir| dir.join(name))
+        .find(|x| x.is_file())
+        .and_then(|x| std::fs::canonicalize(x).ok())
+}
+
+/// Lists `/usr/local/cuda-X.Y` toolkits from the highest version, followed by `/usr/local/cuda`
+fn local_toolkits() -> Vec<PathBuf> {
+    let mut versioned: Vec<(Vec<u32>, PathBuf)> = std::fs::read_dir("/usr/local")
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let version = name.to_str()?.strip_prefix("cuda-")?;
+            let version = version
+                .split('.')
+                .map(|x| x.parse().ok())
+                .collect::<Option<Vec<u32>>>()?;
+            Some((version, entry.path()))
+        })
+        .collect();
+    versioned.sort_by(|a, b| b.0.cmp(&a.0));
+    let mut out: Vec<PathBuf> = versioned.into_iter().map(|x| x.1).collect();
+    out.push("/usr/local/cuda".into());
+    out
+}
+
+/// Gets the Debian multiarch directory name of the target, i.e. `x86_64-linux-gnu`
+fn multiarch_triple() -> Option<&'static str> {
+    Some(match &*env::var("CARGO_CFG_TARGET_ARCH").ok()? {
+        "x86_64" => "x86_64-linux-gnu",
+        "aarch64" => "aarch64-linux-gnu",
+        "powerpc64" => "powerpc64le-linux-gnu",
+        _ => return None,
+    })
 }
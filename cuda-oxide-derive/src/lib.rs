@@ -0,0 +1,253 @@
+//! Derive macros for `cuda-oxide`, re-exported by it with the `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Index, Meta, NestedMeta};
+
+/// Gets the idents inside every `#[repr(...)]` attribute
+fn reprs(input: &DeriveInput) -> Vec<String> {
+    let mut out = vec![];
+    for attr in &input.attrs {
+        if !attr.path.is_ident("repr") {
+            continue;
+        }
+        if let Ok(Meta::List(list)) = attr.parse_meta() {
+            for nested in list.nested {
+                if let NestedMeta::Meta(meta) = nested {
+                    if let Some(ident) = meta.path().get_ident() {
+                        out.push(ident.to_string());
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Derives `cuda_oxide::DeviceCopy`, marking a type as safe to bitwise copy to and from the device.
+/// Structs must be `#[repr(C)]` or `#[repr(transparent)]` so their layout matches the device side, and every field must be `DeviceCopy`.
+/// Enums and unions are rejected, as device memory is loaded back as is and not every bit pattern is a valid enum value.
+#[proc_macro_derive(DeviceCopy)]
+pub fn derive_device_copy(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match device_copy(input) {
+        Ok(x) => x.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn device_copy(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let reprs = reprs(&input);
+    let mut generics = input.generics.clone();
+    match &input.data {
+        Data::Struct(data) => {
+            if !reprs.iter().any(|x| x == "C" || x == "transparent") {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "DeviceCopy structs must be #[repr(C)] or #[repr(transparent)]",
+                ));
+            }
+            let where_clause = generics.make_where_clause();
+            for field in data.fields.iter() {
+                let ty = &field.ty;
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ty: ::cuda_oxide::DeviceCopy));
+            }
+        }
+        Data::Enum(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "DeviceCopy cannot be derived for enums, as not every bit pattern is a valid value",
+            ))
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "DeviceCopy cannot be derived for unions",
+            ))
+        }
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        unsafe impl #impl_generics ::cuda_oxide::DeviceCopy for #name #ty_generics #where_clause {}
+    })
+}
+
+/// Checks for `#[kernel_parameters(by_value)]`
+fn is_by_value(input: &DeriveInput) -> Result<bool, Error> {
+    let mut out = false;
+    for attr in &input.attrs {
+        if !attr.path.is_ident("kernel_parameters") {
+            continue;
+        }
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested {
+                    match nested {
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("by_value") => {
+                            out = true
+                        }
+                        other => {
+                            return Err(Error::new_spanned(
+                                other,
+                                "unknown kernel_parameters option, expected `by_value`",
+                            ))
+                        }
+                    }
+                }
+            }
+            other => {
+                return Err(Error::new_spanned(
+                    other,
+                    "expected #[kernel_parameters(by_value)]",
+                ))
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Derives `cuda_oxide::KernelParameters` for a struct, and `cuda_oxide::KernelArgs` so it can be used with `Module::kernel`.
+/// By default, each field is passed as its own kernel parameter(s), in declaration order, so every field must be `KernelParameters`. `KernelArgs` is implemented when every field is `KernelArgs`.
+/// With `#[kernel_parameters(by_value)]`, the whole struct is passed as a single parameter, matching a struct taken by value in the kernel.
+/// This requires `#[repr(C)]`, `DeviceCopy` fields, and is checked at compile time (where it is defined, or for generic structs once used) to contain no padding, be aligned to at most 16 bytes, and fit within the 4 KiB kernel parameter limit.
+#[proc_macro_derive(KernelParameters, attributes(kernel_parameters))]
+pub fn derive_kernel_parameters(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match kernel_parameters(input) {
+        Ok(x) => x.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn kernel_parameters(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let by_value = is_by_value(&input)?;
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "KernelParameters can only be derived for structs",
+            ))
+        }
+    };
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    if !by_value {
+        let mut args_generics = input.generics.clone();
+        let args_where_clause = args_generics.make_where_clause();
+        let where_clause = generics.make_where_clause();
+        let mut params = vec![];
+        let mut layouts = vec![];
+        for (i, field) in fields.iter().enumerate() {
+            let ty = &field.ty;
+            where_clause
+                .predicates
+                .push(parse_quote!(#ty: ::cuda_oxide::KernelParameters));
+            // higher-ranked, so fields that are not `KernelArgs` (i.e. `Vec<u8>`) leave the impl unsatisfiable instead of failing to compile
+            args_where_clause
+                .predicates
+                .push(parse_quote!(for<'__cuda_oxide> #ty: ::cuda_oxide::KernelArgs));
+            layouts.push(quote! {
+                <#ty as ::cuda_oxide::KernelArgs>::layout(out);
+            });
+            let member = match &field.ident {
+                Some(ident) => quote!(#ident),
+                None => {
+                    let index = Index::from(i);
+                    quote!(#index)
+                }
+            };
+            params.push(quote! {
+                ::cuda_oxide::KernelParameters::params(&self.#member, out);
+            });
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let (args_impl_generics, _, args_where_clause) = args_generics.split_for_impl();
+        return Ok(quote! {
+            impl #impl_generics ::cuda_oxide::KernelParameters for #name #ty_generics #where_clause {
+                fn params(&self, out: &mut ::cuda_oxide::KernelParameterBuffer) {
+                    #(#params)*
+                }
+            }
+
+            impl #args_impl_generics ::cuda_oxide::KernelArgs for #name #ty_generics #args_where_clause {
+                fn layout(out: &mut ::cuda_oxide::KernelParameterBuffer) {
+                    #(#layouts)*
+                }
+            }
+        });
+    }
+
+    if !reprs(&input).iter().any(|x| x == "C") {
+        return Err(Error::new(
+            Span::call_site(),
+            "#[kernel_parameters(by_value)] structs must be #[repr(C)]",
+        ));
+    }
+    let where_clause = generics.make_where_clause();
+    let mut field_sizes = vec![];
+    for field in fields.iter() {
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::cuda_oxide::DeviceCopy));
+        field_sizes.push(quote!(::std::mem::size_of::<#ty>()));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // associated consts are only evaluated once used, which `cargo check` never does, so non-generic structs are checked where they are defined
+    let check = if input.generics.params.is_empty() {
+        quote! {
+            const _: () = #name::__CUDA_OXIDE_KERNEL_PARAMETERS_LAYOUT;
+        }
+    } else {
+        quote!()
+    };
+    Ok(quote! {
+        #check
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            // padding bytes are uninitialized, so they must not be copied into the parameter buffer
+            #[doc(hidden)]
+            const __CUDA_OXIDE_KERNEL_PARAMETERS_LAYOUT: () = {
+                assert!(
+                    ::std::mem::size_of::<Self>() == 0 #(+ #field_sizes)*,
+                    "#[kernel_parameters(by_value)] struct contains padding, reorder fields or add explicit padding fields"
+                );
+                assert!(
+                    ::std::mem::align_of::<Self>() <= 16,
+                    "#[kernel_parameters(by_value)] struct is aligned to more than 16 bytes"
+                );
+                assert!(
+                    ::std::mem::size_of::<Self>() <= 4096,
+                    "#[kernel_parameters(by_value)] struct exceeds the 4 KiB kernel parameter limit"
+                );
+            };
+        }
+
+        impl #impl_generics ::cuda_oxide::KernelParameters for #name #ty_generics #where_clause {
+            fn params(&self, out: &mut ::cuda_oxide::KernelParameterBuffer) {
+                #[allow(clippy::let_unit_value)]
+                let _ = Self::__CUDA_OXIDE_KERNEL_PARAMETERS_LAYOUT;
+                let bytes = unsafe {
+                    ::std::slice::from_raw_parts(
+                        self as *const Self as *const u8,
+                        ::std::mem::size_of::<Self>(),
+                    )
+                };
+                out.push_bytes(bytes, ::std::mem::align_of::<Self>());
+            }
+        }
+
+        impl #impl_generics ::cuda_oxide::KernelArgs for #name #ty_generics #where_clause {
+            fn layout(out: &mut ::cuda_oxide::KernelParameterBuffer) {
+                #[allow(clippy::let_unit_value)]
+                let _ = Self::__CUDA_OXIDE_KERNEL_PARAMETERS_LAYOUT;
+                out.push_zeroed(::std::mem::size_of::<Self>(), ::std::mem::align_of::<Self>());
+            }
+        }
+    })
+}
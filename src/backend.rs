@@ -0,0 +1,475 @@
+//! Pluggable implementations of the CUDA driver API.
+//! Every driver call of the crate goes through the installed [`Backend`], which is the real driver ([`Driver`]) unless another one is installed with [`Cuda::set_backend`](crate::Cuda::set_backend).
+//! [`MockBackend`] simulates devices on the host, to test code using the crate without a GPU, and can run the kernels of PTX modules with an interpreter.
+
+#![allow(non_snake_case, clippy::too_many_arguments)]
+
+use std::{
+    os::raw::{c_char, c_int, c_uchar, c_uint, c_ulonglong, c_ushort, c_void},
+    sync::OnceLock,
+};
+
+use crate::{sys, Cuda, CudaResult, ErrorCode};
+
+mod interpreter;
+mod mock;
+pub use interpreter::InterpretError;
+pub use mock::*;
+
+macro_rules! backend {
+    ($(fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> CUresult;)*) => {
+        /// An implementation of the driver calls used by the crate.
+        /// Methods are the driver API functions of the same name, with the same arguments and `CUresult` return codes.
+        /// Calls a backend does not implement return `CUDA_ERROR_NOT_SUPPORTED`.
+        pub trait Backend: Send + Sync {
+            $(
+                #[doc = concat!("See `", stringify!($name), "` of the CUDA driver API")]
+                unsafe fn $name(&self, $($arg: $ty),*) -> sys::CUresult {
+                    $(let _ = $arg;)*
+                    sys::cudaError_enum_CUDA_ERROR_NOT_SUPPORTED
+                }
+            )*
+        }
+
+        impl Backend for Driver {
+            $(
+                unsafe fn $name(&self, $($arg: $ty),*) -> sys::CUresult {
+                    sys::$name($($arg),*)
+                }
+            )*
+        }
+
+        $(
+            pub(crate) unsafe fn $name($($arg: $ty),*) -> sys::CUresult {
+                backend().$name($($arg),*)
+            }
+        )*
+    };
+}
+
+/// The CUDA driver (`libcuda`), the default [`Backend`]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Driver;
+
+static BACKEND: OnceLock<Box<dyn Backend>> = OnceLock::new();
+
+fn backend() -> &'static dyn Backend {
+    &**BACKEND.get_or_init(|| Box::new(Driver))
+}
+
+impl Cuda {
+    /// Installs the [`Backend`] used for all driver calls of the process, i.e. a [`MockBackend`] in tests.
+    /// It must be installed before any other call, after which it can not be changed: this fails with [`ErrorCode::IllegalState`] if a backend is already in use.
+    pub fn set_backend(backend: Box<dyn Backend>) -> CudaResult<()> {
+        BACKEND.set(backend).map_err(|_| ErrorCode::IllegalState)
+    }
+}
+
+backend! {
+    fn cuCtxCreate_v2(pctx: *mut sys::CUcontext, flags: c_uint, dev: sys::CUdevice) -> CUresult;
+    fn cuCtxDestroy_v2(ctx: sys::CUcontext) -> CUresult;
+    fn cuCtxGetApiVersion(ctx: sys::CUcontext, version: *mut c_uint) -> CUresult;
+    fn cuCtxGetDevice(device: *mut sys::CUdevice) -> CUresult;
+    fn cuCtxGetLimit(pvalue: *mut sys::size_t, limit: sys::CUlimit) -> CUresult;
+    fn cuCtxPopCurrent_v2(pctx: *mut sys::CUcontext) -> CUresult;
+    fn cuCtxPushCurrent_v2(ctx: sys::CUcontext) -> CUresult;
+    fn cuCtxSetLimit(limit: sys::CUlimit, value: sys::size_t) -> CUresult;
+    fn cuCtxSynchronize() -> CUresult;
+    fn cuDeviceGet(device: *mut sys::CUdevice, ordinal: c_int) -> CUresult;
+    fn cuDeviceGetAttribute(
+        pi: *mut c_int,
+        attrib: sys::CUdevice_attribute,
+        dev: sys::CUdevice,
+    ) -> CUresult;
+    fn cuDeviceGetCount(count: *mut c_int) -> CUresult;
+    fn cuDeviceGetDefaultMemPool(pool_out: *mut sys::CUmemoryPool, dev: sys::CUdevice) -> CUresult;
+    fn cuDeviceGetMemPool(pool: *mut sys::CUmemoryPool, dev: sys::CUdevice) -> CUresult;
+    fn cuDeviceGetName(name: *mut c_char, len: c_int, dev: sys::CUdevice) -> CUresult;
+    fn cuDeviceGetTexture1DLinearMaxWidth(
+        maxWidthInElements: *mut sys::size_t,
+        format: sys::CUarray_format,
+        numChannels: c_uint,
+        dev: sys::CUdevice,
+    ) -> CUresult;
+    fn cuDeviceGetUuid(uuid: *mut sys::CUuuid, dev: sys::CUdevice) -> CUresult;
+    fn cuDeviceSetMemPool(dev: sys::CUdevice, pool: sys::CUmemoryPool) -> CUresult;
+    fn cuDeviceTotalMem_v2(bytes: *mut sys::size_t, dev: sys::CUdevice) -> CUresult;
+    fn cuDriverGetVersion(driverVersion: *mut c_int) -> CUresult;
+    fn cuEventCreate(phEvent: *mut sys::CUevent, Flags: c_uint) -> CUresult;
+    fn cuEventDestroy_v2(hEvent: sys::CUevent) -> CUresult;
+    fn cuEventElapsedTime(
+        pMilliseconds: *mut f32,
+        hStart: sys::CUevent,
+        hEnd: sys::CUevent,
+    ) -> CUresult;
+    fn cuEventQuery(hEvent: sys::CUevent) -> CUresult;
+    fn cuEventRecord(hEvent: sys::CUevent, hStream: sys::CUstream) -> CUresult;
+    fn cuEventSynchronize(hEvent: sys::CUevent) -> CUresult;
+    fn cuFuncGetAttribute(
+        pi: *mut c_int,
+        attrib: sys::CUfunction_attribute,
+        hfunc: sys::CUfunction,
+    ) -> CUresult;
+    fn cuFuncSetAttribute(
+        hfunc: sys::CUfunction,
+        attrib: sys::CUfunction_attribute,
+        value: c_int,
+    ) -> CUresult;
+    fn cuFuncSetCacheConfig(hfunc: sys::CUfunction, config: sys::CUfunc_cache) -> CUresult;
+    fn cuFuncSetSharedMemConfig(hfunc: sys::CUfunction, config: sys::CUsharedconfig) -> CUresult;
+    fn cuGraphAddChildGraphNode(
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        childGraph: sys::CUgraph,
+    ) -> CUresult;
+    fn cuGraphAddDependencies(
+        hGraph: sys::CUgraph,
+        from: *const sys::CUgraphNode,
+        to: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+    ) -> CUresult;
+    fn cuGraphAddEmptyNode(
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+    ) -> CUresult;
+    fn cuGraphAddEventRecordNode(
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        event: sys::CUevent,
+    ) -> CUresult;
+    fn cuGraphAddEventWaitNode(
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        event: sys::CUevent,
+    ) -> CUresult;
+    fn cuGraphAddHostNode(
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        nodeParams: *const sys::CUDA_HOST_NODE_PARAMS,
+    ) -> CUresult;
+    fn cuGraphAddKernelNode(
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        nodeParams: *const sys::CUDA_KERNEL_NODE_PARAMS,
+    ) -> CUresult;
+    fn cuGraphAddMemcpyNode(
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        copyParams: *const sys::CUDA_MEMCPY3D,
+        ctx: sys::CUcontext,
+    ) -> CUresult;
+    fn cuGraphAddMemsetNode(
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        memsetParams: *const sys::CUDA_MEMSET_NODE_PARAMS,
+        ctx: sys::CUcontext,
+    ) -> CUresult;
+    fn cuGraphCreate(phGraph: *mut sys::CUgraph, flags: c_uint) -> CUresult;
+    fn cuGraphDestroy(hGraph: sys::CUgraph) -> CUresult;
+    fn cuGraphExecDestroy(hGraphExec: sys::CUgraphExec) -> CUresult;
+    fn cuGraphExecKernelNodeSetParams(
+        hGraphExec: sys::CUgraphExec,
+        hNode: sys::CUgraphNode,
+        nodeParams: *const sys::CUDA_KERNEL_NODE_PARAMS,
+    ) -> CUresult;
+    fn cuGraphExecMemcpyNodeSetParams(
+        hGraphExec: sys::CUgraphExec,
+        hNode: sys::CUgraphNode,
+        copyParams: *const sys::CUDA_MEMCPY3D,
+        ctx: sys::CUcontext,
+    ) -> CUresult;
+    fn cuGraphExecMemsetNodeSetParams(
+        hGraphExec: sys::CUgraphExec,
+        hNode: sys::CUgraphNode,
+        memsetParams: *const sys::CUDA_MEMSET_NODE_PARAMS,
+        ctx: sys::CUcontext,
+    ) -> CUresult;
+    fn cuGraphExecUpdate(
+        hGraphExec: sys::CUgraphExec,
+        hGraph: sys::CUgraph,
+        hErrorNode_out: *mut sys::CUgraphNode,
+        updateResult_out: *mut sys::CUgraphExecUpdateResult,
+    ) -> CUresult;
+    fn cuGraphGetNodes(
+        hGraph: sys::CUgraph,
+        nodes: *mut sys::CUgraphNode,
+        numNodes: *mut sys::size_t,
+    ) -> CUresult;
+    fn cuGraphInstantiate_v2(
+        phGraphExec: *mut sys::CUgraphExec,
+        hGraph: sys::CUgraph,
+        phErrorNode: *mut sys::CUgraphNode,
+        logBuffer: *mut c_char,
+        bufferSize: sys::size_t,
+    ) -> CUresult;
+    fn cuGraphLaunch(hGraphExec: sys::CUgraphExec, hStream: sys::CUstream) -> CUresult;
+    fn cuGraphUpload(hGraphExec: sys::CUgraphExec, hStream: sys::CUstream) -> CUresult;
+    fn cuInit(Flags: c_uint) -> CUresult;
+    fn cuLaunchHostFunc(
+        hStream: sys::CUstream,
+        fn_: sys::CUhostFn,
+        userData: *mut c_void,
+    ) -> CUresult;
+    fn cuLaunchKernel(
+        f: sys::CUfunction,
+        gridDimX: c_uint,
+        gridDimY: c_uint,
+        gridDimZ: c_uint,
+        blockDimX: c_uint,
+        blockDimY: c_uint,
+        blockDimZ: c_uint,
+        sharedMemBytes: c_uint,
+        hStream: sys::CUstream,
+        kernelParams: *mut *mut c_void,
+        extra: *mut *mut c_void,
+    ) -> CUresult;
+    fn cuLinkAddData_v2(
+        state: sys::CUlinkState,
+        type_: sys::CUjitInputType,
+        data: *mut c_void,
+        size: sys::size_t,
+        name: *const c_char,
+        numOptions: c_uint,
+        options: *mut sys::CUjit_option,
+        optionValues: *mut *mut c_void,
+    ) -> CUresult;
+    fn cuLinkComplete(
+        state: sys::CUlinkState,
+        cubinOut: *mut *mut c_void,
+        sizeOut: *mut sys::size_t,
+    ) -> CUresult;
+    fn cuLinkCreate_v2(
+        numOptions: c_uint,
+        options: *mut sys::CUjit_option,
+        optionValues: *mut *mut c_void,
+        stateOut: *mut sys::CUlinkState,
+    ) -> CUresult;
+    fn cuLinkDestroy(state: sys::CUlinkState) -> CUresult;
+    fn cuMemAddressFree(ptr: sys::CUdeviceptr, size: sys::size_t) -> CUresult;
+    fn cuMemAddressReserve(
+        ptr: *mut sys::CUdeviceptr,
+        size: sys::size_t,
+        alignment: sys::size_t,
+        addr: sys::CUdeviceptr,
+        flags: c_ulonglong,
+    ) -> CUresult;
+    fn cuMemAdvise(
+        devPtr: sys::CUdeviceptr,
+        count: sys::size_t,
+        advice: sys::CUmem_advise,
+        device: sys::CUdevice,
+    ) -> CUresult;
+    fn cuMemAllocAsync(
+        dptr: *mut sys::CUdeviceptr,
+        bytesize: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> CUresult;
+    fn cuMemAllocFromPoolAsync(
+        dptr: *mut sys::CUdeviceptr,
+        bytesize: sys::size_t,
+        pool: sys::CUmemoryPool,
+        hStream: sys::CUstream,
+    ) -> CUresult;
+    fn cuMemAllocHost_v2(pp: *mut *mut c_void, bytesize: sys::size_t) -> CUresult;
+    fn cuMemAllocManaged(
+        dptr: *mut sys::CUdeviceptr,
+        bytesize: sys::size_t,
+        flags: c_uint,
+    ) -> CUresult;
+    fn cuMemAlloc_v2(dptr: *mut sys::CUdeviceptr, bytesize: sys::size_t) -> CUresult;
+    fn cuMemCreate(
+        handle: *mut sys::CUmemGenericAllocationHandle,
+        size: sys::size_t,
+        prop: *const sys::CUmemAllocationProp,
+        flags: c_ulonglong,
+    ) -> CUresult;
+    fn cuMemFreeAsync(dptr: sys::CUdeviceptr, hStream: sys::CUstream) -> CUresult;
+    fn cuMemFreeHost(p: *mut c_void) -> CUresult;
+    fn cuMemFree_v2(dptr: sys::CUdeviceptr) -> CUresult;
+    fn cuMemGetAllocationGranularity(
+        granularity: *mut sys::size_t,
+        prop: *const sys::CUmemAllocationProp,
+        option: sys::CUmemAllocationGranularity_flags,
+    ) -> CUresult;
+    fn cuMemHostAlloc(pp: *mut *mut c_void, bytesize: sys::size_t, Flags: c_uint) -> CUresult;
+    fn cuMemHostGetDevicePointer_v2(
+        pdptr: *mut sys::CUdeviceptr,
+        p: *mut c_void,
+        Flags: c_uint,
+    ) -> CUresult;
+    fn cuMemHostRegister_v2(p: *mut c_void, bytesize: sys::size_t, Flags: c_uint) -> CUresult;
+    fn cuMemHostUnregister(p: *mut c_void) -> CUresult;
+    fn cuMemMap(
+        ptr: sys::CUdeviceptr,
+        size: sys::size_t,
+        offset: sys::size_t,
+        handle: sys::CUmemGenericAllocationHandle,
+        flags: c_ulonglong,
+    ) -> CUresult;
+    fn cuMemPoolCreate(
+        pool: *mut sys::CUmemoryPool,
+        poolProps: *const sys::CUmemPoolProps,
+    ) -> CUresult;
+    fn cuMemPoolDestroy(pool: sys::CUmemoryPool) -> CUresult;
+    fn cuMemPoolGetAttribute(
+        pool: sys::CUmemoryPool,
+        attr: sys::CUmemPool_attribute,
+        value: *mut c_void,
+    ) -> CUresult;
+    fn cuMemPoolSetAttribute(
+        pool: sys::CUmemoryPool,
+        attr: sys::CUmemPool_attribute,
+        value: *mut c_void,
+    ) -> CUresult;
+    fn cuMemPoolTrimTo(pool: sys::CUmemoryPool, minBytesToKeep: sys::size_t) -> CUresult;
+    fn cuMemPrefetchAsync(
+        devPtr: sys::CUdeviceptr,
+        count: sys::size_t,
+        dstDevice: sys::CUdevice,
+        hStream: sys::CUstream,
+    ) -> CUresult;
+    fn cuMemRelease(handle: sys::CUmemGenericAllocationHandle) -> CUresult;
+    fn cuMemSetAccess(
+        ptr: sys::CUdeviceptr,
+        size: sys::size_t,
+        desc: *const sys::CUmemAccessDesc,
+        count: sys::size_t,
+    ) -> CUresult;
+    fn cuMemUnmap(ptr: sys::CUdeviceptr, size: sys::size_t) -> CUresult;
+    fn cuMemcpy(dst: sys::CUdeviceptr, src: sys::CUdeviceptr, ByteCount: sys::size_t) -> CUresult;
+    fn cuMemcpyAsync(
+        dst: sys::CUdeviceptr,
+        src: sys::CUdeviceptr,
+        ByteCount: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> CUresult;
+    fn cuMemcpyDtoHAsync_v2(
+        dstHost: *mut c_void,
+        srcDevice: sys::CUdeviceptr,
+        ByteCount: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> CUresult;
+    fn cuMemcpyDtoH_v2(
+        dstHost: *mut c_void,
+        srcDevice: sys::CUdeviceptr,
+        ByteCount: sys::size_t,
+    ) -> CUresult;
+    fn cuMemcpyHtoDAsync_v2(
+        dstDevice: sys::CUdeviceptr,
+        srcHost: *const c_void,
+        ByteCount: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> CUresult;
+    fn cuMemcpyHtoD_v2(
+        dstDevice: sys::CUdeviceptr,
+        srcHost: *const c_void,
+        ByteCount: sys::size_t,
+    ) -> CUresult;
+    fn cuMemcpyPeer(
+        dstDevice: sys::CUdeviceptr,
+        dstContext: sys::CUcontext,
+        srcDevice: sys::CUdeviceptr,
+        srcContext: sys::CUcontext,
+        ByteCount: sys::size_t,
+    ) -> CUresult;
+    fn cuMemcpyPeerAsync(
+        dstDevice: sys::CUdeviceptr,
+        dstContext: sys::CUcontext,
+        srcDevice: sys::CUdeviceptr,
+        srcContext: sys::CUcontext,
+        ByteCount: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> CUresult;
+    fn cuMemsetD16Async(
+        dstDevice: sys::CUdeviceptr,
+        us: c_ushort,
+        N: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> CUresult;
+    fn cuMemsetD16_v2(dstDevice: sys::CUdeviceptr, us: c_ushort, N: sys::size_t) -> CUresult;
+    fn cuMemsetD32Async(
+        dstDevice: sys::CUdeviceptr,
+        ui: c_uint,
+        N: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> CUresult;
+    fn cuMemsetD32_v2(dstDevice: sys::CUdeviceptr, ui: c_uint, N: sys::size_t) -> CUresult;
+    fn cuMemsetD8Async(
+        dstDevice: sys::CUdeviceptr,
+        uc: c_uchar,
+        N: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> CUresult;
+    fn cuMemsetD8_v2(dstDevice: sys::CUdeviceptr, uc: c_uchar, N: sys::size_t) -> CUresult;
+    fn cuModuleGetFunction(
+        hfunc: *mut sys::CUfunction,
+        hmod: sys::CUmodule,
+        name: *const c_char,
+    ) -> CUresult;
+    fn cuModuleGetGlobal_v2(
+        dptr: *mut sys::CUdeviceptr,
+        bytes: *mut sys::size_t,
+        hmod: sys::CUmodule,
+        name: *const c_char,
+    ) -> CUresult;
+    fn cuModuleLoadData(module: *mut sys::CUmodule, image: *const c_void) -> CUresult;
+    fn cuModuleLoadFatBinary(module: *mut sys::CUmodule, fatCubin: *const c_void) -> CUresult;
+    fn cuModuleUnload(hmod: sys::CUmodule) -> CUresult;
+    fn cuStreamAttachMemAsync(
+        hStream: sys::CUstream,
+        dptr: sys::CUdeviceptr,
+        length: sys::size_t,
+        flags: c_uint,
+    ) -> CUresult;
+    fn cuStreamBeginCapture_v2(hStream: sys::CUstream, mode: sys::CUstreamCaptureMode) -> CUresult;
+    fn cuStreamCreate(phStream: *mut sys::CUstream, Flags: c_uint) -> CUresult;
+    fn cuStreamDestroy_v2(hStream: sys::CUstream) -> CUresult;
+    fn cuStreamEndCapture(hStream: sys::CUstream, phGraph: *mut sys::CUgraph) -> CUresult;
+    fn cuStreamIsCapturing(
+        hStream: sys::CUstream,
+        captureStatus: *mut sys::CUstreamCaptureStatus,
+    ) -> CUresult;
+    fn cuStreamQuery(hStream: sys::CUstream) -> CUresult;
+    fn cuStreamSynchronize(hStream: sys::CUstream) -> CUresult;
+    fn cuStreamWaitEvent(hStream: sys::CUstream, hEvent: sys::CUevent, Flags: c_uint) -> CUresult;
+    fn cuStreamWaitValue32(
+        stream: sys::CUstream,
+        addr: sys::CUdeviceptr,
+        value: sys::cuuint32_t,
+        flags: c_uint,
+    ) -> CUresult;
+    fn cuStreamWaitValue64(
+        stream: sys::CUstream,
+        addr: sys::CUdeviceptr,
+        value: sys::cuuint64_t,
+        flags: c_uint,
+    ) -> CUresult;
+    fn cuStreamWriteValue32(
+        stream: sys::CUstream,
+        addr: sys::CUdeviceptr,
+        value: sys::cuuint32_t,
+        flags: c_uint,
+    ) -> CUresult;
+    fn cuStreamWriteValue64(
+        stream: sys::CUstream,
+        addr: sys::CUdeviceptr,
+        value: sys::cuuint64_t,
+        flags: c_uint,
+    ) -> CUresult;
+}
@@ -0,0 +1,2118 @@
+//! A PTX interpreter running the kernels of loaded modules on the host, see [`MockBackend::interpret_ptx`](super::MockBackend::interpret_ptx).
+//! Kernels are parsed into instructions once per module. A launch runs the blocks of the grid one after another,
+//! and the threads of a block one at a time, each until it reaches a barrier or exits.
+
+use std::{
+    alloc::{alloc_zeroed, dealloc, Layout},
+    collections::HashMap,
+    error::Error,
+    fmt,
+};
+
+use super::MockLaunch;
+use crate::{
+    ptx::{is_identifier, tokenize},
+    KernelParam, KernelSignature, PtxParseError,
+};
+
+/// Generic addresses of shared, local and parameter memory are tagged with a high byte no host address has
+const SHARED_BASE: u64 = 0xfe00_0000_0000_0000;
+const LOCAL_BASE: u64 = 0xfd00_0000_0000_0000;
+const PARAM_BASE: u64 = 0xfc00_0000_0000_0000;
+const WINDOW_MASK: u64 = 0xff00_0000_0000_0000;
+/// The alignment of dynamic shared memory
+const DYNAMIC_SHARED_ALIGN: u64 = 16;
+const WARP_SIZE: u32 = 32;
+
+/// An error from the PTX interpreter of [`MockBackend`](super::MockBackend), see [`MockBackend::last_error`](super::MockBackend::last_error)
+#[derive(Clone, Debug)]
+pub enum InterpretError {
+    /// A module could not be parsed
+    Parse(PtxParseError),
+    /// A kernel executed an instruction the interpreter does not implement
+    Unsupported { kernel: String, instruction: String },
+    /// A kernel accessed memory outside of any allocation or variable, or at a misaligned address
+    InvalidAccess {
+        kernel: String,
+        instruction: String,
+        address: u64,
+        block: (u32, u32, u32),
+        thread: (u32, u32, u32),
+    },
+}
+
+impl fmt::Display for InterpretError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpretError::Parse(e) => e.fmt(f),
+            InterpretError::Unsupported {
+                kernel,
+                instruction,
+            } => write!(
+                f,
+                "unsupported instruction `{}` in kernel '{}'",
+                instruction, kernel
+            ),
+            InterpretError::InvalidAccess {
+                kernel,
+                instruction,
+                address,
+                block,
+                thread,
+            } => write!(
+                f,
+                "invalid memory access at {:#x} by `{}` in kernel '{}', block {:?} thread {:?}",
+                address, instruction, kernel, block, thread
+            ),
+        }
+    }
+}
+
+impl Error for InterpretError {}
+
+impl From<PtxParseError> for InterpretError {
+    fn from(e: PtxParseError) -> Self {
+        InterpretError::Parse(e)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Ty {
+    B(u32),
+    U(u32),
+    S(u32),
+    F(u32),
+    Pred,
+}
+
+impl Ty {
+    /// Parses a type modifier without its leading `.`, i.e. `u32`
+    fn parse(name: &str) -> Option<Ty> {
+        if name == "pred" {
+            return Some(Ty::Pred);
+        }
+        let bits: u32 = name.get(1..)?.parse().ok()?;
+        if ![8, 16, 32, 64].contains(&bits) {
+            return None;
+        }
+        Some(match &name[..1] {
+            "b" => Ty::B(bits),
+            "u" => Ty::U(bits),
+            "s" => Ty::S(bits),
+            "f" if bits >= 16 => Ty::F(bits),
+            _ => return None,
+        })
+    }
+
+    fn bits(self) -> u32 {
+        match self {
+            Ty::B(x) | Ty::U(x) | Ty::S(x) | Ty::F(x) => x,
+            Ty::Pred => 1,
+        }
+    }
+
+    fn bytes(self) -> Result<u64, Fault> {
+        match self {
+            Ty::Pred => Err(Fault::Unsupported),
+            x => Ok(x.bits() as u64 / 8),
+        }
+    }
+
+    fn mask(self) -> u64 {
+        mask(self.bits())
+    }
+
+    fn is_signed(self) -> bool {
+        matches!(self, Ty::S(_))
+    }
+
+    fn is_float(self) -> bool {
+        matches!(self, Ty::F(_))
+    }
+
+    /// Gets the type of twice the width, for `.wide` results
+    fn wide(self) -> Result<Ty, Fault> {
+        match self {
+            Ty::U(x) if x < 64 => Ok(Ty::U(x * 2)),
+            Ty::S(x) if x < 64 => Ok(Ty::S(x * 2)),
+            _ => Err(Fault::Unsupported),
+        }
+    }
+}
+
+fn mask(bits: u32) -> u64 {
+    if bits >= 64 {
+        u64::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+/// Sign extends the low `bits` of `value`
+fn sext(value: u64, bits: u32) -> i64 {
+    let shift = 64 - bits.min(64);
+    ((value << shift) as i64) >> shift
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Space {
+    Generic,
+    Global,
+    Shared,
+    Local,
+    Param,
+    Const,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Mov,
+    Ld,
+    St,
+    Cvta,
+    Cvt,
+    Add,
+    Sub,
+    Mul,
+    Mad,
+    Fma,
+    Div,
+    Rem,
+    Abs,
+    Neg,
+    Min,
+    Max,
+    And,
+    Or,
+    Xor,
+    Not,
+    Cnot,
+    Shl,
+    Shr,
+    Popc,
+    Clz,
+    Brev,
+    Bfe,
+    Bfi,
+    Selp,
+    Setp,
+    Sqrt,
+    Rsqrt,
+    Rcp,
+    Sin,
+    Cos,
+    Ex2,
+    Lg2,
+    Copysign,
+    Bra,
+    Ret,
+    Exit,
+    Bar,
+    Nop,
+    Atom,
+    Red,
+    Unsupported,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cmp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Lo,
+    Ls,
+    Hi,
+    Hs,
+    Equ,
+    Neu,
+    Ltu,
+    Leu,
+    Gtu,
+    Geu,
+    Num,
+    Nan,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BoolOp {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Width {
+    Lo,
+    Hi,
+    Wide,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Round {
+    Nearest,
+    Zero,
+    Down,
+    Up,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AtomOp {
+    Add,
+    Min,
+    Max,
+    Exch,
+    Cas,
+    And,
+    Or,
+    Xor,
+    Inc,
+    Dec,
+}
+
+/// A decoded opcode with its modifiers
+#[derive(Clone, Debug)]
+struct Op {
+    kind: Kind,
+    /// The instruction type, or the destination type of `cvt`
+    ty: Ty,
+    /// The source type of `cvt`
+    src_ty: Ty,
+    space: Space,
+    vector: u64,
+    cmp: Option<Cmp>,
+    bool_op: Option<BoolOp>,
+    width: Option<Width>,
+    round: Option<Round>,
+    /// Rounding a float to an integral value, i.e. `cvt.rni.f32.f32`
+    integral: bool,
+    sat: bool,
+    to: bool,
+    atom: Option<AtomOp>,
+}
+
+/// Modifiers that do not change results on a single threaded host
+const IGNORED_MODIFIERS: &[&str] = &[
+    "ftz", "approx", "full", "uni", "sync", "aligned", "nc", "ca", "cg", "cs", "lu", "cv", "wb",
+    "wt", "volatile", "relaxed", "acquire", "release", "acq_rel", "sc", "cta", "gpu", "sys", "gl",
+    "noftz",
+];
+
+impl Op {
+    fn parse(opcode: &str) -> Op {
+        let mut parts = opcode.split('.');
+        let kind = match parts.next().unwrap_or_default() {
+            "mov" => Kind::Mov,
+            "ld" => Kind::Ld,
+            "st" => Kind::St,
+            "cvta" => Kind::Cvta,
+            "cvt" => Kind::Cvt,
+            "add" => Kind::Add,
+            "sub" => Kind::Sub,
+            "mul" => Kind::Mul,
+            "mad" => Kind::Mad,
+            "fma" => Kind::Fma,
+            "div" => Kind::Div,
+            "rem" => Kind::Rem,
+            "abs" => Kind::Abs,
+            "neg" => Kind::Neg,
+            "min" => Kind::Min,
+            "max" => Kind::Max,
+            "and" => Kind::And,
+            "or" => Kind::Or,
+            "xor" => Kind::Xor,
+            "not" => Kind::Not,
+            "cnot" => Kind::Cnot,
+            "shl" => Kind::Shl,
+            "shr" => Kind::Shr,
+            "popc" => Kind::Popc,
+            "clz" => Kind::Clz,
+            "brev" => Kind::Brev,
+            "bfe" => Kind::Bfe,
+            "bfi" => Kind::Bfi,
+            "selp" => Kind::Selp,
+            "setp" => Kind::Setp,
+            "sqrt" => Kind::Sqrt,
+            "rsqrt" => Kind::Rsqrt,
+            "rcp" => Kind::Rcp,
+            "sin" => Kind::Sin,
+            "cos" => Kind::Cos,
+            "ex2" => Kind::Ex2,
+            "lg2" => Kind::Lg2,
+            "copysign" => Kind::Copysign,
+            "bra" => Kind::Bra,
+            "ret" => Kind::Ret,
+            "exit" => Kind::Exit,
+            "bar" | "barrier" => Kind::Bar,
+            "membar" | "fence" => Kind::Nop,
+            "atom" => Kind::Atom,
+            "red" => Kind::Red,
+            _ => Kind::Unsupported,
+        };
+        let mut op = Op {
+            kind,
+            ty: Ty::B(32),
+            src_ty: Ty::B(32),
+            space: Space::Generic,
+            vector: 1,
+            cmp: None,
+            bool_op: None,
+            width: None,
+            round: None,
+            integral: false,
+            sat: false,
+            to: false,
+            atom: None,
+        };
+        let mut types = vec![];
+        let mut barrier_sync = false;
+        for part in parts {
+            if let Some(ty) = Ty::parse(part) {
+                types.push(ty);
+                continue;
+            }
+            let atomic = matches!(kind, Kind::Atom | Kind::Red);
+            match part {
+                "global" => op.space = Space::Global,
+                "shared" | "shared::cta" => op.space = Space::Shared,
+                "local" => op.space = Space::Local,
+                "param" => op.space = Space::Param,
+                "const" => op.space = Space::Const,
+                "v2" => op.vector = 2,
+                "v4" => op.vector = 4,
+                "to" => op.to = true,
+                "sat" => op.sat = true,
+                "wide" => op.width = Some(Width::Wide),
+                "lo" if kind != Kind::Setp => op.width = Some(Width::Lo),
+                "hi" if kind != Kind::Setp => op.width = Some(Width::Hi),
+                "rn" | "rz" | "rm" | "rp" | "rni" | "rzi" | "rmi" | "rpi" => {
+                    op.round = Some(match &part[..2] {
+                        "rn" => Round::Nearest,
+                        "rz" => Round::Zero,
+                        "rm" => Round::Down,
+                        _ => Round::Up,
+                    });
+                    op.integral = part.len() == 3;
+                }
+                "and" | "or" | "xor" if kind == Kind::Setp => {
+                    op.bool_op = Some(match part {
+                        "and" => BoolOp::And,
+                        "or" => BoolOp::Or,
+                        _ => BoolOp::Xor,
+                    })
+                }
+                _ if kind == Kind::Setp && op.cmp.is_none() && Self::parse_cmp(part).is_some() => {
+                    op.cmp = Self::parse_cmp(part)
+                }
+                _ if atomic && op.atom.is_none() && Self::parse_atom(part).is_some() => {
+                    op.atom = Self::parse_atom(part)
+                }
+                "sync" if kind == Kind::Bar => barrier_sync = true,
+                _ if IGNORED_MODIFIERS.contains(&part)
+                    || part.starts_with("L1::")
+                    || part.starts_with("L2::") => {}
+                _ => op.kind = Kind::Unsupported,
+            }
+        }
+        match (op.kind, &types[..]) {
+            (Kind::Cvt, [dst, src]) => {
+                op.ty = *dst;
+                op.src_ty = *src;
+            }
+            (Kind::Cvt, _) => op.kind = Kind::Unsupported,
+            (Kind::Bra | Kind::Ret | Kind::Exit | Kind::Bar | Kind::Nop, []) => (),
+            (Kind::Unsupported, _) => (),
+            (_, [ty]) => {
+                op.ty = *ty;
+                op.src_ty = *ty;
+            }
+            _ => op.kind = Kind::Unsupported,
+        }
+        let incomplete = match op.kind {
+            Kind::Setp => op.cmp.is_none(),
+            Kind::Atom | Kind::Red => op.atom.is_none(),
+            // only full barriers are supported, not `bar.arrive` or `bar.red`
+            Kind::Bar => !barrier_sync,
+            Kind::Cvta => op.space == Space::Generic,
+            _ => false,
+        };
+        if incomplete {
+            op.kind = Kind::Unsupported;
+        }
+        op
+    }
+
+    fn parse_cmp(name: &str) -> Option<Cmp> {
+        Some(match name {
+            "eq" => Cmp::Eq,
+            "ne" => Cmp::Ne,
+            "lt" => Cmp::Lt,
+            "le" => Cmp::Le,
+            "gt" => Cmp::Gt,
+            "ge" => Cmp::Ge,
+            "lo" => Cmp::Lo,
+            "ls" => Cmp::Ls,
+            "hi" => Cmp::Hi,
+            "hs" => Cmp::Hs,
+            "equ" => Cmp::Equ,
+            "neu" => Cmp::Neu,
+            "ltu" => Cmp::Ltu,
+            "leu" => Cmp::Leu,
+            "gtu" => Cmp::Gtu,
+            "geu" => Cmp::Geu,
+            "num" => Cmp::Num,
+            "nan" => Cmp::Nan,
+            _ => return None,
+        })
+    }
+
+    fn parse_atom(name: &str) -> Option<AtomOp> {
+        Some(match name {
+            "add" => AtomOp::Add,
+            "min" => AtomOp::Min,
+            "max" => AtomOp::Max,
+            "exch" => AtomOp::Exch,
+            "cas" => AtomOp::Cas,
+            "and" => AtomOp::And,
+            "or" => AtomOp::Or,
+            "xor" => AtomOp::Xor,
+            "inc" => AtomOp::Inc,
+            "dec" => AtomOp::Dec,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Imm {
+    Int(u64),
+    F32(u32),
+    F64(u64),
+}
+
+impl Imm {
+    fn parse(token: &str) -> Option<Imm> {
+        let token = token.strip_suffix('U').unwrap_or(token);
+        let radix = |digits: &str, radix| u64::from_str_radix(digits, radix).ok();
+        if let Some(hex) = token
+            .strip_prefix("0f")
+            .or_else(|| token.strip_prefix("0F"))
+        {
+            return Some(Imm::F32(u32::from_str_radix(hex, 16).ok()?));
+        }
+        if let Some(hex) = token
+            .strip_prefix("0d")
+            .or_else(|| token.strip_prefix("0D"))
+        {
+            return Some(Imm::F64(radix(hex, 16)?));
+        }
+        if let Some(hex) = token
+            .strip_prefix("0x")
+            .or_else(|| token.strip_prefix("0X"))
+        {
+            return Some(Imm::Int(radix(hex, 16)?));
+        }
+        if let Some(binary) = token
+            .strip_prefix("0b")
+            .or_else(|| token.strip_prefix("0B"))
+        {
+            return Some(Imm::Int(radix(binary, 2)?));
+        }
+        if token.contains(['.', 'e', 'E']) {
+            return Some(Imm::F64(token.parse::<f64>().ok()?.to_bits()));
+        }
+        if token.len() > 1 && token.starts_with('0') {
+            return Some(Imm::Int(radix(&token[1..], 8)?));
+        }
+        Some(Imm::Int(token.parse().ok()?))
+    }
+
+    fn neg(self) -> Imm {
+        match self {
+            Imm::Int(x) => Imm::Int(x.wrapping_neg()),
+            Imm::F32(x) => Imm::F32(x ^ (1 << 31)),
+            Imm::F64(x) => Imm::F64(x ^ (1 << 63)),
+        }
+    }
+
+    /// Gets the bits of the immediate as an operand of type `ty`
+    fn bits(self, ty: Ty) -> u64 {
+        match (self, ty) {
+            (Imm::Int(x), Ty::F(32)) => (x as i64 as f32).to_bits() as u64,
+            (Imm::Int(x), Ty::F(64)) => (x as i64 as f64).to_bits(),
+            (Imm::F32(x), Ty::F(64)) => (f32::from_bits(x) as f64).to_bits(),
+            (Imm::F64(x), Ty::F(32)) => (f64::from_bits(x) as f32).to_bits() as u64,
+            (Imm::Int(x), _) | (Imm::F64(x), _) => x,
+            (Imm::F32(x), _) => x as u64,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Special {
+    Tid(usize),
+    Ntid(usize),
+    Ctaid(usize),
+    Nctaid(usize),
+    LaneId,
+    WarpId,
+    NWarpId,
+    Clock,
+}
+
+impl Special {
+    fn parse(name: &str) -> Option<Special> {
+        let component = |x: &str| match x {
+            "x" => Some(0),
+            "y" => Some(1),
+            "z" => Some(2),
+            _ => None,
+        };
+        Some(match name.split_once('.') {
+            Some(("%tid", x)) => Special::Tid(component(x)?),
+            Some(("%ntid", x)) => Special::Ntid(component(x)?),
+            Some(("%ctaid", x)) => Special::Ctaid(component(x)?),
+            Some(("%nctaid", x)) => Special::Nctaid(component(x)?),
+            _ => match name {
+                "%laneid" => Special::LaneId,
+                "%warpid" => Special::WarpId,
+                "%nwarpid" => Special::NWarpId,
+                "%clock" | "%clock64" => Special::Clock,
+                _ => return None,
+            },
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Operand {
+    Reg(usize),
+    NotPred(usize),
+    Imm(Imm),
+    Special(Special),
+    /// The address of a variable in its state space
+    Var(Space, u64),
+    /// A `.global` or `.const` variable, at an offset into the module memory
+    Global(u64),
+    /// The `.extern .shared` array sized at launch
+    DynamicShared,
+    Address(Box<Operand>, i64),
+    Vector(Vec<Operand>),
+    Label(usize),
+    Sink,
+}
+
+#[derive(Clone, Debug)]
+struct Instruction {
+    /// The predicate register guarding the instruction, and if it is negated
+    guard: Option<(usize, bool)>,
+    op: Op,
+    operands: Vec<Operand>,
+    text: String,
+}
+
+struct Kernel {
+    params: Vec<KernelParam>,
+    param_size: usize,
+    registers: usize,
+    /// The size of static shared memory, dynamic shared memory follows it
+    shared_size: u64,
+    local_size: u64,
+    instructions: Vec<Instruction>,
+}
+
+impl Kernel {
+    fn dynamic_shared(&self) -> u64 {
+        self.shared_size.next_multiple_of(DYNAMIC_SHARED_ALIGN)
+    }
+}
+
+/// Memory of the `.global` and `.const` variables of a module
+struct ModuleMemory {
+    ptr: *mut u8,
+    layout: Layout,
+}
+
+// the memory is only accessed by running kernels, like device memory
+unsafe impl Send for ModuleMemory {}
+unsafe impl Sync for ModuleMemory {}
+
+impl Drop for ModuleMemory {
+    fn drop(&mut self) {
+        unsafe { dealloc(self.ptr, self.layout) };
+    }
+}
+
+/// A parsed PTX module
+pub(crate) struct PtxModule {
+    kernels: HashMap<String, Kernel>,
+    /// `.global` and `.const` variables by name, as offset and size into `memory`
+    globals: HashMap<String, (u64, u64)>,
+    memory: ModuleMemory,
+}
+
+impl PtxModule {
+    pub(crate) fn parse(ptx: &str) -> Result<PtxModule, InterpretError> {
+        let tokens = tokenize(ptx)?;
+        let signatures = KernelSignature::parse_ptx(ptx)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            pos: 0,
+            signatures,
+            kernels: HashMap::new(),
+            shared: HashMap::new(),
+            shared_size: 0,
+            globals: HashMap::new(),
+            global_size: 0,
+            initializers: vec![],
+        };
+        parser.module()?;
+
+        let layout = Layout::from_size_align(parser.global_size.max(1) as usize, 256)
+            .map_err(|_| PtxParseError::new("module variables too large"))?;
+        let ptr = unsafe { alloc_zeroed(layout) };
+        if ptr.is_null() {
+            return Err(PtxParseError::new("failed to allocate module variables").into());
+        }
+        for (offset, data) in &parser.initializers {
+            unsafe {
+                std::ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(*offset as usize), data.len())
+            };
+        }
+        Ok(PtxModule {
+            kernels: parser.kernels,
+            globals: parser.globals,
+            memory: ModuleMemory { ptr, layout },
+        })
+    }
+
+    pub(crate) fn kernel_params(&self, name: &str) -> Option<&[KernelParam]> {
+        self.kernels.get(name).map(|x| &x.params[..])
+    }
+
+    /// Gets the address range of the memory of the `.global` and `.const` variables
+    pub(crate) fn memory(&self) -> (u64, u64) {
+        let base = self.memory.ptr as u64;
+        (base, base + self.memory.layout.size() as u64)
+    }
+
+    /// Gets the address and size of a `.global` or `.const` variable
+    pub(crate) fn global(&self, name: &str) -> Option<(u64, u64)> {
+        self.globals
+            .get(name)
+            .map(|(offset, size)| (self.memory.ptr as u64 + offset, *size))
+    }
+
+    /// Runs kernel `name`. `memory` lists the accessible global memory, including [`PtxModule::memory`], as sorted `(start, end)` ranges.
+    pub(crate) fn launch(
+        &self,
+        name: &str,
+        launch: &MockLaunch,
+        memory: &[(u64, u64)],
+    ) -> Result<(), InterpretError> {
+        let kernel = self
+            .kernels
+            .get(name)
+            .expect("launched kernel is in the module");
+        let mut params = vec![0u8; kernel.param_size];
+        for (param, data) in kernel.params.iter().zip(launch.params()) {
+            let len = data.len().min(param.size);
+            params[param.offset..param.offset + len].copy_from_slice(&data[..len]);
+        }
+        let grid = launch.grid_dim.0;
+        let shared_size = kernel.dynamic_shared() + launch.shared_mem_size as u64;
+        for z in 0..grid.2 {
+            for y in 0..grid.1 {
+                for x in 0..grid.0 {
+                    let mut block = Block {
+                        module: self,
+                        kernel,
+                        name,
+                        params: &params,
+                        memory,
+                        shared: vec![0; shared_size as usize],
+                        ctaid: (x, y, z),
+                        ntid: launch.block_dim.0,
+                        nctaid: grid,
+                    };
+                    block.run()?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Parser<'a> {
+    tokens: &'a [String],
+    pos: usize,
+    signatures: Vec<KernelSignature>,
+    kernels: HashMap<String, Kernel>,
+    /// Module scope `.shared` variables, by offset
+    shared: HashMap<String, Option<u64>>,
+    shared_size: u64,
+    globals: HashMap<String, (u64, u64)>,
+    global_size: u64,
+    initializers: Vec<(u64, Vec<u8>)>,
+}
+
+/// The symbols of a kernel body
+#[derive(Default)]
+struct Scope {
+    registers: HashMap<String, usize>,
+    /// `.shared` variables by offset, or `None` for the dynamically sized array
+    shared: HashMap<String, Option<u64>>,
+    shared_size: u64,
+    local: HashMap<String, u64>,
+    local_size: u64,
+    params: HashMap<String, u64>,
+}
+
+/// A variable declaration
+struct Variable {
+    name: String,
+    align: u64,
+    /// The size in bytes, or `None` for `[]`
+    size: Option<u64>,
+    init: Vec<u8>,
+}
+
+impl<'a> Parser<'a> {
+    fn next(&mut self) -> Result<&'a str, PtxParseError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .ok_or_else(|| PtxParseError::new("unexpected end of module"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.pos).map(|x| &**x)
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), PtxParseError> {
+        match self.next()? {
+            x if x == expected => Ok(()),
+            x => Err(PtxParseError::new(format!(
+                "expected '{}', found '{}'",
+                expected, x
+            ))),
+        }
+    }
+
+    /// Skips a statement up to its `;`, or up to the end of its `{}` block
+    fn skip_statement(&mut self) -> Result<(), PtxParseError> {
+        let mut depth = 0;
+        loop {
+            match self.next()? {
+                ";" if depth == 0 => return Ok(()),
+                "{" => depth += 1,
+                "}" => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Skips a directive without a `;` and its `args` arguments, i.e. `.loc 1 2 3` or `.file 1 "a.cu", 0, 0` (strings are dropped by the tokenizer)
+    fn skip_directive(&mut self, args: usize) -> Result<(), PtxParseError> {
+        for _ in 0..args {
+            self.next()?;
+        }
+        while self.peek() == Some(",") {
+            self.next()?;
+            self.next()?;
+        }
+        Ok(())
+    }
+
+    fn module(&mut self) -> Result<(), PtxParseError> {
+        let mut external = false;
+        while let Some(token) = self.peek() {
+            self.pos += 1;
+            match token {
+                ".version" | ".address_size" => self.skip_directive(1)?,
+                ".target" => self.skip_directive(1)?,
+                ".file" => self.skip_directive(1)?,
+                ".visible" | ".weak" | ".common" => continue,
+                ".extern" => {
+                    external = true;
+                    continue;
+                }
+                ".entry" => self.entry()?,
+                ".global" | ".const" => {
+                    let variable = self.variable()?;
+                    self.declare_global(variable);
+                }
+                ".shared" => {
+                    let variable = self.variable()?;
+                    let offset = self.declare_shared(&variable, external)?;
+                    self.shared.insert(variable.name, offset);
+                }
+                // device functions can't be called, they are reported when a kernel calls them
+                _ => self.skip_statement()?,
+            }
+            external = false;
+        }
+        Ok(())
+    }
+
+    fn declare_global(&mut self, variable: Variable) {
+        let offset = self.global_size.next_multiple_of(variable.align);
+        let size = variable.size.unwrap_or(0);
+        self.global_size = offset + size;
+        if !variable.init.is_empty() {
+            self.initializers.push((offset, variable.init));
+        }
+        self.globals.insert(variable.name, (offset, size));
+    }
+
+    /// Allocates a module scope `.shared` variable, returning `None` for the dynamically sized array
+    fn declare_shared(
+        &mut self,
+        variable: &Variable,
+        external: bool,
+    ) -> Result<Option<u64>, PtxParseError> {
+        match variable.size {
+            None | Some(0) if external => Ok(None),
+            None => Err(PtxParseError::new(format!(
+                "unsized shared variable '{}' is not extern",
+                variable.name
+            ))),
+            Some(size) => {
+                let offset = self.shared_size.next_multiple_of(variable.align);
+                self.shared_size = offset + size;
+                Ok(Some(offset))
+            }
+        }
+    }
+
+    /// Parses a variable declaration after its state space
+    fn variable(&mut self) -> Result<Variable, PtxParseError> {
+        let mut align = None;
+        let mut ty = None;
+        let mut vector = 1;
+        loop {
+            let token = self.next()?;
+            match token {
+                ".align" => {
+                    align = self.next()?.parse::<u64>().ok();
+                    if !align.is_some_and(|x| x.is_power_of_two()) {
+                        return Err(PtxParseError::new("invalid .align"));
+                    }
+                }
+                ".v2" => vector = 2,
+                ".v4" => vector = 4,
+                _ if token.starts_with('.') => {
+                    if let Some(x) = Ty::parse(&token[1..]) {
+                        ty = Some(x);
+                    }
+                }
+                _ if is_identifier(token) => {
+                    let ty = ty.ok_or_else(|| {
+                        PtxParseError::new(format!("missing type of variable '{}'", token))
+                    })?;
+                    let elem_size = ty.bits() as u64 / 8 * vector;
+                    let mut size = Some(elem_size);
+                    while self.peek() == Some("[") {
+                        self.next()?;
+                        if self.peek() == Some("]") {
+                            size = None;
+                        } else {
+                            let len = Imm::parse(self.next()?).and_then(|x| match x {
+                                Imm::Int(x) => Some(x),
+                                _ => None,
+                            });
+                            let len = len.ok_or_else(|| {
+                                PtxParseError::new(format!(
+                                    "invalid array length of variable '{}'",
+                                    token
+                                ))
+                            })?;
+                            size = size.map(|x| x * len);
+                        }
+                        self.expect("]")?;
+                    }
+                    let mut init = vec![];
+                    if self.peek() == Some("=") {
+                        self.next()?;
+                        init = self.initializer(token, ty)?;
+                        // `.b8 x[] = {...}` is sized by its initializer
+                        size = Some(size.unwrap_or(0).max(init.len() as u64));
+                    }
+                    self.expect(";")?;
+                    return Ok(Variable {
+                        name: token.to_string(),
+                        align: align.unwrap_or(elem_size),
+                        size,
+                        init,
+                    });
+                }
+                _ => {
+                    return Err(PtxParseError::new(format!(
+                        "unexpected token '{}' in variable declaration",
+                        token
+                    )))
+                }
+            }
+        }
+    }
+
+    fn initializer(&mut self, name: &str, ty: Ty) -> Result<Vec<u8>, PtxParseError> {
+        let mut out = vec![];
+        let mut depth = 0;
+        loop {
+            match self.peek() {
+                Some("{") => depth += 1,
+                Some("}") => depth -= 1,
+                Some(",") => (),
+                Some(";") | None => break,
+                Some(token) => {
+                    let negative = token == "-";
+                    if negative {
+                        self.next()?;
+                    }
+                    let value = self
+                        .peek()
+                        .and_then(Imm::parse)
+                        .map(|x| if negative { x.neg() } else { x })
+                        .ok_or_else(|| {
+                            PtxParseError::new(format!(
+                                "unsupported initializer of variable '{}'",
+                                name
+                            ))
+                        })?;
+                    let bytes = value.bits(ty).to_le_bytes();
+                    out.extend_from_slice(&bytes[..ty.bits() as usize / 8]);
+                }
+            }
+            self.next()?;
+            if depth == 0 {
+                break;
+            }
+        }
+        Ok(out)
+    }
+
+    fn entry(&mut self) -> Result<(), PtxParseError> {
+        let name = self.next()?.to_string();
+        let signature = self
+            .signatures
+            .iter()
+            .find(|x| x.name == name)
+            .cloned()
+            .ok_or_else(|| PtxParseError::new(format!("unknown kernel '{}'", name)))?;
+        // parameters are read by `KernelSignature::parse_ptx`, performance directives are not needed
+        loop {
+            match self.next()? {
+                "{" => break,
+                ";" => return Ok(()),
+                _ => (),
+            }
+        }
+
+        let mut scope = Scope {
+            params: signature
+                .params
+                .iter()
+                .map(|x| (x.name.clone(), x.offset as u64))
+                .collect(),
+            shared_size: self.shared_size,
+            ..Default::default()
+        };
+        let mut instructions = vec![];
+        let mut labels = HashMap::new();
+        let mut branches = vec![];
+        let mut depth = 1;
+        loop {
+            let token = self.next()?;
+            match token {
+                "{" => depth += 1,
+                "}" => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                ".reg" => self.registers(&mut scope)?,
+                ".shared" | ".extern" => {
+                    let external = token == ".extern";
+                    if external {
+                        self.expect(".shared")?;
+                    }
+                    let variable = self.variable()?;
+                    let offset = match variable.size {
+                        None | Some(0) if external => None,
+                        size => {
+                            let offset = scope.shared_size.next_multiple_of(variable.align);
+                            scope.shared_size = offset + size.unwrap_or(0);
+                            Some(offset)
+                        }
+                    };
+                    scope.shared.insert(variable.name, offset);
+                }
+                ".local" => {
+                    let variable = self.variable()?;
+                    let offset = scope.local_size.next_multiple_of(variable.align);
+                    scope.local_size = offset + variable.size.unwrap_or(0);
+                    scope.local.insert(variable.name, offset);
+                }
+                ".global" | ".const" => {
+                    let variable = self.variable()?;
+                    self.declare_global(variable);
+                }
+                ".loc" => self.skip_directive(3)?,
+                _ if token.starts_with('.') => self.skip_statement()?,
+                _ if self.peek() == Some(":") => {
+                    self.next()?;
+                    labels.insert(token.to_string(), instructions.len());
+                }
+                _ => {
+                    self.pos -= 1;
+                    let (instruction, label) = self.instruction(&scope)?;
+                    if let Some(label) = label {
+                        branches.push((instructions.len(), label));
+                    }
+                    instructions.push(instruction);
+                }
+            }
+        }
+
+        for (index, label) in branches {
+            let target = *labels.get(&label).ok_or_else(|| {
+                PtxParseError::new(format!("unknown label '{}' in kernel '{}'", label, name))
+            })?;
+            instructions[index].operands = vec![Operand::Label(target)];
+        }
+        let param_size = signature
+            .params
+            .last()
+            .map(|x| x.offset + x.size)
+            .unwrap_or(0);
+        self.kernels.insert(
+            name,
+            Kernel {
+                params: signature.params,
+                param_size,
+                registers: scope.registers.len(),
+                shared_size: scope.shared_size,
+                local_size: scope.local_size,
+                instructions,
+            },
+        );
+        Ok(())
+    }
+
+    fn registers(&mut self, scope: &mut Scope) -> Result<(), PtxParseError> {
+        loop {
+            let token = self.next()?;
+            match token {
+                ";" => return Ok(()),
+                "," => (),
+                ".v2" | ".v4" => {
+                    return Err(PtxParseError::new("vector registers are not supported"))
+                }
+                _ if token.starts_with('.') => (),
+                _ if is_identifier(token) => {
+                    if self.peek() == Some("<") {
+                        self.next()?;
+                        let count: usize = self
+                            .next()?
+                            .parse()
+                            .map_err(|_| PtxParseError::new("invalid register count"))?;
+                        self.expect(">")?;
+                        for i in 0..count {
+                            let index = scope.registers.len();
+                            scope.registers.insert(format!("{}{}", token, i), index);
+                        }
+                    } else {
+                        let index = scope.registers.len();
+                        scope.registers.insert(token.to_string(), index);
+                    }
+                }
+                _ => {
+                    return Err(PtxParseError::new(format!(
+                        "unexpected token '{}' in register declaration",
+                        token
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Parses an instruction, returning the label it branches to
+    fn instruction(
+        &mut self,
+        scope: &Scope,
+    ) -> Result<(Instruction, Option<String>), PtxParseError> {
+        let mut guard = None;
+        if self.peek() == Some("@") {
+            self.next()?;
+            let negated = self.peek() == Some("!");
+            if negated {
+                self.next()?;
+            }
+            let name = self.next()?;
+            let register = *scope
+                .registers
+                .get(name)
+                .ok_or_else(|| PtxParseError::new(format!("unknown register '{}'", name)))?;
+            guard = Some((register, negated));
+        }
+        let opcode = self.next()?;
+        let start = self.pos;
+        while self.next()? != ";" {}
+        let tokens = &self.tokens[start..self.pos - 1];
+        let text = instruction_text(opcode, tokens);
+
+        let op = Op::parse(opcode);
+        let mut operands = vec![];
+        let mut label = None;
+        match op.kind {
+            Kind::Unsupported => (),
+            Kind::Bra => match tokens {
+                [target] => label = Some(target.to_string()),
+                _ => return Err(PtxParseError::new(format!("invalid branch `{}`", text))),
+            },
+            _ => {
+                for operand in split(tokens, ",") {
+                    operands.push(self.operand(scope, operand)?);
+                }
+            }
+        }
+        Ok((
+            Instruction {
+                guard,
+                op,
+                operands,
+                text,
+            },
+            label,
+        ))
+    }
+
+    fn operand(&self, scope: &Scope, tokens: &[String]) -> Result<Operand, PtxParseError> {
+        let invalid = || {
+            PtxParseError::new(format!(
+                "invalid operand '{}'",
+                instruction_text("", tokens).trim()
+            ))
+        };
+        let first = tokens.first().ok_or_else(invalid)?;
+        let last = tokens.last().ok_or_else(invalid)?;
+        if first == "[" && last == "]" {
+            let inner = &tokens[1..tokens.len() - 1];
+            let base = inner.first().ok_or_else(invalid)?;
+            let offset = match &inner[1..] {
+                [] => 0,
+                [sign, value] | [_, sign, value] if sign == "-" => match Imm::parse(value) {
+                    Some(Imm::Int(x)) => (x as i64).wrapping_neg(),
+                    _ => return Err(invalid()),
+                },
+                [plus, value] if plus == "+" => match Imm::parse(value) {
+                    Some(Imm::Int(x)) => x as i64,
+                    _ => return Err(invalid()),
+                },
+                _ => return Err(invalid()),
+            };
+            return Ok(Operand::Address(
+                Box::new(self.single(scope, base)?),
+                offset,
+            ));
+        }
+        if first == "{" && last == "}" {
+            return split(&tokens[1..tokens.len() - 1], ",")
+                .map(|x| self.operand(scope, x))
+                .collect::<Result<_, _>>()
+                .map(Operand::Vector);
+        }
+        if tokens.iter().any(|x| x == "|") {
+            return split(tokens, "|")
+                .map(|x| self.operand(scope, x))
+                .collect::<Result<_, _>>()
+                .map(Operand::Vector);
+        }
+        match tokens {
+            [single] => self.single(scope, single),
+            [not, register] if not == "!" => match self.single(scope, register)? {
+                Operand::Reg(x) => Ok(Operand::NotPred(x)),
+                _ => Err(invalid()),
+            },
+            [minus, value] if minus == "-" => Imm::parse(value)
+                .map(|x| Operand::Imm(x.neg()))
+                .ok_or_else(invalid),
+            _ => Err(invalid()),
+        }
+    }
+
+    fn single(&self, scope: &Scope, token: &str) -> Result<Operand, PtxParseError> {
+        if token == "_" {
+            return Ok(Operand::Sink);
+        }
+        if token.starts_with(|x: char| x.is_ascii_digit()) {
+            return Imm::parse(token)
+                .map(Operand::Imm)
+                .ok_or_else(|| PtxParseError::new(format!("invalid immediate '{}'", token)));
+        }
+        if let Some(x) = scope.registers.get(token) {
+            return Ok(Operand::Reg(*x));
+        }
+        if let Some(x) = Special::parse(token) {
+            return Ok(Operand::Special(x));
+        }
+        if let Some(x) = scope.shared.get(token).or_else(|| self.shared.get(token)) {
+            return Ok(match x {
+                Some(offset) => Operand::Var(Space::Shared, *offset),
+                None => Operand::DynamicShared,
+            });
+        }
+        if let Some(x) = scope.local.get(token) {
+            return Ok(Operand::Var(Space::Local, *x));
+        }
+        if let Some(x) = scope.params.get(token) {
+            return Ok(Operand::Var(Space::Param, *x));
+        }
+        if let Some((offset, _)) = self.globals.get(token) {
+            return Ok(Operand::Global(*offset));
+        }
+        if token == "WARP_SZ" {
+            return Ok(Operand::Imm(Imm::Int(WARP_SIZE as u64)));
+        }
+        Err(PtxParseError::new(format!(
+            "unknown identifier '{}'",
+            token
+        )))
+    }
+}
+
+/// Splits tokens at top level occurrences of `separator`
+fn split<'a>(tokens: &'a [String], separator: &'a str) -> impl Iterator<Item = &'a [String]> {
+    let mut depth = 0;
+    tokens
+        .split(move |x| {
+            match &**x {
+                "{" | "[" => depth += 1,
+                "}" | "]" => depth -= 1,
+                _ => (),
+            }
+            depth == 0 && x == separator
+        })
+        .filter(|x| !x.is_empty())
+}
+
+/// Formats an instruction for errors, i.e. `ld.global.f32 %f1, [%rd1+4]`
+fn instruction_text(opcode: &str, tokens: &[String]) -> String {
+    let mut out = opcode.to_string();
+    let mut last = " ";
+    for (index, token) in tokens.iter().enumerate() {
+        let attached = matches!(&**token, "," | "]" | "}" | "+" | "|")
+            || matches!(last, "[" | "{" | "+" | "-" | "!" | "|" | "@")
+            || (token == "-" && last == "+");
+        if index == 0 || !attached {
+            out.push(' ');
+        }
+        out.push_str(token);
+        last = token;
+    }
+    out
+}
+
+/// An error executing an instruction, reported as an [`InterpretError`] with the kernel and thread
+enum Fault {
+    Unsupported,
+    Access(u64),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ThreadState {
+    Running,
+    Barrier,
+    Exited,
+}
+
+struct Thread {
+    registers: Vec<u64>,
+    pc: usize,
+    local: Vec<u8>,
+    tid: (u32, u32, u32),
+    linear: u32,
+    state: ThreadState,
+}
+
+enum Flow {
+    Next,
+    Barrier,
+    Exit,
+}
+
+/// A block being run
+struct Block<'a> {
+    module: &'a PtxModule,
+    kernel: &'a Kernel,
+    name: &'a str,
+    params: &'a [u8],
+    memory: &'a [(u64, u64)],
+    shared: Vec<u8>,
+    ctaid: (u32, u32, u32),
+    ntid: (u32, u32, u32),
+    nctaid: (u32, u32, u32),
+}
+
+fn component(value: (u32, u32, u32), index: usize) -> u64 {
+    [value.0, value.1, value.2][index] as u64
+}
+
+macro_rules! float_arith {
+    ($name:ident, $t:ty) => {
+        fn $name(kind: Kind, a: $t, b: $t, c: $t) -> Option<$t> {
+            Some(match kind {
+                Kind::Add => a + b,
+                Kind::Sub => a - b,
+                Kind::Mul => a * b,
+                Kind::Fma | Kind::Mad => a.mul_add(b, c),
+                Kind::Div => a / b,
+                Kind::Abs => a.abs(),
+                Kind::Neg => -a,
+                Kind::Min if a.is_nan() => b,
+                Kind::Min if b.is_nan() => a,
+                Kind::Min => a.min(b),
+                Kind::Max if a.is_nan() => b,
+                Kind::Max if b.is_nan() => a,
+                Kind::Max => a.max(b),
+                Kind::Sqrt => a.sqrt(),
+                Kind::Rsqrt => 1.0 / a.sqrt(),
+                Kind::Rcp => 1.0 / a,
+                Kind::Sin => a.sin(),
+                Kind::Cos => a.cos(),
+                Kind::Ex2 => a.exp2(),
+                Kind::Lg2 => a.log2(),
+                // `copysign d, a, b` copies the sign of `a` to `b`
+                Kind::Copysign => b.copysign(a),
+                _ => return None,
+            })
+        }
+    };
+}
+
+float_arith!(float_arith_f32, f32);
+float_arith!(float_arith_f64, f64);
+
+fn to_float(value: u64, ty: Ty) -> f64 {
+    match ty {
+        Ty::F(32) => f32::from_bits(value as u32) as f64,
+        _ => f64::from_bits(value),
+    }
+}
+
+fn from_float(value: f64, ty: Ty) -> u64 {
+    match ty {
+        Ty::F(32) => (value as f32).to_bits() as u64,
+        _ => value.to_bits(),
+    }
+}
+
+fn round(value: f64, round: Option<Round>) -> f64 {
+    match round {
+        Some(Round::Nearest) => value.round_ties_even(),
+        Some(Round::Down) => value.floor(),
+        Some(Round::Up) => value.ceil(),
+        Some(Round::Zero) | None => value.trunc(),
+    }
+}
+
+/// Converts `value` to an integer of type `ty`, saturating
+fn float_to_int(value: f64, ty: Ty) -> u64 {
+    let bits = ty.bits();
+    if ty.is_signed() {
+        let min = -(1i128 << (bits - 1));
+        let max = (1i128 << (bits - 1)) - 1;
+        (value as i128).clamp(min, max) as u64 & ty.mask()
+    } else {
+        (value as u128).min(ty.mask() as u128) as u64
+    }
+}
+
+fn to_generic(space: Space, address: u64) -> u64 {
+    match space {
+        Space::Shared => SHARED_BASE + address,
+        Space::Local => LOCAL_BASE + address,
+        Space::Param => PARAM_BASE + address,
+        _ => address,
+    }
+}
+
+fn from_generic(space: Space, address: u64) -> u64 {
+    match space {
+        Space::Shared => address.wrapping_sub(SHARED_BASE),
+        Space::Local => address.wrapping_sub(LOCAL_BASE),
+        Space::Param => address.wrapping_sub(PARAM_BASE),
+        _ => address,
+    }
+}
+
+/// Resolves a generic address to its state space
+fn resolve(space: Space, address: u64) -> (Space, u64) {
+    match space {
+        Space::Generic => match address & WINDOW_MASK {
+            SHARED_BASE => (Space::Shared, address - SHARED_BASE),
+            LOCAL_BASE => (Space::Local, address - LOCAL_BASE),
+            PARAM_BASE => (Space::Param, address - PARAM_BASE),
+            _ => (Space::Global, address),
+        },
+        Space::Const => (Space::Global, address),
+        x => (x, address),
+    }
+}
+
+fn slice_range(len: usize, offset: u64, size: u64) -> Option<std::ops::Range<usize>> {
+    let end = offset.checked_add(size)?;
+    if end > len as u64 {
+        return None;
+    }
+    Some(offset as usize..end as usize)
+}
+
+impl<'a> Block<'a> {
+    fn run(&mut self) -> Result<(), InterpretError> {
+        let (x, y, z) = self.ntid;
+        let mut threads: Vec<Thread> = (0..x * y * z)
+            .map(|linear| Thread {
+                registers: vec![0; self.kernel.registers],
+                pc: 0,
+                local: vec![0; self.kernel.local_size as usize],
+                tid: (linear % x, linear / x % y, linear / (x * y)),
+                linear,
+                state: ThreadState::Running,
+            })
+            .collect();
+        loop {
+            for thread in threads.iter_mut() {
+                if thread.state == ThreadState::Running {
+                    self.run_thread(thread)?;
+                }
+            }
+            // every thread has exited or is waiting at a barrier, which releases them
+            let mut waiting = false;
+            for thread in threads.iter_mut() {
+                if thread.state == ThreadState::Barrier {
+                    thread.state = ThreadState::Running;
+                    waiting = true;
+                }
+            }
+            if !waiting {
+                return Ok(());
+            }
+        }
+    }
+
+    fn run_thread(&mut self, thread: &mut Thread) -> Result<(), InterpretError> {
+        loop {
+            let instruction = match self.kernel.instructions.get(thread.pc) {
+                Some(x) => x,
+                None => {
+                    thread.state = ThreadState::Exited;
+                    return Ok(());
+                }
+            };
+            thread.pc += 1;
+            if let Some((register, negated)) = instruction.guard {
+                if (thread.registers[register] != 0) == negated {
+                    continue;
+                }
+            }
+            match self.step(thread, instruction) {
+                Ok(Flow::Next) => (),
+                Ok(Flow::Barrier) => {
+                    thread.state = ThreadState::Barrier;
+                    return Ok(());
+                }
+                Ok(Flow::Exit) => {
+                    thread.state = ThreadState::Exited;
+                    return Ok(());
+                }
+                Err(Fault::Unsupported) => {
+                    return Err(InterpretError::Unsupported {
+                        kernel: self.name.to_string(),
+                        instruction: instruction.text.clone(),
+                    })
+                }
+                Err(Fault::Access(address)) => {
+                    return Err(InterpretError::InvalidAccess {
+                        kernel: self.name.to_string(),
+                        instruction: instruction.text.clone(),
+                        address,
+                        block: self.ctaid,
+                        thread: thread.tid,
+                    })
+                }
+            }
+        }
+    }
+
+    fn read(&self, thread: &Thread, operand: &Operand, ty: Ty) -> Result<u64, Fault> {
+        let value = match operand {
+            Operand::Reg(x) => thread.registers[*x],
+            Operand::NotPred(x) => (thread.registers[*x] == 0) as u64,
+            Operand::Imm(x) => x.bits(ty),
+            Operand::Special(x) => self.special(thread, *x),
+            Operand::Var(_, address) => *address,
+            Operand::Global(offset) => self.module.memory.ptr as u64 + offset,
+            Operand::DynamicShared => self.kernel.dynamic_shared(),
+            _ => return Err(Fault::Unsupported),
+        };
+        Ok(value & ty.mask())
+    }
+
+    /// Reads an optional operand, missing operands are 0
+    fn read_opt(&self, thread: &Thread, operand: Option<&Operand>, ty: Ty) -> Result<u64, Fault> {
+        match operand {
+            Some(x) => self.read(thread, x, ty),
+            None => Ok(0),
+        }
+    }
+
+    /// Writes a register, sign extending signed values so they can be read at any width
+    fn write(
+        &self,
+        thread: &mut Thread,
+        operand: &Operand,
+        ty: Ty,
+        value: u64,
+    ) -> Result<(), Fault> {
+        let value = match ty {
+            Ty::Pred => (value != 0) as u64,
+            Ty::S(bits) => sext(value, bits) as u64,
+            _ => value & ty.mask(),
+        };
+        match operand {
+            Operand::Reg(x) => thread.registers[*x] = value,
+            Operand::Sink => (),
+            _ => return Err(Fault::Unsupported),
+        }
+        Ok(())
+    }
+
+    fn special(&self, thread: &Thread, special: Special) -> u64 {
+        let threads = self.ntid.0 * self.ntid.1 * self.ntid.2;
+        match special {
+            Special::Tid(x) => component(thread.tid, x),
+            Special::Ntid(x) => component(self.ntid, x),
+            Special::Ctaid(x) => component(self.ctaid, x),
+            Special::Nctaid(x) => component(self.nctaid, x),
+            Special::LaneId => (thread.linear % WARP_SIZE) as u64,
+            Special::WarpId => (thread.linear / WARP_SIZE) as u64,
+            Special::NWarpId => threads.div_ceil(WARP_SIZE) as u64,
+            Special::Clock => 0,
+        }
+    }
+
+    /// Evaluates an address operand of an access to `space`
+    fn address(&self, thread: &Thread, space: Space, operand: &Operand) -> Result<u64, Fault> {
+        let (base, offset) = match operand {
+            Operand::Address(base, offset) => (&**base, *offset),
+            _ => return Err(Fault::Unsupported),
+        };
+        let base = match (space, base) {
+            (Space::Generic, Operand::Var(space, address)) => to_generic(*space, *address),
+            (Space::Generic, Operand::DynamicShared) => {
+                to_generic(Space::Shared, self.kernel.dynamic_shared())
+            }
+            _ => self.read(thread, base, Ty::U(64))?,
+        };
+        Ok(base.wrapping_add(offset as u64))
+    }
+
+    fn check_global(&self, address: u64, size: u64) -> Result<(), Fault> {
+        let index = self.memory.partition_point(|x| x.0 <= address);
+        match index.checked_sub(1).map(|x| self.memory[x]) {
+            Some((_, end)) if address.checked_add(size).is_some_and(|x| x <= end) => Ok(()),
+            _ => Err(Fault::Access(address)),
+        }
+    }
+
+    fn load(&self, thread: &Thread, space: Space, address: u64, size: u64) -> Result<u64, Fault> {
+        let fault = Fault::Access(address);
+        let mut bytes = [0u8; 8];
+        let (space, offset) = resolve(space, address);
+        let source = match space {
+            Space::Shared => &self.shared[..],
+            Space::Local => &thread.local[..],
+            Space::Param => self.params,
+            _ => {
+                self.check_global(address, size)?;
+                unsafe {
+                    std::ptr::copy_nonoverlapping(
+                        address as *const u8,
+                        bytes.as_mut_ptr(),
+                        size as usize,
+                    )
+                };
+                return Ok(u64::from_le_bytes(bytes));
+            }
+        };
+        let range = slice_range(source.len(), offset, size).ok_or(fault)?;
+        bytes[..size as usize].copy_from_slice(&source[range]);
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn store(
+        &mut self,
+        thread: &mut Thread,
+        space: Space,
+        address: u64,
+        size: u64,
+        value: u64,
+    ) -> Result<(), Fault> {
+        let fault = Fault::Access(address);
+        let bytes = value.to_le_bytes();
+        let (space, offset) = resolve(space, address);
+        let target = match space {
+            Space::Shared => &mut self.shared[..],
+            Space::Local => &mut thread.local[..],
+            Space::Param => return Err(fault),
+            _ => {
+                self.check_global(address, size)?;
+                unsafe {
+                    std::ptr::copy_nonoverlapping(bytes.as_ptr(), address as *mut u8, size as usize)
+                };
+                return Ok(());
+            }
+        };
+        let range = slice_range(target.len(), offset, size).ok_or(fault)?;
+        target[range].copy_from_slice(&bytes[..size as usize]);
+        Ok(())
+    }
+
+    fn step(&mut self, thread: &mut Thread, instruction: &Instruction) -> Result<Flow, Fault> {
+        let op = &instruction.op;
+        let operands = &instruction.operands;
+        let arg = |index: usize| operands.get(index).ok_or(Fault::Unsupported);
+        let ty = op.ty;
+        match op.kind {
+            Kind::Unsupported => return Err(Fault::Unsupported),
+            Kind::Nop => (),
+            Kind::Bar => return Ok(Flow::Barrier),
+            Kind::Ret | Kind::Exit => return Ok(Flow::Exit),
+            Kind::Bra => match arg(0)? {
+                Operand::Label(target) => thread.pc = *target,
+                _ => return Err(Fault::Unsupported),
+            },
+            Kind::Mov => match (arg(0)?, arg(1)?) {
+                (Operand::Vector(parts), source) => {
+                    let value = self.read(thread, source, ty)?;
+                    let bits = ty.bits() / parts.len() as u32;
+                    for (index, part) in parts.iter().enumerate() {
+                        self.write(thread, part, Ty::B(bits), value >> (index as u32 * bits))?;
+                    }
+                }
+                (target, Operand::Vector(parts)) => {
+                    let bits = ty.bits() / parts.len() as u32;
+                    let mut value = 0;
+                    for (index, part) in parts.iter().enumerate() {
+                        value |= self.read(thread, part, Ty::B(bits))? << (index as u32 * bits);
+                    }
+                    self.write(thread, target, ty, value)?;
+                }
+                (target, source) => {
+                    let value = self.read(thread, source, ty)?;
+                    self.write(thread, target, ty, value)?;
+                }
+            },
+            Kind::Ld | Kind::St => {
+                let load = op.kind == Kind::Ld;
+                let (data, address) = if load {
+                    (arg(0)?, arg(1)?)
+                } else {
+                    (arg(1)?, arg(0)?)
+                };
+                let address = self.address(thread, op.space, address)?;
+                let size = ty.bytes()?;
+                if address % (size * op.vector) != 0 {
+                    return Err(Fault::Access(address));
+                }
+                let data = match data {
+                    Operand::Vector(parts) if parts.len() as u64 == op.vector => &parts[..],
+                    x if op.vector == 1 => std::slice::from_ref(x),
+                    _ => return Err(Fault::Unsupported),
+                };
+                for (index, part) in data.iter().enumerate() {
+                    let address = address + index as u64 * size;
+                    if load {
+                        let value = self.load(thread, op.space, address, size)?;
+                        self.write(thread, part, ty, value)?;
+                    } else {
+                        let value = self.read(thread, part, ty)?;
+                        self.store(thread, op.space, address, size, value)?;
+                    }
+                }
+            }
+            Kind::Cvta => {
+                let value = self.read(thread, arg(1)?, ty)?;
+                let value = if op.to {
+                    from_generic(op.space, value)
+                } else {
+                    to_generic(op.space, value)
+                };
+                self.write(thread, arg(0)?, ty, value)?;
+            }
+            Kind::Cvt => {
+                let value = self.read(thread, arg(1)?, op.src_ty)?;
+                let value = self.convert(op, value)?;
+                self.write(thread, arg(0)?, ty, value)?;
+            }
+            Kind::Selp => {
+                let a = self.read(thread, arg(1)?, ty)?;
+                let b = self.read(thread, arg(2)?, ty)?;
+                let value = if self.read(thread, arg(3)?, Ty::Pred)? != 0 {
+                    a
+                } else {
+                    b
+                };
+                self.write(thread, arg(0)?, ty, value)?;
+            }
+            Kind::Setp => {
+                let a = self.read(thread, arg(1)?, ty)?;
+                let b = self.read(thread, arg(2)?, ty)?;
+                let result = compare(op.cmp.ok_or(Fault::Unsupported)?, ty, a, b)?;
+                let (p, q) = match op.bool_op {
+                    Some(bool_op) => {
+                        let c = self.read(thread, arg(3)?, Ty::Pred)? != 0;
+                        let combine = |x: bool| match bool_op {
+                            BoolOp::And => x && c,
+                            BoolOp::Or => x || c,
+                            BoolOp::Xor => x ^ c,
+                        };
+                        (combine(result), combine(!result))
+                    }
+                    None => (result, !result),
+                };
+                match arg(0)? {
+                    Operand::Vector(parts) if parts.len() == 2 => {
+                        self.write(thread, &parts[0], Ty::Pred, p as u64)?;
+                        self.write(thread, &parts[1], Ty::Pred, q as u64)?;
+                    }
+                    target => self.write(thread, target, Ty::Pred, p as u64)?,
+                }
+            }
+            Kind::Atom | Kind::Red => {
+                let (target, rest) = if op.kind == Kind::Atom {
+                    (Some(arg(0)?), &operands[1..])
+                } else {
+                    (None, &operands[..])
+                };
+                let address =
+                    self.address(thread, op.space, rest.first().ok_or(Fault::Unsupported)?)?;
+                let b = self.read_opt(thread, rest.get(1), ty)?;
+                let c = self.read_opt(thread, rest.get(2), ty)?;
+                let size = ty.bytes()?;
+                if address % size != 0 {
+                    return Err(Fault::Access(address));
+                }
+                let old = self.load(thread, op.space, address, size)?;
+                let new = atomic(op.atom.ok_or(Fault::Unsupported)?, ty, old, b, c)?;
+                self.store(thread, op.space, address, size, new)?;
+                if let Some(target) = target {
+                    self.write(thread, target, ty, old)?;
+                }
+            }
+            _ if ty.is_float() => {
+                let a = self.read(thread, arg(1)?, ty)?;
+                let b = self.read_opt(thread, operands.get(2), ty)?;
+                let c = self.read_opt(thread, operands.get(3), ty)?;
+                let value = match ty {
+                    Ty::F(32) => {
+                        let f = |x: u64| f32::from_bits(x as u32);
+                        let mut value =
+                            float_arith_f32(op.kind, f(a), f(b), f(c)).ok_or(Fault::Unsupported)?;
+                        if op.sat {
+                            value = if value.is_nan() {
+                                0.0
+                            } else {
+                                value.clamp(0.0, 1.0)
+                            };
+                        }
+                        value.to_bits() as u64
+                    }
+                    Ty::F(64) => {
+                        let mut value = float_arith_f64(
+                            op.kind,
+                            f64::from_bits(a),
+                            f64::from_bits(b),
+                            f64::from_bits(c),
+                        )
+                        .ok_or(Fault::Unsupported)?;
+                        if op.sat {
+                            value = if value.is_nan() {
+                                0.0
+                            } else {
+                                value.clamp(0.0, 1.0)
+                            };
+                        }
+                        value.to_bits()
+                    }
+                    _ => return Err(Fault::Unsupported),
+                };
+                self.write(thread, arg(0)?, ty, value)?;
+            }
+            _ => {
+                let target_ty = match (op.kind, op.width) {
+                    (Kind::Mul | Kind::Mad, Some(Width::Wide)) => ty.wide()?,
+                    (Kind::Popc | Kind::Clz, _) => Ty::U(32),
+                    _ => ty,
+                };
+                let operand_ty = |index: usize| match (op.kind, index) {
+                    (Kind::Shl | Kind::Shr, 2) | (Kind::Bfe, 2..) | (Kind::Bfi, 3..) => Ty::U(32),
+                    (Kind::Mad, 3) => target_ty,
+                    _ => ty,
+                };
+                let mut values = [0u64; 4];
+                for (index, value) in values.iter_mut().enumerate() {
+                    *value =
+                        self.read_opt(thread, operands.get(index + 1), operand_ty(index + 1))?;
+                }
+                let value = int_arith(op, values)?;
+                self.write(thread, arg(0)?, target_ty, value)?;
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    fn convert(&self, op: &Op, value: u64) -> Result<u64, Fault> {
+        let (dst, src) = (op.ty, op.src_ty);
+        if matches!(dst, Ty::F(16) | Ty::Pred) || matches!(src, Ty::F(16) | Ty::Pred) {
+            return Err(Fault::Unsupported);
+        }
+        Ok(match (src.is_float(), dst.is_float()) {
+            (true, true) => {
+                let mut x = to_float(value, src);
+                if op.integral {
+                    x = round(x, op.round);
+                }
+                if op.sat {
+                    x = if x.is_nan() { 0.0 } else { x.clamp(0.0, 1.0) };
+                }
+                from_float(x, dst)
+            }
+            (true, false) => float_to_int(round(to_float(value, src), op.round), dst),
+            (false, true) => match (src.is_signed(), dst) {
+                (true, Ty::F(32)) => (sext(value, src.bits()) as f32).to_bits() as u64,
+                (true, _) => (sext(value, src.bits()) as f64).to_bits(),
+                (false, Ty::F(32)) => (value as f32).to_bits() as u64,
+                (false, _) => (value as f64).to_bits(),
+            },
+            (false, false) => {
+                let x = if src.is_signed() {
+                    sext(value, src.bits()) as i128
+                } else {
+                    value as i128
+                };
+                if op.sat {
+                    let (min, max) = if dst.is_signed() {
+                        (
+                            -(1i128 << (dst.bits() - 1)),
+                            (1i128 << (dst.bits() - 1)) - 1,
+                        )
+                    } else {
+                        (0, dst.mask() as i128)
+                    };
+                    x.clamp(min, max) as u64 & dst.mask()
+                } else {
+                    x as u64 & dst.mask()
+                }
+            }
+        })
+    }
+}
+
+fn compare(cmp: Cmp, ty: Ty, a: u64, b: u64) -> Result<bool, Fault> {
+    if ty.is_float() {
+        if ty == Ty::F(16) {
+            return Err(Fault::Unsupported);
+        }
+        let (x, y) = (to_float(a, ty), to_float(b, ty));
+        let unordered = x.is_nan() || y.is_nan();
+        return Ok(match cmp {
+            Cmp::Eq => !unordered && x == y,
+            Cmp::Ne => !unordered && x != y,
+            Cmp::Lt => !unordered && x < y,
+            Cmp::Le => !unordered && x <= y,
+            Cmp::Gt => !unordered && x > y,
+            Cmp::Ge => !unordered && x >= y,
+            Cmp::Equ => unordered || x == y,
+            Cmp::Neu => unordered || x != y,
+            Cmp::Ltu => unordered || x < y,
+            Cmp::Leu => unordered || x <= y,
+            Cmp::Gtu => unordered || x > y,
+            Cmp::Geu => unordered || x >= y,
+            Cmp::Num => !unordered,
+            Cmp::Nan => unordered,
+            Cmp::Lo | Cmp::Ls | Cmp::Hi | Cmp::Hs => return Err(Fault::Unsupported),
+        });
+    }
+    let ordering = if ty.is_signed() {
+        sext(a, ty.bits()).cmp(&sext(b, ty.bits()))
+    } else {
+        a.cmp(&b)
+    };
+    Ok(match cmp {
+        Cmp::Eq => a == b,
+        Cmp::Ne => a != b,
+        Cmp::Lt => ordering.is_lt(),
+        Cmp::Le => ordering.is_le(),
+        Cmp::Gt => ordering.is_gt(),
+        Cmp::Ge => ordering.is_ge(),
+        Cmp::Lo => a < b,
+        Cmp::Ls => a <= b,
+        Cmp::Hi => a > b,
+        Cmp::Hs => a >= b,
+        _ => return Err(Fault::Unsupported),
+    })
+}
+
+fn atomic(atom: AtomOp, ty: Ty, old: u64, b: u64, c: u64) -> Result<u64, Fault> {
+    let bits = ty.bits();
+    if ty.is_float() {
+        if ty == Ty::F(16) {
+            return Err(Fault::Unsupported);
+        }
+        let (x, y) = (to_float(old, ty), to_float(b, ty));
+        return Ok(match atom {
+            AtomOp::Add => from_float(x + y, ty),
+            AtomOp::Min => from_float(x.min(y), ty),
+            AtomOp::Max => from_float(x.max(y), ty),
+            AtomOp::Exch => b,
+            _ => return Err(Fault::Unsupported),
+        });
+    }
+    let signed = ty.is_signed();
+    Ok(match atom {
+        AtomOp::Add => old.wrapping_add(b),
+        AtomOp::Min if signed => sext(old, bits).min(sext(b, bits)) as u64,
+        AtomOp::Max if signed => sext(old, bits).max(sext(b, bits)) as u64,
+        AtomOp::Min => old.min(b),
+        AtomOp::Max => old.max(b),
+        AtomOp::Exch => b,
+        AtomOp::Cas => {
+            if old == b {
+                c
+            } else {
+                old
+            }
+        }
+        AtomOp::And => old & b,
+        AtomOp::Or => old | b,
+        AtomOp::Xor => old ^ b,
+        AtomOp::Inc => {
+            if old >= b {
+                0
+            } else {
+                old + 1
+            }
+        }
+        AtomOp::Dec => {
+            if old == 0 || old > b {
+                b
+            } else {
+                old - 1
+            }
+        }
+    } & ty.mask())
+}
+
+/// Runs an integer instruction on its source operands
+fn int_arith(op: &Op, [a, b, c, d]: [u64; 4]) -> Result<u64, Fault> {
+    let ty = op.ty;
+    let bits = ty.bits();
+    let signed = ty.is_signed();
+    let (sa, sb) = (sext(a, bits), sext(b, bits));
+    let saturate = |x: i128| {
+        let (min, max) = (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1);
+        x.clamp(min, max) as u64
+    };
+    Ok(match op.kind {
+        Kind::Add if op.sat && signed => saturate(sa as i128 + sb as i128),
+        Kind::Sub if op.sat && signed => saturate(sa as i128 - sb as i128),
+        Kind::Add => a.wrapping_add(b),
+        Kind::Sub => a.wrapping_sub(b),
+        Kind::Mul | Kind::Mad => {
+            let product = if signed {
+                (sa as i128 * sb as i128) as u128
+            } else {
+                a as u128 * b as u128
+            };
+            let value = match op.width {
+                Some(Width::Hi) => (product >> bits) as u64,
+                Some(Width::Lo) | Some(Width::Wide) => product as u64,
+                None => return Err(Fault::Unsupported),
+            };
+            if op.kind == Kind::Mad {
+                value.wrapping_add(c)
+            } else {
+                value
+            }
+        }
+        Kind::Div if b == 0 => ty.mask(),
+        Kind::Div if signed => sa.wrapping_div(sb) as u64,
+        Kind::Div => a / b,
+        Kind::Rem if b == 0 => a,
+        Kind::Rem if signed => sa.wrapping_rem(sb) as u64,
+        Kind::Rem => a % b,
+        Kind::Abs if signed => sa.wrapping_abs() as u64,
+        Kind::Neg if signed => sa.wrapping_neg() as u64,
+        Kind::Min if signed => sa.min(sb) as u64,
+        Kind::Max if signed => sa.max(sb) as u64,
+        Kind::Min => a.min(b),
+        Kind::Max => a.max(b),
+        Kind::And => a & b,
+        Kind::Or => a | b,
+        Kind::Xor => a ^ b,
+        Kind::Not => !a,
+        Kind::Cnot => (a == 0) as u64,
+        Kind::Shl if b >= bits as u64 => 0,
+        Kind::Shl => a << b,
+        Kind::Shr if signed => (sa >> b.min(63)) as u64,
+        Kind::Shr if b >= bits as u64 => 0,
+        Kind::Shr => a >> b,
+        Kind::Popc => a.count_ones() as u64,
+        Kind::Clz => (a.leading_zeros() - (64 - bits)) as u64,
+        Kind::Brev => a.reverse_bits() >> (64 - bits),
+        Kind::Bfe => {
+            let (pos, len) = (b & 0xff, c & 0xff);
+            let msb = if len == 0 {
+                0
+            } else {
+                (pos + len - 1).min(bits as u64 - 1)
+            };
+            let sign = signed && len != 0 && (a >> msb) & 1 == 1;
+            let mut value = 0;
+            for i in 0..bits as u64 {
+                let bit = if i < len && pos + i < bits as u64 {
+                    (a >> (pos + i)) & 1 == 1
+                } else {
+                    sign
+                };
+                value |= (bit as u64) << i;
+            }
+            value
+        }
+        Kind::Bfi => {
+            let (pos, len) = (c & 0xff, d & 0xff);
+            let mut value = b;
+            for i in 0..len {
+                if pos + i >= bits as u64 {
+                    break;
+                }
+                value = (value & !(1 << (pos + i))) | (((a >> i) & 1) << (pos + i));
+            }
+            value
+        }
+        _ => return Err(Fault::Unsupported),
+    })
+}
@@ -0,0 +1,2970 @@
+use std::{
+    alloc::{alloc_zeroed, dealloc, Layout},
+    cell::RefCell,
+    collections::{BTreeMap, HashMap, VecDeque},
+    convert::TryInto,
+    ffi::CStr,
+    fmt,
+    mem::discriminant,
+    os::raw::{c_char, c_int, c_uchar, c_uint, c_ulonglong, c_ushort, c_void},
+    ptr::null_mut,
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    thread::{self, ThreadId},
+    time::Instant,
+};
+
+use super::{
+    interpreter::{InterpretError, PtxModule},
+    Backend,
+};
+use crate::{
+    cubin::is_cubin,
+    fatbin::{is_fatbin, Fatbin, FatbinEntryKind},
+    sys, Cuda, CudaResult, CudaVersion, Dim3,
+};
+
+const SUCCESS: sys::CUresult = sys::cudaError_enum_CUDA_SUCCESS;
+const INVALID_VALUE: sys::CUresult = sys::cudaError_enum_CUDA_ERROR_INVALID_VALUE;
+const INVALID_DEVICE: sys::CUresult = sys::cudaError_enum_CUDA_ERROR_INVALID_DEVICE;
+const INVALID_CONTEXT: sys::CUresult = sys::cudaError_enum_CUDA_ERROR_INVALID_CONTEXT;
+const INVALID_HANDLE: sys::CUresult = sys::cudaError_enum_CUDA_ERROR_INVALID_HANDLE;
+const NOT_FOUND: sys::CUresult = sys::cudaError_enum_CUDA_ERROR_NOT_FOUND;
+const NOT_READY: sys::CUresult = sys::cudaError_enum_CUDA_ERROR_NOT_READY;
+const INVALID_PTX: sys::CUresult = sys::cudaError_enum_CUDA_ERROR_INVALID_PTX;
+const LAUNCH_FAILED: sys::CUresult = sys::cudaError_enum_CUDA_ERROR_LAUNCH_FAILED;
+const NOT_SUPPORTED: sys::CUresult = sys::cudaError_enum_CUDA_ERROR_NOT_SUPPORTED;
+const ILLEGAL_STATE: sys::CUresult = sys::cudaError_enum_CUDA_ERROR_ILLEGAL_STATE;
+const CAPTURE_UNSUPPORTED: sys::CUresult =
+    sys::cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_UNSUPPORTED;
+const CAPTURE_INVALIDATED: sys::CUresult =
+    sys::cudaError_enum_CUDA_ERROR_STREAM_CAPTURE_INVALIDATED;
+const UPDATE_FAILURE: sys::CUresult = sys::cudaError_enum_CUDA_ERROR_GRAPH_EXEC_UPDATE_FAILURE;
+const UPDATE_TOPOLOGY_CHANGED: sys::CUgraphExecUpdateResult =
+    sys::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_TOPOLOGY_CHANGED;
+const UPDATE_NODE_TYPE_CHANGED: sys::CUgraphExecUpdateResult =
+    sys::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_ERROR_NODE_TYPE_CHANGED;
+
+/// The driver version reported by [`MockBackend`], 11.3
+const DRIVER_VERSION: c_int = 11030;
+/// The API version reported for contexts, as reported by real drivers
+const API_VERSION: c_uint = 3020;
+/// The alignment of allocations, as guaranteed by `cuMemAlloc`
+const ALLOCATION_ALIGN: usize = 256;
+/// The granularity of virtual memory management, as on current devices
+const VMM_GRANULARITY: usize = 2 << 20;
+/// Handles are never small, so they can't be mistaken for `CU_STREAM_LEGACY` or `CU_STREAM_PER_THREAD`
+const FIRST_HANDLE: usize = 0x1000;
+
+/// A device simulated by [`MockBackend`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockDevice {
+    pub name: String,
+    pub compute_capability: CudaVersion,
+    /// The reported memory size in bytes. Allocations are not limited to it.
+    pub total_memory: u64,
+}
+
+impl Default for MockDevice {
+    fn default() -> Self {
+        MockDevice {
+            name: "cuda-oxide mock device".to_string(),
+            compute_capability: (8, 0).into(),
+            total_memory: 1 << 30,
+        }
+    }
+}
+
+/// A launch of a kernel registered with [`MockBackend::kernel`]
+#[derive(Debug, Clone)]
+pub struct MockLaunch {
+    pub grid_dim: Dim3,
+    pub block_dim: Dim3,
+    pub shared_mem_size: u32,
+    params: Vec<Vec<u8>>,
+}
+
+impl MockLaunch {
+    /// Gets the raw bytes of each parameter
+    pub fn params(&self) -> &[Vec<u8>] {
+        &self.params
+    }
+
+    /// Reads the parameter at `index`. Device pointers are host addresses, so a pointer parameter can be read as a `u64` and dereferenced.
+    /// Panics if there is no such parameter, or if its size is not the size of `T`.
+    pub fn param<T: Copy>(&self, index: usize) -> T {
+        let param = &self.params[index];
+        assert_eq!(
+            param.len(),
+            std::mem::size_of::<T>(),
+            "size of kernel parameter {}",
+            index
+        );
+        unsafe { std::ptr::read_unaligned(param.as_ptr() as *const T) }
+    }
+}
+
+#[derive(Clone)]
+enum MockKernel {
+    /// A kernel registered with [`MockBackend::kernel`]
+    Host(Arc<dyn Fn(&MockLaunch) + Send + Sync>),
+    /// A kernel of a loaded PTX module, run by the interpreter
+    Ptx(Arc<PtxModule>, String),
+}
+
+#[derive(Clone)]
+struct KernelEntry {
+    name: String,
+    /// The size and alignment of each parameter
+    params: Vec<(usize, usize)>,
+    kernel: MockKernel,
+}
+
+/// A host pointer passed to a host function
+#[derive(Clone, Copy)]
+struct UserData(*mut c_void);
+
+// host functions are called on whichever thread runs the stream, as with the driver
+unsafe impl Send for UserData {}
+
+#[derive(Clone)]
+enum Op {
+    Copy {
+        dst: usize,
+        src: usize,
+        len: usize,
+    },
+    Set {
+        dst: usize,
+        value: u32,
+        size: usize,
+        count: usize,
+    },
+    Launch {
+        kernel: MockKernel,
+        launch: MockLaunch,
+        context: usize,
+    },
+    Host {
+        function: unsafe extern "C" fn(*mut c_void),
+        data: UserData,
+    },
+    Record {
+        event: usize,
+        seq: u64,
+    },
+    Wait {
+        event: usize,
+        seq: u64,
+    },
+    Free(u64),
+}
+
+/// A node of a graph. Graphs instantiated from it keep its handle, to set its parameters.
+#[derive(Clone)]
+struct Node {
+    handle: usize,
+    kind: NodeKind,
+    /// The handles of the nodes this node depends on
+    dependencies: Vec<usize>,
+}
+
+#[derive(Clone)]
+enum NodeKind {
+    Empty,
+    /// A copy, memset, kernel launch or host function, queued as is by every launch
+    Op(Op),
+    Record(usize),
+    Wait(usize),
+    /// A copy of a child graph, in launch order
+    Child(Vec<Node>),
+}
+
+impl NodeKind {
+    /// Checks that `self` is the same type of node as `other`, as updating an instantiated graph requires
+    fn same_type(&self, other: &NodeKind) -> bool {
+        match (self, other) {
+            (NodeKind::Op(a), NodeKind::Op(b)) => discriminant(a) == discriminant(b),
+            (a, b) => discriminant(a) == discriminant(b),
+        }
+    }
+}
+
+/// Orders `nodes` after their dependencies, and otherwise in the order they were added. Returns `None` if the dependencies form a cycle.
+fn launch_order(nodes: &[Node]) -> Option<Vec<Node>> {
+    let mut out: Vec<Node> = vec![];
+    let mut remaining: Vec<&Node> = nodes.iter().collect();
+    while !remaining.is_empty() {
+        let next = remaining.iter().position(|node| {
+            node.dependencies
+                .iter()
+                .all(|x| out.iter().any(|done| done.handle == *x))
+        })?;
+        out.push(remaining.remove(next).clone());
+    }
+    Some(out)
+}
+
+/// Updates the nodes of an instantiated graph to those of `graph` in launch order, which must have the same topology and node types.
+/// Otherwise returns why the update failed, and the node of `graph` at fault.
+fn update_nodes(
+    exec: &mut [Node],
+    graph: &[Node],
+) -> Result<(), (sys::CUgraphExecUpdateResult, Option<usize>)> {
+    if exec.len() != graph.len() {
+        return Err((UPDATE_TOPOLOGY_CHANGED, None));
+    }
+    let dependencies = |nodes: &[Node], node: &Node| {
+        let mut out: Vec<_> = node
+            .dependencies
+            .iter()
+            .map(|x| nodes.iter().position(|node| node.handle == *x))
+            .collect();
+        out.sort_unstable();
+        out
+    };
+    for (node, new) in exec.iter().zip(graph) {
+        if dependencies(exec, node) != dependencies(graph, new) {
+            return Err((UPDATE_TOPOLOGY_CHANGED, Some(new.handle)));
+        }
+        if !node.kind.same_type(&new.kind) {
+            return Err((UPDATE_NODE_TYPE_CHANGED, Some(new.handle)));
+        }
+    }
+    for (node, new) in exec.iter_mut().zip(graph) {
+        node.kind = new.kind.clone();
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct StreamState {
+    /// The context the stream was created in, or `None` for the default stream
+    context: Option<usize>,
+    ops: VecDeque<Op>,
+    /// The number of ops taken from `ops` but not finished
+    running: usize,
+    /// The thread running the stream, others wait for it so that ops run in order
+    runner: Option<ThreadId>,
+    capture: Option<Capture>,
+}
+
+/// The work captured by a stream, see [`Capture::add`]
+#[derive(Default)]
+struct Capture {
+    nodes: Vec<Node>,
+    invalidated: bool,
+}
+
+impl Capture {
+    /// Adds `op` as a node depending on the previously captured one.
+    /// Only copies, memsets, kernel launches and host functions can be captured, other ops invalidate the capture.
+    fn add(&mut self, handle: usize, op: Op) -> sys::CUresult {
+        if self.invalidated {
+            return CAPTURE_INVALIDATED;
+        }
+        match op {
+            Op::Copy { .. } | Op::Set { .. } | Op::Launch { .. } | Op::Host { .. } => {
+                let dependencies = self.nodes.last().map(|x| x.handle).into_iter().collect();
+                self.nodes.push(Node {
+                    handle,
+                    kind: NodeKind::Op(op),
+                    dependencies,
+                });
+                SUCCESS
+            }
+            _ => self.unsupported(),
+        }
+    }
+
+    /// Invalidates the capture, for a call the capturing stream does not support
+    fn unsupported(&mut self) -> sys::CUresult {
+        self.invalidated = true;
+        CAPTURE_UNSUPPORTED
+    }
+}
+
+struct EventState {
+    timing: bool,
+    stream: usize,
+    recorded: u64,
+    completed: u64,
+    time: Option<Instant>,
+}
+
+#[derive(Default)]
+struct PoolState {
+    device: sys::CUdevice,
+    /// The reserved bytes kept when synchronizing, the rest is released
+    release_threshold: u64,
+    reserved: u64,
+    used: u64,
+}
+
+impl PoolState {
+    /// Releases reserved memory that is not in use, keeping at least `keep` bytes
+    fn trim(&mut self, keep: u64) {
+        self.reserved = self.reserved.min(keep.max(self.used));
+    }
+}
+
+/// Physical memory mapped into a reserved address range
+struct Mapping {
+    size: usize,
+    /// Set with `cuMemSetAccess`, the range can't be accessed before
+    accessible: bool,
+}
+
+#[derive(Default)]
+struct ContextState {
+    device: sys::CUdevice,
+    limits: HashMap<sys::CUlimit, sys::size_t>,
+    /// A kernel failed since the last synchronizing call
+    failed: bool,
+}
+
+struct State {
+    devices: Vec<MockDevice>,
+    next_handle: usize,
+    contexts: HashMap<usize, ContextState>,
+    /// Host backed allocations, by address
+    allocations: BTreeMap<u64, Layout>,
+    /// Host memory registered with `cuMemHostRegister`, by address
+    registered: BTreeMap<u64, usize>,
+    /// Streams by handle, the default stream is 0
+    streams: HashMap<usize, StreamState>,
+    events: HashMap<usize, EventState>,
+    /// Modules by handle, with the PTX they were loaded from if interpreting
+    modules: HashMap<usize, Vec<Arc<PtxModule>>>,
+    /// Kernels registered with [`MockBackend::kernel`]
+    kernels: Vec<KernelEntry>,
+    /// Kernels looked up with `cuModuleGetFunction`, by function handle
+    functions: Vec<KernelEntry>,
+    links: HashMap<usize, Vec<u8>>,
+    /// Graphs by handle
+    graphs: HashMap<usize, Vec<Node>>,
+    /// Instantiated graphs by handle, in launch order
+    graph_execs: HashMap<usize, Vec<Node>>,
+    /// Memory pools by handle
+    pools: HashMap<usize, PoolState>,
+    /// The default and current memory pool of each device, created when first used
+    device_pools: HashMap<sys::CUdevice, (usize, usize)>,
+    /// Allocations from memory pools, by address, with their pool
+    pool_allocations: HashMap<u64, usize>,
+    /// Address ranges reserved with `cuMemAddressReserve`, backed by host memory, by address
+    reservations: BTreeMap<u64, Layout>,
+    /// Physical allocations created with `cuMemCreate` with their size, by handle
+    physical: HashMap<sys::CUmemGenericAllocationHandle, usize>,
+    /// Physical memory mapped into reserved ranges, by address
+    mappings: BTreeMap<u64, Mapping>,
+    interpret: bool,
+    last_error: Option<InterpretError>,
+}
+
+impl State {
+    fn handle(&mut self) -> usize {
+        self.next_handle += 16;
+        self.next_handle
+    }
+
+    fn allocate(&mut self, size: usize) -> Option<u64> {
+        if size == 0 {
+            return None;
+        }
+        let layout = Layout::from_size_align(size, ALLOCATION_ALIGN).ok()?;
+        let ptr = unsafe { alloc_zeroed(layout) };
+        if ptr.is_null() {
+            return None;
+        }
+        self.allocations.insert(ptr as u64, layout);
+        Some(ptr as u64)
+    }
+
+    fn free(&mut self, ptr: u64) -> sys::CUresult {
+        match self.allocations.remove(&ptr) {
+            Some(layout) => {
+                let pool = self.pool_allocations.remove(&ptr);
+                if let Some(pool) = pool.and_then(|x| self.pools.get_mut(&x)) {
+                    pool.used -= layout.size() as u64;
+                }
+                unsafe { dealloc(ptr as *mut u8, layout) };
+                SUCCESS
+            }
+            None => INVALID_VALUE,
+        }
+    }
+
+    /// Gets the default and current memory pool of `device`
+    fn device_pools(&mut self, device: sys::CUdevice) -> Option<&mut (usize, usize)> {
+        self.devices.get(device as usize)?;
+        if !self.device_pools.contains_key(&device) {
+            let handle = self.handle();
+            let pool = PoolState {
+                device,
+                ..Default::default()
+            };
+            self.pools.insert(handle, pool);
+            self.device_pools.insert(device, (handle, handle));
+        }
+        self.device_pools.get_mut(&device)
+    }
+
+    /// Releases the reserved memory of every pool above its release threshold, as synchronizing does
+    fn release_pools(&mut self) {
+        for pool in self.pools.values_mut() {
+            pool.trim(pool.release_threshold);
+        }
+    }
+
+    /// Checks that `len` bytes at `ptr` are covered by adjacent mappings, which are accessible if `accessible` is set
+    fn covered_by_mappings(&self, ptr: u64, len: usize, accessible: bool) -> bool {
+        let end = match ptr.checked_add(len as u64) {
+            Some(x) => x,
+            None => return false,
+        };
+        let mut at = ptr;
+        loop {
+            match self.mappings.range(..=at).next_back() {
+                Some((base, x)) if base + x.size as u64 > at && (x.accessible || !accessible) => {
+                    at = base + x.size as u64;
+                }
+                _ => return false,
+            }
+            if at >= end {
+                return true;
+            }
+        }
+    }
+
+    /// Checks that `len` bytes at `ptr` are within a single allocation or registered host range, or accessible mapped memory
+    fn is_mapped(&self, ptr: u64, len: usize) -> bool {
+        let within = |base: u64, size: usize| {
+            ptr.checked_add(len as u64)
+                .is_some_and(|end| end <= base + size as u64)
+        };
+        let allocation = self.allocations.range(..=ptr).next_back();
+        let registered = self.registered.range(..=ptr).next_back();
+        allocation.is_some_and(|(base, layout)| within(*base, layout.size()))
+            || registered.is_some_and(|(base, size)| within(*base, *size))
+            || self.modules.values().flatten().any(|program| {
+                let (base, end) = program.memory();
+                ptr >= base && within(base, (end - base) as usize)
+            })
+            || self.covered_by_mappings(ptr, len, true)
+    }
+
+    fn stream(&mut self, stream: sys::CUstream) -> Option<(usize, &mut StreamState)> {
+        let key = stream_key(stream);
+        self.streams.get_mut(&key).map(|x| (key, x))
+    }
+
+    /// Gets the capture of `stream`, if it is capturing
+    fn capture(&mut self, stream: sys::CUstream) -> Option<&mut Capture> {
+        self.stream(stream).and_then(|(_, x)| x.capture.as_mut())
+    }
+
+    fn enqueue(&mut self, stream: sys::CUstream, op: Op) -> sys::CUresult {
+        let handle = self.handle();
+        match self.stream(stream) {
+            Some((
+                _,
+                StreamState {
+                    capture: Some(capture),
+                    ..
+                },
+            )) => capture.add(handle, op),
+            Some((_, stream)) => {
+                stream.ops.push_back(op);
+                SUCCESS
+            }
+            None => INVALID_HANDLE,
+        }
+    }
+
+    /// Gets the memory kernels may access, as sorted `(start, end)` ranges: allocations, registered host memory and module variables
+    fn memory_ranges(&self) -> Vec<(u64, u64)> {
+        let allocations = self
+            .allocations
+            .iter()
+            .map(|(base, layout)| (*base, base + layout.size() as u64));
+        let registered = self
+            .registered
+            .iter()
+            .map(|(base, size)| (*base, base + *size as u64));
+        let modules = self.modules.values().flatten().map(|x| x.memory());
+        let mappings = self
+            .mappings
+            .iter()
+            .filter(|(_, x)| x.accessible)
+            .map(|(base, x)| (*base, base + x.size as u64));
+        let mut ranges: Vec<_> = allocations
+            .chain(registered)
+            .chain(modules)
+            .chain(mappings)
+            .collect();
+        ranges.sort_unstable();
+        ranges
+    }
+
+    /// Reports and clears a kernel failure in `context`
+    fn take_failure(&mut self, context: Option<usize>) -> sys::CUresult {
+        let context = context.and_then(|x| self.contexts.get_mut(&x));
+        match context.is_some_and(|x| std::mem::take(&mut x.failed)) {
+            true => LAUNCH_FAILED,
+            false => SUCCESS,
+        }
+    }
+
+    /// Builds a launch of kernel `f`, copying its parameters from `kernel_params` or `extra` as the driver does
+    unsafe fn launch_op(
+        &self,
+        f: sys::CUfunction,
+        grid_dim: [c_uint; 3],
+        block_dim: [c_uint; 3],
+        shared_mem_size: c_uint,
+        kernel_params: *mut *mut c_void,
+        extra: *mut *mut c_void,
+    ) -> Result<Op, sys::CUresult> {
+        let entry = self
+            .functions
+            .get(function_index(f))
+            .ok_or(INVALID_HANDLE)?;
+        let threads: u64 = block_dim.iter().map(|x| *x as u64).product();
+        if grid_dim.contains(&0) || block_dim.contains(&0) || threads > 1024 {
+            return Err(INVALID_VALUE);
+        }
+
+        // parameters are copied at launch, as with the driver
+        let mut params = vec![];
+        if !kernel_params.is_null() {
+            for (index, (size, _)) in entry.params.iter().enumerate() {
+                let param = *kernel_params.add(index) as *const u8;
+                params.push(std::slice::from_raw_parts(param, *size).to_vec());
+            }
+        } else if !extra.is_null() {
+            let (mut buffer, mut buffer_size) = (std::ptr::null::<u8>(), None);
+            let mut i = 0;
+            loop {
+                match *extra.add(i) as usize {
+                    0x00 => break,
+                    0x01 => buffer = *extra.add(i + 1) as *const u8,
+                    0x02 => buffer_size = Some(*(*extra.add(i + 1) as *const sys::size_t)),
+                    _ => return Err(INVALID_VALUE),
+                }
+                i += 2;
+            }
+            let buffer_size = match buffer_size {
+                Some(x) if !buffer.is_null() => x as usize,
+                _ => return Err(INVALID_VALUE),
+            };
+            let mut offset = 0usize;
+            for (size, align) in &entry.params {
+                offset = offset.next_multiple_of(*align);
+                if offset + size > buffer_size {
+                    return Err(INVALID_VALUE);
+                }
+                params.push(std::slice::from_raw_parts(buffer.add(offset), *size).to_vec());
+                offset += size;
+            }
+        } else if !entry.params.is_empty() {
+            return Err(INVALID_VALUE);
+        }
+
+        Ok(Op::Launch {
+            kernel: entry.kernel.clone(),
+            launch: MockLaunch {
+                grid_dim: Dim3(grid_dim.into()),
+                block_dim: Dim3(block_dim.into()),
+                shared_mem_size,
+                params,
+            },
+            context: current_context().unwrap_or_default(),
+        })
+    }
+
+    /// Records `event` on the stream `stream`, returning the op completing it
+    fn record_op(&mut self, event: usize, stream: usize) -> Option<Op> {
+        let state = self.events.get_mut(&event)?;
+        state.recorded += 1;
+        state.stream = stream;
+        let seq = state.recorded;
+        Some(Op::Record { event, seq })
+    }
+
+    /// Gets an op waiting for the most recent record of `event`
+    fn wait_op(&self, event: usize) -> Option<Op> {
+        let seq = self.events.get(&event)?.recorded;
+        Some(Op::Wait { event, seq })
+    }
+
+    /// Builds the copy of a memcpy node. Only copies of contiguous memory are supported, not of arrays or of 2D and 3D regions.
+    fn memcpy_op(&self, params: &sys::CUDA_MEMCPY3D) -> Result<Op, sys::CUresult> {
+        let address = |memory_type, host: *const c_void, device| match memory_type {
+            sys::CUmemorytype_enum_CU_MEMORYTYPE_HOST => Ok((host as u64, false)),
+            sys::CUmemorytype_enum_CU_MEMORYTYPE_DEVICE
+            | sys::CUmemorytype_enum_CU_MEMORYTYPE_UNIFIED => Ok((device, true)),
+            _ => Err(NOT_SUPPORTED),
+        };
+        let (src, check_src) = address(params.srcMemoryType, params.srcHost, params.srcDevice)?;
+        let (dst, check_dst) = address(params.dstMemoryType, params.dstHost, params.dstDevice)?;
+        let offsets = [
+            params.srcXInBytes,
+            params.srcY,
+            params.srcZ,
+            params.dstXInBytes,
+            params.dstY,
+            params.dstZ,
+        ];
+        if offsets.iter().any(|x| *x != 0) || params.Height > 1 || params.Depth > 1 {
+            return Err(NOT_SUPPORTED);
+        }
+        let len = params.WidthInBytes as usize;
+        if (check_src && !self.is_mapped(src, len)) || (check_dst && !self.is_mapped(dst, len)) {
+            return Err(INVALID_VALUE);
+        }
+        Ok(Op::Copy {
+            dst: dst as usize,
+            src: src as usize,
+            len,
+        })
+    }
+
+    /// Builds the memset of a memset node. Only memsets of contiguous memory are supported, not of 2D regions.
+    fn memset_op(&self, params: &sys::CUDA_MEMSET_NODE_PARAMS) -> Result<Op, sys::CUresult> {
+        let (size, count) = (params.elementSize as usize, params.width as usize);
+        if params.height > 1 {
+            return Err(NOT_SUPPORTED);
+        }
+        if ![1, 2, 4].contains(&size)
+            || params.dst as usize % size != 0
+            || !self.is_mapped(params.dst, size * count)
+        {
+            return Err(INVALID_VALUE);
+        }
+        Ok(Op::Set {
+            dst: params.dst as usize,
+            value: params.value,
+            size,
+            count,
+        })
+    }
+
+    /// Adds a node to `graph`, depending on the `count` nodes at `dependencies`
+    unsafe fn add_node(
+        &mut self,
+        out: *mut sys::CUgraphNode,
+        graph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        count: sys::size_t,
+        kind: NodeKind,
+    ) -> sys::CUresult {
+        let dependencies: Vec<usize> = match count {
+            0 => vec![],
+            count => std::slice::from_raw_parts(dependencies, count as usize)
+                .iter()
+                .map(|x| *x as usize)
+                .collect(),
+        };
+        let handle = self.handle();
+        let nodes = match self.graphs.get_mut(&(graph as usize)) {
+            Some(x) => x,
+            None => return INVALID_HANDLE,
+        };
+        if !dependencies
+            .iter()
+            .all(|x| nodes.iter().any(|node| node.handle == *x))
+        {
+            return INVALID_VALUE;
+        }
+        nodes.push(Node {
+            handle,
+            kind,
+            dependencies,
+        });
+        *out = handle as sys::CUgraphNode;
+        SUCCESS
+    }
+
+    /// Collects the ops of a launch of `nodes` on the stream `stream`
+    fn graph_ops(&mut self, nodes: &[Node], stream: usize, out: &mut Vec<Op>) {
+        for node in nodes {
+            match &node.kind {
+                NodeKind::Empty => {}
+                NodeKind::Op(op) => out.push(op.clone()),
+                NodeKind::Record(event) => out.extend(self.record_op(*event, stream)),
+                NodeKind::Wait(event) => out.extend(self.wait_op(*event)),
+                NodeKind::Child(nodes) => self.graph_ops(nodes, stream, out),
+            }
+        }
+    }
+
+    /// Replaces the op of node `node` of the instantiated graph `exec` with `op` of the same type
+    fn set_exec_node(
+        &mut self,
+        exec: sys::CUgraphExec,
+        node: sys::CUgraphNode,
+        op: Op,
+    ) -> sys::CUresult {
+        let nodes = match self.graph_execs.get_mut(&(exec as usize)) {
+            Some(x) => x,
+            None => return INVALID_HANDLE,
+        };
+        let kind = NodeKind::Op(op);
+        match nodes.iter_mut().find(|x| x.handle == node as usize) {
+            Some(node) if node.kind.same_type(&kind) => {
+                node.kind = kind;
+                SUCCESS
+            }
+            _ => INVALID_VALUE,
+        }
+    }
+
+    fn event_complete(&self, event: usize, seq: u64) -> bool {
+        self.events
+            .get(&event)
+            .is_none_or(|event| event.completed >= seq)
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        let reservations = std::mem::take(&mut self.reservations);
+        for (ptr, layout) in std::mem::take(&mut self.allocations)
+            .into_iter()
+            .chain(reservations)
+        {
+            unsafe { dealloc(ptr as *mut u8, layout) };
+        }
+    }
+}
+
+thread_local! {
+    /// The context stack of the calling thread
+    static CURRENT: RefCell<Vec<usize>> = const { RefCell::new(vec![]) };
+}
+
+fn current_context() -> Option<usize> {
+    CURRENT.with(|x| x.borrow().last().copied())
+}
+
+/// `CU_STREAM_LEGACY` and `CU_STREAM_PER_THREAD` are both treated as the default stream
+fn stream_key(stream: sys::CUstream) -> usize {
+    match stream as usize {
+        0..=2 => 0,
+        x => x,
+    }
+}
+
+/// Gets the natural alignment of a kernel parameter of `size` bytes
+fn param_align(size: usize) -> usize {
+    if size == 0 {
+        1
+    } else {
+        (1 << size.trailing_zeros()).min(8)
+    }
+}
+
+/// A [`Backend`] simulating devices on the host, to test code using the crate without a GPU.
+///
+/// * Device memory is allocated on the host, so device pointers are valid host pointers. Copies and memsets are checked to be within an allocation.
+/// * Work queued on streams is deferred until it is waited on: by synchronizing the stream, an event recorded on it or the context, by a synchronous copy or free in the same context, or by [`MockBackend::flush`].
+/// * Kernels are host closures registered with [`MockBackend::kernel`], found by name in any loaded module. Each launch calls the closure once for the whole grid.
+/// * With [`MockBackend::interpret_ptx`], kernels of loaded PTX modules that are not registered are run by an interpreter. A failing kernel makes the next synchronizing call in its context return `CUDA_ERROR_LAUNCH_FAILED`, see [`MockBackend::last_error`].
+/// * Contexts are tracked per thread like with the driver, and calls needing a current context fail with `CUDA_ERROR_INVALID_CONTEXT` without one.
+/// * Streams may be synchronized from several threads at once. Each stream runs on one thread at a time, the others wait for it.
+/// * Graph launches queue the nodes of the graph one at a time in an order respecting their dependencies. Memcpy and memset nodes are limited to contiguous memory.
+///
+/// * Streams capture copies, memsets, kernel launches and host functions into a graph of nodes each depending on the previous one. Other work invalidates the capture.
+///
+/// * Memory pools only track their reserved and used memory. Allocations from them are host allocations like any other, made right away, but return to the pool when the free runs on its stream.
+///
+/// * Address ranges reserved for virtual memory management are backed by host memory right away. Physical allocations only track which parts of them are mapped and accessible, so their contents are not kept across mappings.
+/// * All devices share the host memory, so peer copies are plain copies.
+///
+/// ```
+/// use cuda_oxide::{backend::MockBackend, *};
+///
+/// let mock = MockBackend::new();
+/// mock.kernel("fill", &[8, 4], |launch| {
+///     let (ptr, value): (u64, u32) = (launch.param(0), launch.param(1));
+///     let threads = launch.grid_dim.0 .0 * launch.block_dim.0 .0;
+///     let out = unsafe { std::slice::from_raw_parts_mut(ptr as *mut u32, threads as usize) };
+///     out.iter_mut().for_each(|x| *x = value);
+/// });
+/// mock.install().unwrap();
+///
+/// Cuda::init().unwrap();
+/// let device = Cuda::list_devices().unwrap().remove(0);
+/// let mut context = Context::new(&device).unwrap();
+/// let handle = context.enter().unwrap();
+/// let module = Module::load(&handle, b"\0").unwrap();
+/// let function = module.get_function("fill").unwrap();
+/// let mut stream = Stream::new(&handle).unwrap();
+/// let buffer = DeviceBox::<u32>::alloc(&handle, 64).unwrap();
+/// unsafe { stream.launch(&function, 2, 32, 0, (&buffer, 7u32)).unwrap() };
+/// stream.sync().unwrap();
+/// assert_eq!(buffer.load().unwrap(), vec![7; 64]);
+/// ```
+#[derive(Clone)]
+pub struct MockBackend {
+    state: Arc<Mutex<State>>,
+    /// Notified whenever an op of a stream completes
+    progress: Arc<Condvar>,
+}
+
+impl fmt::Debug for MockBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockBackend")
+            .field("devices", &self.lock().devices)
+            .finish()
+    }
+}
+
+impl Default for MockBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockBackend {
+    /// Creates a new [`MockBackend`] with a single default [`MockDevice`]
+    pub fn new() -> Self {
+        Self::with_devices(vec![MockDevice::default()])
+    }
+
+    /// Creates a new [`MockBackend`] with the given devices
+    pub fn with_devices(devices: Vec<MockDevice>) -> Self {
+        MockBackend {
+            state: Arc::new(Mutex::new(State {
+                devices,
+                next_handle: FIRST_HANDLE,
+                contexts: HashMap::new(),
+                allocations: BTreeMap::new(),
+                registered: BTreeMap::new(),
+                streams: vec![(0, StreamState::default())].into_iter().collect(),
+                events: HashMap::new(),
+                modules: HashMap::new(),
+                kernels: vec![],
+                functions: vec![],
+                links: HashMap::new(),
+                graphs: HashMap::new(),
+                graph_execs: HashMap::new(),
+                pools: HashMap::new(),
+                device_pools: HashMap::new(),
+                pool_allocations: HashMap::new(),
+                reservations: BTreeMap::new(),
+                physical: HashMap::new(),
+                mappings: BTreeMap::new(),
+                interpret: false,
+                last_error: None,
+            })),
+            progress: Arc::new(Condvar::new()),
+        }
+    }
+
+    /// Runs kernels of loaded PTX modules with an interpreter, rather than only kernels registered with [`MockBackend::kernel`].
+    /// Modules are parsed when loaded, and fail to load with `CUDA_ERROR_INVALID_PTX` if they can't be parsed.
+    ///
+    /// The interpreter runs the blocks of a grid one after another, and the threads of a block one at a time between barriers, so kernels relying on warp synchronous execution without barriers don't behave as on a device.
+    /// Common integer, floating point, memory, atomic, branch and barrier instructions are supported. Others, like warp shuffles, texture accesses and calls, fail the launch as unsupported.
+    pub fn interpret_ptx(self) -> Self {
+        self.lock().interpret = true;
+        self
+    }
+
+    /// Gets the error of the last module that failed to parse or kernel that failed to run, with the instruction at fault
+    pub fn last_error(&self) -> Option<InterpretError> {
+        self.lock().last_error.clone()
+    }
+
+    /// Installs this backend for the process, see [`Cuda::set_backend`]
+    pub fn install(&self) -> CudaResult<()> {
+        Cuda::set_backend(Box::new(self.clone()))
+    }
+
+    /// Registers a kernel implemented by `kernel`, taking parameters of the given sizes in bytes.
+    /// Registering a kernel of the same name again replaces it for functions looked up afterwards.
+    pub fn kernel<F: Fn(&MockLaunch) + Send + Sync + 'static>(
+        &self,
+        name: &str,
+        param_sizes: &[usize],
+        kernel: F,
+    ) {
+        let mut state = self.lock();
+        state.kernels.retain(|x| x.name != name);
+        state.kernels.push(KernelEntry {
+            name: name.to_string(),
+            params: param_sizes.iter().map(|x| (*x, param_align(*x))).collect(),
+            kernel: MockKernel::Host(Arc::new(kernel)),
+        });
+    }
+
+    /// Runs all deferred work, like synchronizing every stream
+    pub fn flush(&self) {
+        self.flush_streams(|_| true)
+    }
+
+    /// Runs deferred work on the default stream and the streams of the current context, as a synchronizing call does
+    fn flush_context(&self) {
+        let context = current_context();
+        self.flush_streams(|x| x.context.is_none() || x.context == context)
+    }
+
+    fn flush_streams(&self, filter: impl Fn(&StreamState) -> bool) {
+        let thread = thread::current().id();
+        loop {
+            // streams run by other threads are waited for
+            let streams: Vec<usize> = self
+                .lock()
+                .streams
+                .iter()
+                .filter(|(_, x)| {
+                    (!x.ops.is_empty() || x.runner.is_some_and(|x| x != thread)) && filter(x)
+                })
+                .map(|(key, _)| *key)
+                .collect();
+            if streams.is_empty() {
+                return;
+            }
+            for stream in streams {
+                self.run(stream, |_| false);
+            }
+        }
+    }
+
+    /// Gets the number of operations queued on streams and not yet run
+    pub fn pending(&self) -> usize {
+        self.lock().streams.values().map(|x| x.ops.len()).sum()
+    }
+
+    /// Gets the number of live allocations, of device, managed and page-locked host memory
+    pub fn allocations(&self) -> usize {
+        self.lock().allocations.len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // a panicking kernel must not break every later call
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Locks the state if the calling thread has a current context
+    fn lock_context(&self) -> Result<MutexGuard<'_, State>, sys::CUresult> {
+        let state = self.lock();
+        match current_context() {
+            Some(context) if state.contexts.contains_key(&context) => Ok(state),
+            _ => Err(INVALID_CONTEXT),
+        }
+    }
+
+    /// Runs the work queued on `stream` in order, until `until` holds or the stream is empty.
+    /// If another thread is running the stream, waits for it instead.
+    fn run(&self, stream: usize, until: impl Fn(&State) -> bool) {
+        let thread = thread::current().id();
+        let mut state = self.lock();
+        loop {
+            if until(&state) {
+                return;
+            }
+            let queue = match state.streams.get_mut(&stream) {
+                Some(x) => x,
+                None => return,
+            };
+            if queue.runner.is_some_and(|x| x != thread) {
+                state = self.progress.wait(state).unwrap_or_else(|e| e.into_inner());
+                continue;
+            }
+            let op = match queue.ops.pop_front() {
+                Some(op) => op,
+                None => return,
+            };
+            queue.running += 1;
+            queue.runner = Some(thread);
+            // the lock is not held while running kernels and host functions
+            drop(state);
+            self.execute(op);
+            state = self.lock();
+            if let Some(queue) = state.streams.get_mut(&stream) {
+                queue.running -= 1;
+                if queue.running == 0 {
+                    queue.runner = None;
+                }
+            }
+            self.progress.notify_all();
+        }
+    }
+
+    fn execute(&self, op: Op) {
+        match op {
+            Op::Copy { dst, src, len } => unsafe {
+                std::ptr::copy(src as *const u8, dst as *mut u8, len)
+            },
+            Op::Set {
+                dst,
+                value,
+                size,
+                count,
+            } => unsafe {
+                for i in 0..count {
+                    let ptr = (dst + i * size) as *mut u8;
+                    match size {
+                        1 => ptr.write(value as u8),
+                        2 => (ptr as *mut u16).write_unaligned(value as u16),
+                        _ => (ptr as *mut u32).write_unaligned(value),
+                    }
+                }
+            },
+            Op::Launch {
+                kernel,
+                launch,
+                context,
+            } => match kernel {
+                MockKernel::Host(kernel) => kernel(&launch),
+                MockKernel::Ptx(module, name) => {
+                    let memory = self.lock().memory_ranges();
+                    if let Err(e) = module.launch(&name, &launch, &memory) {
+                        eprintln!("CUDA mock: kernel '{}' failed: {}", name, e);
+                        let mut state = self.lock();
+                        if let Some(context) = state.contexts.get_mut(&context) {
+                            context.failed = true;
+                        }
+                        state.last_error = Some(e);
+                    }
+                }
+            },
+            Op::Host { function, data } => unsafe { function(data.0) },
+            Op::Record { event, seq } => {
+                if let Some(event) = self.lock().events.get_mut(&event) {
+                    event.completed = event.completed.max(seq);
+                    event.time = Some(Instant::now());
+                }
+            }
+            Op::Wait { event, seq } => {
+                let stream = self.lock().events.get(&event).map(|x| x.stream);
+                if let Some(stream) = stream {
+                    self.run(stream, |state| state.event_complete(event, seq));
+                }
+            }
+            Op::Free(ptr) => {
+                self.lock().free(ptr);
+            }
+        }
+    }
+
+    /// Runs a copy or memset on `stream`, or right away after all deferred work if `stream` is `None`
+    fn submit(&self, stream: Option<sys::CUstream>, op: Op) -> sys::CUresult {
+        match stream {
+            Some(stream) => self.lock().enqueue(stream, op),
+            None => {
+                self.flush_context();
+                let failure = self.lock().take_failure(current_context());
+                self.execute(op);
+                failure
+            }
+        }
+    }
+
+    fn copy(
+        &self,
+        dst: u64,
+        src: u64,
+        len: usize,
+        check_dst: bool,
+        check_src: bool,
+        stream: Option<sys::CUstream>,
+    ) -> sys::CUresult {
+        {
+            let state = match self.lock_context() {
+                Ok(x) => x,
+                Err(e) => return e,
+            };
+            if (check_dst && !state.is_mapped(dst, len))
+                || (check_src && !state.is_mapped(src, len))
+            {
+                return INVALID_VALUE;
+            }
+        }
+        let op = Op::Copy {
+            dst: dst as usize,
+            src: src as usize,
+            len,
+        };
+        self.submit(stream, op)
+    }
+
+    fn set(
+        &self,
+        dst: u64,
+        value: u32,
+        size: usize,
+        count: usize,
+        stream: Option<sys::CUstream>,
+    ) -> sys::CUresult {
+        {
+            let state = match self.lock_context() {
+                Ok(x) => x,
+                Err(e) => return e,
+            };
+            if dst as usize % size != 0 || !state.is_mapped(dst, size * count) {
+                return INVALID_VALUE;
+            }
+        }
+        let op = Op::Set {
+            dst: dst as usize,
+            value,
+            size,
+            count,
+        };
+        self.submit(stream, op)
+    }
+
+    fn allocate(&self, out: *mut u64, size: sys::size_t) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        match state.allocate(size as usize) {
+            Some(ptr) => {
+                unsafe { *out = ptr };
+                SUCCESS
+            }
+            None => INVALID_VALUE,
+        }
+    }
+
+    fn has_contexts(&self, contexts: &[sys::CUcontext]) -> bool {
+        let state = self.lock();
+        contexts
+            .iter()
+            .all(|x| state.contexts.contains_key(&(*x as usize)))
+    }
+
+    /// Allocates from memory pool `pool` on `stream`, or from the current pool of the device of the current context.
+    /// The memory is allocated right away, but only returned to the pool when the free runs on its stream.
+    fn allocate_from_pool(
+        &self,
+        out: *mut u64,
+        size: sys::size_t,
+        pool: Option<usize>,
+        stream: sys::CUstream,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        if state.stream(stream).is_none() {
+            return INVALID_HANDLE;
+        }
+        if let Some(capture) = state.capture(stream) {
+            return capture.unsupported();
+        }
+        let pool = match pool {
+            Some(x) => x,
+            None => {
+                let device = current_context()
+                    .and_then(|x| state.contexts.get(&x))
+                    .map_or(0, |x| x.device);
+                match state.device_pools(device) {
+                    Some((_, current)) => *current,
+                    None => return INVALID_DEVICE,
+                }
+            }
+        };
+        if !state.pools.contains_key(&pool) {
+            return INVALID_HANDLE;
+        }
+        let ptr = match state.allocate(size as usize) {
+            Some(x) => x,
+            None => return INVALID_VALUE,
+        };
+        state.pool_allocations.insert(ptr, pool);
+        if let Some(pool) = state.pools.get_mut(&pool) {
+            pool.used += size;
+            pool.reserved = pool.reserved.max(pool.used);
+        }
+        unsafe { *out = ptr };
+        SUCCESS
+    }
+
+    fn load_module(&self, module: *mut sys::CUmodule, image: *const c_void) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        let mut programs = vec![];
+        if state.interpret && !image.is_null() {
+            let image = unsafe { read_image(&state, image as *const u8) };
+            let chunks = image.split(|x| *x == 0).map(String::from_utf8_lossy);
+            for ptx in chunks.filter(|x| x.contains(".entry") || x.contains(".version")) {
+                match PtxModule::parse(&ptx) {
+                    Ok(program) => programs.push(Arc::new(program)),
+                    Err(e) => {
+                        state.last_error = Some(e);
+                        return INVALID_PTX;
+                    }
+                }
+            }
+        }
+        let handle = state.handle();
+        state.modules.insert(handle, programs);
+        unsafe { *module = handle as sys::CUmodule };
+        SUCCESS
+    }
+}
+
+/// Reads the PTX of a module image: the output of a linker, a fatbin, or a NUL terminated string. Cubins hold no PTX.
+unsafe fn read_image(state: &State, image: *const u8) -> Vec<u8> {
+    if let Some(link) = state.links.values().find(|x| x.as_ptr() == image) {
+        return link.clone();
+    }
+    let header = std::slice::from_raw_parts(image, 4);
+    if is_cubin(header) {
+        return vec![];
+    }
+    if is_fatbin(header) {
+        let header = std::slice::from_raw_parts(image, 16);
+        let header_size = u16::from_le_bytes([header[6], header[7]]) as usize;
+        let size = u64::from_le_bytes(header[8..16].try_into().unwrap());
+        let fatbin = std::slice::from_raw_parts(image, header_size + size as usize);
+        let ptx = Fatbin::parse(fatbin).ok().and_then(|fatbin| {
+            let entry = fatbin
+                .entries()
+                .iter()
+                .find(|x| x.kind == FatbinEntryKind::Ptx)?;
+            entry.data().ok().map(|x| x.into_owned())
+        });
+        return ptx.unwrap_or_default();
+    }
+    CStr::from_ptr(image as *const c_char).to_bytes().to_vec()
+}
+
+/// Function handles are indices into the looked up functions
+fn function_handle(index: usize) -> sys::CUfunction {
+    ((index + 1) * 16) as sys::CUfunction
+}
+
+fn function_index(function: sys::CUfunction) -> usize {
+    (function as usize / 16).wrapping_sub(1)
+}
+
+impl Backend for MockBackend {
+    unsafe fn cuInit(&self, _flags: c_uint) -> sys::CUresult {
+        SUCCESS
+    }
+
+    unsafe fn cuDriverGetVersion(&self, version: *mut c_int) -> sys::CUresult {
+        *version = DRIVER_VERSION;
+        SUCCESS
+    }
+
+    unsafe fn cuDeviceGetCount(&self, count: *mut c_int) -> sys::CUresult {
+        *count = self.lock().devices.len() as c_int;
+        SUCCESS
+    }
+
+    unsafe fn cuDeviceGet(&self, device: *mut sys::CUdevice, ordinal: c_int) -> sys::CUresult {
+        if ordinal < 0 || ordinal as usize >= self.lock().devices.len() {
+            return INVALID_DEVICE;
+        }
+        *device = ordinal;
+        SUCCESS
+    }
+
+    unsafe fn cuDeviceGetName(
+        &self,
+        name: *mut c_char,
+        len: c_int,
+        dev: sys::CUdevice,
+    ) -> sys::CUresult {
+        let state = self.lock();
+        let device = match state.devices.get(dev as usize) {
+            Some(x) => x,
+            None => return INVALID_DEVICE,
+        };
+        if len <= 0 {
+            return INVALID_VALUE;
+        }
+        let bytes = device.name.as_bytes();
+        let n = bytes.len().min(len as usize - 1);
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), name as *mut u8, n);
+        *name.add(n) = 0;
+        SUCCESS
+    }
+
+    unsafe fn cuDeviceTotalMem_v2(
+        &self,
+        bytes: *mut sys::size_t,
+        dev: sys::CUdevice,
+    ) -> sys::CUresult {
+        match self.lock().devices.get(dev as usize) {
+            Some(device) => {
+                *bytes = device.total_memory as sys::size_t;
+                SUCCESS
+            }
+            None => INVALID_DEVICE,
+        }
+    }
+
+    unsafe fn cuDeviceGetUuid(&self, uuid: *mut sys::CUuuid, dev: sys::CUdevice) -> sys::CUresult {
+        if dev < 0 || dev as usize >= self.lock().devices.len() {
+            return INVALID_DEVICE;
+        }
+        (*uuid).bytes = [dev as c_char; 16];
+        SUCCESS
+    }
+
+    unsafe fn cuDeviceGetAttribute(
+        &self,
+        pi: *mut c_int,
+        attrib: sys::CUdevice_attribute,
+        dev: sys::CUdevice,
+    ) -> sys::CUresult {
+        let state = self.lock();
+        let device = match state.devices.get(dev as usize) {
+            Some(x) => x,
+            None => return INVALID_DEVICE,
+        };
+        *pi = match attrib {
+            sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MAJOR => {
+                device.compute_capability.major as c_int
+            }
+            sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MINOR => {
+                device.compute_capability.minor as c_int
+            }
+            sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_THREADS_PER_BLOCK
+            | sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_X
+            | sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_Y => 1024,
+            sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_Z => 64,
+            sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_X => i32::MAX,
+            sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_Y
+            | sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_Z => 65535,
+            sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_BLOCK => {
+                48 * 1024
+            }
+            sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_WARP_SIZE => 32,
+            sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MULTIPROCESSOR_COUNT => 1,
+            sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_UNIFIED_ADDRESSING
+            | sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_MANAGED_MEMORY
+            | sys::CUdevice_attribute_enum_CU_DEVICE_ATTRIBUTE_CONCURRENT_MANAGED_ACCESS => 1,
+            _ => 0,
+        };
+        SUCCESS
+    }
+
+    unsafe fn cuCtxCreate_v2(
+        &self,
+        pctx: *mut sys::CUcontext,
+        _flags: c_uint,
+        dev: sys::CUdevice,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        if dev < 0 || dev as usize >= state.devices.len() {
+            return INVALID_DEVICE;
+        }
+        let handle = state.handle();
+        let context = ContextState {
+            device: dev,
+            ..Default::default()
+        };
+        state.contexts.insert(handle, context);
+        CURRENT.with(|x| x.borrow_mut().push(handle));
+        *pctx = handle as sys::CUcontext;
+        SUCCESS
+    }
+
+    unsafe fn cuCtxDestroy_v2(&self, ctx: sys::CUcontext) -> sys::CUresult {
+        if self.lock().contexts.remove(&(ctx as usize)).is_none() {
+            return INVALID_CONTEXT;
+        }
+        CURRENT.with(|x| x.borrow_mut().retain(|x| *x != ctx as usize));
+        SUCCESS
+    }
+
+    unsafe fn cuCtxPushCurrent_v2(&self, ctx: sys::CUcontext) -> sys::CUresult {
+        if !self.lock().contexts.contains_key(&(ctx as usize)) {
+            return INVALID_CONTEXT;
+        }
+        CURRENT.with(|x| x.borrow_mut().push(ctx as usize));
+        SUCCESS
+    }
+
+    unsafe fn cuCtxPopCurrent_v2(&self, pctx: *mut sys::CUcontext) -> sys::CUresult {
+        match CURRENT.with(|x| x.borrow_mut().pop()) {
+            Some(ctx) => {
+                if !pctx.is_null() {
+                    *pctx = ctx as sys::CUcontext;
+                }
+                SUCCESS
+            }
+            None => INVALID_CONTEXT,
+        }
+    }
+
+    unsafe fn cuCtxGetApiVersion(
+        &self,
+        ctx: sys::CUcontext,
+        version: *mut c_uint,
+    ) -> sys::CUresult {
+        if !self.lock().contexts.contains_key(&(ctx as usize)) {
+            return INVALID_CONTEXT;
+        }
+        *version = API_VERSION;
+        SUCCESS
+    }
+
+    unsafe fn cuCtxGetDevice(&self, device: *mut sys::CUdevice) -> sys::CUresult {
+        let state = self.lock();
+        match current_context().and_then(|x| state.contexts.get(&x)) {
+            Some(context) => {
+                *device = context.device;
+                SUCCESS
+            }
+            None => INVALID_CONTEXT,
+        }
+    }
+
+    unsafe fn cuCtxSynchronize(&self) -> sys::CUresult {
+        if let Err(e) = self.lock_context() {
+            return e;
+        }
+        self.flush_context();
+        let mut state = self.lock();
+        state.release_pools();
+        state.take_failure(current_context())
+    }
+
+    unsafe fn cuCtxSetLimit(&self, limit: sys::CUlimit, value: sys::size_t) -> sys::CUresult {
+        let mut state = self.lock();
+        match current_context().and_then(|x| state.contexts.get_mut(&x)) {
+            Some(context) => {
+                context.limits.insert(limit, value);
+                SUCCESS
+            }
+            None => INVALID_CONTEXT,
+        }
+    }
+
+    unsafe fn cuCtxGetLimit(&self, pvalue: *mut sys::size_t, limit: sys::CUlimit) -> sys::CUresult {
+        let state = self.lock();
+        match current_context().and_then(|x| state.contexts.get(&x)) {
+            Some(context) => {
+                *pvalue = context.limits.get(&limit).copied().unwrap_or(0);
+                SUCCESS
+            }
+            None => INVALID_CONTEXT,
+        }
+    }
+
+    unsafe fn cuMemAlloc_v2(
+        &self,
+        dptr: *mut sys::CUdeviceptr,
+        bytesize: sys::size_t,
+    ) -> sys::CUresult {
+        self.allocate(dptr, bytesize)
+    }
+
+    unsafe fn cuMemAllocManaged(
+        &self,
+        dptr: *mut sys::CUdeviceptr,
+        bytesize: sys::size_t,
+        _flags: c_uint,
+    ) -> sys::CUresult {
+        self.allocate(dptr, bytesize)
+    }
+
+    unsafe fn cuMemAllocAsync(
+        &self,
+        dptr: *mut sys::CUdeviceptr,
+        bytesize: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        self.allocate_from_pool(dptr, bytesize, None, hStream)
+    }
+
+    unsafe fn cuMemAllocFromPoolAsync(
+        &self,
+        dptr: *mut sys::CUdeviceptr,
+        bytesize: sys::size_t,
+        pool: sys::CUmemoryPool,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        self.allocate_from_pool(dptr, bytesize, Some(pool as usize), hStream)
+    }
+
+    unsafe fn cuMemPoolCreate(
+        &self,
+        pool: *mut sys::CUmemoryPool,
+        poolProps: *const sys::CUmemPoolProps,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        let props = &*poolProps;
+        if props.location.type_ != sys::CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_DEVICE {
+            return INVALID_VALUE;
+        }
+        if state.devices.get(props.location.id as usize).is_none() {
+            return INVALID_DEVICE;
+        }
+        let handle = state.handle();
+        let device = props.location.id;
+        state.pools.insert(
+            handle,
+            PoolState {
+                device,
+                ..Default::default()
+            },
+        );
+        *pool = handle as sys::CUmemoryPool;
+        SUCCESS
+    }
+
+    unsafe fn cuMemPoolDestroy(&self, pool: sys::CUmemoryPool) -> sys::CUresult {
+        let mut state = self.lock();
+        let pool = pool as usize;
+        if state
+            .device_pools
+            .values()
+            .any(|(default, _)| *default == pool)
+        {
+            return INVALID_VALUE;
+        }
+        if state.pools.remove(&pool).is_none() {
+            return INVALID_HANDLE;
+        }
+        // destroying the current pool makes the default pool current again
+        for (default, current) in state.device_pools.values_mut() {
+            if *current == pool {
+                *current = *default;
+            }
+        }
+        SUCCESS
+    }
+
+    unsafe fn cuDeviceGetDefaultMemPool(
+        &self,
+        pool_out: *mut sys::CUmemoryPool,
+        dev: sys::CUdevice,
+    ) -> sys::CUresult {
+        match self.lock().device_pools(dev) {
+            Some((default, _)) => {
+                *pool_out = *default as sys::CUmemoryPool;
+                SUCCESS
+            }
+            None => INVALID_DEVICE,
+        }
+    }
+
+    unsafe fn cuDeviceGetMemPool(
+        &self,
+        pool: *mut sys::CUmemoryPool,
+        dev: sys::CUdevice,
+    ) -> sys::CUresult {
+        match self.lock().device_pools(dev) {
+            Some((_, current)) => {
+                *pool = *current as sys::CUmemoryPool;
+                SUCCESS
+            }
+            None => INVALID_DEVICE,
+        }
+    }
+
+    unsafe fn cuDeviceSetMemPool(
+        &self,
+        dev: sys::CUdevice,
+        pool: sys::CUmemoryPool,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        match state.pools.get(&(pool as usize)) {
+            Some(x) if x.device == dev => {}
+            Some(_) => return INVALID_DEVICE,
+            None => return INVALID_HANDLE,
+        }
+        match state.device_pools(dev) {
+            Some((_, current)) => {
+                *current = pool as usize;
+                SUCCESS
+            }
+            None => INVALID_DEVICE,
+        }
+    }
+
+    unsafe fn cuMemPoolGetAttribute(
+        &self,
+        pool: sys::CUmemoryPool,
+        attr: sys::CUmemPool_attribute,
+        value: *mut c_void,
+    ) -> sys::CUresult {
+        let state = self.lock();
+        let pool = match state.pools.get(&(pool as usize)) {
+            Some(x) => x,
+            None => return INVALID_HANDLE,
+        };
+        *(value as *mut u64) = match attr {
+            sys::CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_RELEASE_THRESHOLD => {
+                pool.release_threshold
+            }
+            sys::CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_RESERVED_MEM_CURRENT => pool.reserved,
+            sys::CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_USED_MEM_CURRENT => pool.used,
+            _ => return NOT_SUPPORTED,
+        };
+        SUCCESS
+    }
+
+    unsafe fn cuMemPoolSetAttribute(
+        &self,
+        pool: sys::CUmemoryPool,
+        attr: sys::CUmemPool_attribute,
+        value: *mut c_void,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        let pool = match state.pools.get_mut(&(pool as usize)) {
+            Some(x) => x,
+            None => return INVALID_HANDLE,
+        };
+        match attr {
+            sys::CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_RELEASE_THRESHOLD => {
+                pool.release_threshold = *(value as *const u64);
+                SUCCESS
+            }
+            _ => NOT_SUPPORTED,
+        }
+    }
+
+    unsafe fn cuMemPoolTrimTo(
+        &self,
+        pool: sys::CUmemoryPool,
+        minBytesToKeep: sys::size_t,
+    ) -> sys::CUresult {
+        match self.lock().pools.get_mut(&(pool as usize)) {
+            Some(pool) => {
+                pool.trim(minBytesToKeep);
+                SUCCESS
+            }
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuMemAllocHost_v2(
+        &self,
+        pp: *mut *mut c_void,
+        bytesize: sys::size_t,
+    ) -> sys::CUresult {
+        self.allocate(pp as *mut u64, bytesize)
+    }
+
+    unsafe fn cuMemHostAlloc(
+        &self,
+        pp: *mut *mut c_void,
+        bytesize: sys::size_t,
+        _flags: c_uint,
+    ) -> sys::CUresult {
+        self.allocate(pp as *mut u64, bytesize)
+    }
+
+    unsafe fn cuMemFree_v2(&self, dptr: sys::CUdeviceptr) -> sys::CUresult {
+        // freeing memory waits for work that may use it
+        self.flush_context();
+        self.lock().free(dptr)
+    }
+
+    unsafe fn cuMemFreeHost(&self, p: *mut c_void) -> sys::CUresult {
+        self.flush_context();
+        self.lock().free(p as u64)
+    }
+
+    unsafe fn cuMemFreeAsync(
+        &self,
+        dptr: sys::CUdeviceptr,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        if !state.allocations.contains_key(&dptr) {
+            return INVALID_VALUE;
+        }
+        state.enqueue(hStream, Op::Free(dptr))
+    }
+
+    unsafe fn cuMemcpyPeer(
+        &self,
+        dstDevice: sys::CUdeviceptr,
+        dstContext: sys::CUcontext,
+        srcDevice: sys::CUdeviceptr,
+        srcContext: sys::CUcontext,
+        ByteCount: sys::size_t,
+    ) -> sys::CUresult {
+        if !self.has_contexts(&[dstContext, srcContext]) {
+            return INVALID_CONTEXT;
+        }
+        // all devices share the host memory, so peer copies are plain copies
+        self.copy(dstDevice, srcDevice, ByteCount as usize, true, true, None)
+    }
+
+    unsafe fn cuMemcpyPeerAsync(
+        &self,
+        dstDevice: sys::CUdeviceptr,
+        dstContext: sys::CUcontext,
+        srcDevice: sys::CUdeviceptr,
+        srcContext: sys::CUcontext,
+        ByteCount: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        if !self.has_contexts(&[dstContext, srcContext]) {
+            return INVALID_CONTEXT;
+        }
+        self.copy(
+            dstDevice,
+            srcDevice,
+            ByteCount as usize,
+            true,
+            true,
+            Some(hStream),
+        )
+    }
+
+    unsafe fn cuMemGetAllocationGranularity(
+        &self,
+        granularity: *mut sys::size_t,
+        prop: *const sys::CUmemAllocationProp,
+        _option: sys::CUmemAllocationGranularity_flags,
+    ) -> sys::CUresult {
+        if self
+            .lock()
+            .devices
+            .get((*prop).location.id as usize)
+            .is_none()
+        {
+            return INVALID_DEVICE;
+        }
+        *granularity = VMM_GRANULARITY as sys::size_t;
+        SUCCESS
+    }
+
+    unsafe fn cuMemAddressReserve(
+        &self,
+        ptr: *mut sys::CUdeviceptr,
+        size: sys::size_t,
+        alignment: sys::size_t,
+        _addr: sys::CUdeviceptr,
+        _flags: c_ulonglong,
+    ) -> sys::CUresult {
+        let size = size as usize;
+        if size == 0 || size % VMM_GRANULARITY != 0 {
+            return INVALID_VALUE;
+        }
+        let layout = match Layout::from_size_align(size, (alignment as usize).max(VMM_GRANULARITY))
+        {
+            Ok(x) => x,
+            Err(_) => return INVALID_VALUE,
+        };
+        let base = alloc_zeroed(layout);
+        if base.is_null() {
+            return sys::cudaError_enum_CUDA_ERROR_OUT_OF_MEMORY;
+        }
+        self.lock().reservations.insert(base as u64, layout);
+        *ptr = base as sys::CUdeviceptr;
+        SUCCESS
+    }
+
+    unsafe fn cuMemAddressFree(&self, ptr: sys::CUdeviceptr, size: sys::size_t) -> sys::CUresult {
+        let mut state = self.lock();
+        match state.reservations.get(&ptr) {
+            Some(layout) if layout.size() == size as usize => {}
+            _ => return INVALID_VALUE,
+        }
+        // memory still mapped in the range must be unmapped first
+        if state.mappings.range(ptr..ptr + size).next().is_some() {
+            return INVALID_VALUE;
+        }
+        if let Some(layout) = state.reservations.remove(&ptr) {
+            dealloc(ptr as *mut u8, layout);
+        }
+        SUCCESS
+    }
+
+    unsafe fn cuMemCreate(
+        &self,
+        handle: *mut sys::CUmemGenericAllocationHandle,
+        size: sys::size_t,
+        prop: *const sys::CUmemAllocationProp,
+        _flags: c_ulonglong,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        if state.devices.get((*prop).location.id as usize).is_none() {
+            return INVALID_DEVICE;
+        }
+        if size == 0 || size as usize % VMM_GRANULARITY != 0 {
+            return INVALID_VALUE;
+        }
+        let out = state.handle() as sys::CUmemGenericAllocationHandle;
+        state.physical.insert(out, size as usize);
+        *handle = out;
+        SUCCESS
+    }
+
+    unsafe fn cuMemRelease(&self, handle: sys::CUmemGenericAllocationHandle) -> sys::CUresult {
+        match self.lock().physical.remove(&handle) {
+            Some(_) => SUCCESS,
+            None => INVALID_VALUE,
+        }
+    }
+
+    unsafe fn cuMemMap(
+        &self,
+        ptr: sys::CUdeviceptr,
+        size: sys::size_t,
+        offset: sys::size_t,
+        handle: sys::CUmemGenericAllocationHandle,
+        _flags: c_ulonglong,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        let (size, end) = (size as usize, ptr + size);
+        match state.physical.get(&handle) {
+            Some(physical) if offset as usize + size <= *physical => {}
+            _ => return INVALID_VALUE,
+        }
+        let reservation = state.reservations.range(..=ptr).next_back();
+        if reservation.is_none_or(|(base, layout)| end > base + layout.size() as u64)
+            || ptr as usize % VMM_GRANULARITY != 0
+            || size == 0
+            || size % VMM_GRANULARITY != 0
+        {
+            return INVALID_VALUE;
+        }
+        let previous = state.mappings.range(..end).next_back();
+        if previous.is_some_and(|(base, x)| base + x.size as u64 > ptr) {
+            return INVALID_VALUE;
+        }
+        let mapping = Mapping {
+            size,
+            accessible: false,
+        };
+        state.mappings.insert(ptr, mapping);
+        SUCCESS
+    }
+
+    unsafe fn cuMemUnmap(&self, ptr: sys::CUdeviceptr, size: sys::size_t) -> sys::CUresult {
+        let mut state = self.lock();
+        // the range must cover whole mappings
+        let end = ptr + size;
+        if !state.mappings.contains_key(&ptr)
+            || !state.covered_by_mappings(ptr, size as usize, false)
+            || state
+                .mappings
+                .range(..end)
+                .next_back()
+                .is_some_and(|(base, x)| base + x.size as u64 > end)
+        {
+            return INVALID_VALUE;
+        }
+        let unmapped: Vec<_> = state.mappings.range(ptr..end).map(|(x, _)| *x).collect();
+        for base in unmapped {
+            state.mappings.remove(&base);
+        }
+        SUCCESS
+    }
+
+    unsafe fn cuMemSetAccess(
+        &self,
+        ptr: sys::CUdeviceptr,
+        size: sys::size_t,
+        desc: *const sys::CUmemAccessDesc,
+        count: sys::size_t,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        if count == 0 || !state.covered_by_mappings(ptr, size as usize, false) {
+            return INVALID_VALUE;
+        }
+        let desc = std::slice::from_raw_parts(desc, count as usize);
+        if desc
+            .iter()
+            .any(|x| state.devices.get(x.location.id as usize).is_none())
+        {
+            return INVALID_DEVICE;
+        }
+        // every device shares the host memory, so any access flag makes the range accessible to all
+        let accessible = desc
+            .iter()
+            .any(|x| x.flags != sys::CUmemAccess_flags_enum_CU_MEM_ACCESS_FLAGS_PROT_NONE);
+        let start = state
+            .mappings
+            .range(..=ptr)
+            .next_back()
+            .map_or(ptr, |(x, _)| *x);
+        for (_, mapping) in state.mappings.range_mut(start..ptr + size) {
+            mapping.accessible = accessible;
+        }
+        SUCCESS
+    }
+
+    unsafe fn cuMemHostGetDevicePointer_v2(
+        &self,
+        pdptr: *mut sys::CUdeviceptr,
+        p: *mut c_void,
+        _flags: c_uint,
+    ) -> sys::CUresult {
+        if !self.lock().is_mapped(p as u64, 1) {
+            return INVALID_VALUE;
+        }
+        *pdptr = p as sys::CUdeviceptr;
+        SUCCESS
+    }
+
+    unsafe fn cuMemHostRegister_v2(
+        &self,
+        p: *mut c_void,
+        bytesize: sys::size_t,
+        _flags: c_uint,
+    ) -> sys::CUresult {
+        if p.is_null() || bytesize == 0 {
+            return INVALID_VALUE;
+        }
+        let mut state = self.lock();
+        let (start, end) = (p as u64, p as u64 + bytesize);
+        let overlapping = state.registered.range(..end).next_back();
+        if overlapping.is_some_and(|(base, size)| base + *size as u64 > start) {
+            return sys::cudaError_enum_CUDA_ERROR_HOST_MEMORY_ALREADY_REGISTERED;
+        }
+        state.registered.insert(start, bytesize as usize);
+        SUCCESS
+    }
+
+    unsafe fn cuMemHostUnregister(&self, p: *mut c_void) -> sys::CUresult {
+        match self.lock().registered.remove(&(p as u64)) {
+            Some(_) => SUCCESS,
+            None => sys::cudaError_enum_CUDA_ERROR_HOST_MEMORY_NOT_REGISTERED,
+        }
+    }
+
+    unsafe fn cuMemAdvise(
+        &self,
+        _devPtr: sys::CUdeviceptr,
+        _count: sys::size_t,
+        _advice: sys::CUmem_advise,
+        _device: sys::CUdevice,
+    ) -> sys::CUresult {
+        SUCCESS
+    }
+
+    unsafe fn cuMemPrefetchAsync(
+        &self,
+        _devPtr: sys::CUdeviceptr,
+        _count: sys::size_t,
+        _dstDevice: sys::CUdevice,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        match self.lock().stream(hStream) {
+            Some(_) => SUCCESS,
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuStreamAttachMemAsync(
+        &self,
+        hStream: sys::CUstream,
+        _dptr: sys::CUdeviceptr,
+        _length: sys::size_t,
+        _flags: c_uint,
+    ) -> sys::CUresult {
+        match self.lock().stream(hStream) {
+            Some(_) => SUCCESS,
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuMemcpy(
+        &self,
+        dst: sys::CUdeviceptr,
+        src: sys::CUdeviceptr,
+        ByteCount: sys::size_t,
+    ) -> sys::CUresult {
+        self.copy(dst, src, ByteCount as usize, false, false, None)
+    }
+
+    unsafe fn cuMemcpyAsync(
+        &self,
+        dst: sys::CUdeviceptr,
+        src: sys::CUdeviceptr,
+        ByteCount: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        self.copy(dst, src, ByteCount as usize, false, false, Some(hStream))
+    }
+
+    unsafe fn cuMemcpyHtoD_v2(
+        &self,
+        dstDevice: sys::CUdeviceptr,
+        srcHost: *const c_void,
+        ByteCount: sys::size_t,
+    ) -> sys::CUresult {
+        self.copy(
+            dstDevice,
+            srcHost as u64,
+            ByteCount as usize,
+            true,
+            false,
+            None,
+        )
+    }
+
+    unsafe fn cuMemcpyHtoDAsync_v2(
+        &self,
+        dstDevice: sys::CUdeviceptr,
+        srcHost: *const c_void,
+        ByteCount: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        let len = ByteCount as usize;
+        self.copy(dstDevice, srcHost as u64, len, true, false, Some(hStream))
+    }
+
+    unsafe fn cuMemcpyDtoH_v2(
+        &self,
+        dstHost: *mut c_void,
+        srcDevice: sys::CUdeviceptr,
+        ByteCount: sys::size_t,
+    ) -> sys::CUresult {
+        self.copy(
+            dstHost as u64,
+            srcDevice,
+            ByteCount as usize,
+            false,
+            true,
+            None,
+        )
+    }
+
+    unsafe fn cuMemcpyDtoHAsync_v2(
+        &self,
+        dstHost: *mut c_void,
+        srcDevice: sys::CUdeviceptr,
+        ByteCount: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        let len = ByteCount as usize;
+        self.copy(dstHost as u64, srcDevice, len, false, true, Some(hStream))
+    }
+
+    unsafe fn cuMemsetD8_v2(
+        &self,
+        dstDevice: sys::CUdeviceptr,
+        uc: c_uchar,
+        N: sys::size_t,
+    ) -> sys::CUresult {
+        self.set(dstDevice, uc as u32, 1, N as usize, None)
+    }
+
+    unsafe fn cuMemsetD8Async(
+        &self,
+        dstDevice: sys::CUdeviceptr,
+        uc: c_uchar,
+        N: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        self.set(dstDevice, uc as u32, 1, N as usize, Some(hStream))
+    }
+
+    unsafe fn cuMemsetD16_v2(
+        &self,
+        dstDevice: sys::CUdeviceptr,
+        us: c_ushort,
+        N: sys::size_t,
+    ) -> sys::CUresult {
+        self.set(dstDevice, us as u32, 2, N as usize, None)
+    }
+
+    unsafe fn cuMemsetD16Async(
+        &self,
+        dstDevice: sys::CUdeviceptr,
+        us: c_ushort,
+        N: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        self.set(dstDevice, us as u32, 2, N as usize, Some(hStream))
+    }
+
+    unsafe fn cuMemsetD32_v2(
+        &self,
+        dstDevice: sys::CUdeviceptr,
+        ui: c_uint,
+        N: sys::size_t,
+    ) -> sys::CUresult {
+        self.set(dstDevice, ui, 4, N as usize, None)
+    }
+
+    unsafe fn cuMemsetD32Async(
+        &self,
+        dstDevice: sys::CUdeviceptr,
+        ui: c_uint,
+        N: sys::size_t,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        self.set(dstDevice, ui, 4, N as usize, Some(hStream))
+    }
+
+    unsafe fn cuStreamCreate(&self, phStream: *mut sys::CUstream, _flags: c_uint) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        let handle = state.handle();
+        let stream = StreamState {
+            context: current_context(),
+            ..Default::default()
+        };
+        state.streams.insert(handle, stream);
+        *phStream = handle as sys::CUstream;
+        SUCCESS
+    }
+
+    unsafe fn cuStreamDestroy_v2(&self, hStream: sys::CUstream) -> sys::CUresult {
+        let key = stream_key(hStream);
+        if key == 0 || !self.lock().streams.contains_key(&key) {
+            return INVALID_HANDLE;
+        }
+        // queued work still completes after the stream is destroyed
+        self.run(key, |_| false);
+        self.lock().streams.remove(&key);
+        SUCCESS
+    }
+
+    unsafe fn cuStreamSynchronize(&self, hStream: sys::CUstream) -> sys::CUresult {
+        if let Some(capture) = self.lock().capture(hStream) {
+            return capture.unsupported();
+        }
+        let (key, context) = match self.lock().stream(hStream) {
+            Some((key, stream)) => (key, stream.context.or_else(current_context)),
+            None => return INVALID_HANDLE,
+        };
+        self.run(key, |_| false);
+        let mut state = self.lock();
+        state.release_pools();
+        state.take_failure(context)
+    }
+
+    unsafe fn cuStreamQuery(&self, hStream: sys::CUstream) -> sys::CUresult {
+        match self.lock().stream(hStream) {
+            Some((
+                _,
+                StreamState {
+                    capture: Some(capture),
+                    ..
+                },
+            )) => capture.unsupported(),
+            Some((_, stream)) if stream.ops.is_empty() && stream.running == 0 => SUCCESS,
+            Some(_) => NOT_READY,
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuStreamBeginCapture_v2(
+        &self,
+        hStream: sys::CUstream,
+        _mode: sys::CUstreamCaptureMode,
+    ) -> sys::CUresult {
+        // the legacy default stream synchronizes with every other stream, so it cannot capture
+        if stream_key(hStream) == 0 {
+            return CAPTURE_UNSUPPORTED;
+        }
+        match self.lock().stream(hStream) {
+            Some((
+                _,
+                StreamState {
+                    capture: Some(_), ..
+                },
+            )) => ILLEGAL_STATE,
+            Some((_, stream)) => {
+                stream.capture = Some(Capture::default());
+                SUCCESS
+            }
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuStreamEndCapture(
+        &self,
+        hStream: sys::CUstream,
+        phGraph: *mut sys::CUgraph,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        let capture = match state.stream(hStream) {
+            Some((_, stream)) => stream.capture.take(),
+            None => return INVALID_HANDLE,
+        };
+        match capture {
+            Some(capture) if capture.invalidated => {
+                *phGraph = null_mut();
+                CAPTURE_INVALIDATED
+            }
+            Some(capture) => {
+                let handle = state.handle();
+                state.graphs.insert(handle, capture.nodes);
+                *phGraph = handle as sys::CUgraph;
+                SUCCESS
+            }
+            None => ILLEGAL_STATE,
+        }
+    }
+
+    unsafe fn cuStreamIsCapturing(
+        &self,
+        hStream: sys::CUstream,
+        captureStatus: *mut sys::CUstreamCaptureStatus,
+    ) -> sys::CUresult {
+        *captureStatus = match self.lock().stream(hStream) {
+            Some((_, stream)) => match &stream.capture {
+                None => sys::CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_NONE,
+                Some(x) if x.invalidated => {
+                    sys::CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_INVALIDATED
+                }
+                Some(_) => sys::CUstreamCaptureStatus_enum_CU_STREAM_CAPTURE_STATUS_ACTIVE,
+            },
+            None => return INVALID_HANDLE,
+        };
+        SUCCESS
+    }
+
+    unsafe fn cuStreamWaitEvent(
+        &self,
+        hStream: sys::CUstream,
+        hEvent: sys::CUevent,
+        _flags: c_uint,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        match state.wait_op(hEvent as usize) {
+            Some(op) => state.enqueue(hStream, op),
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuLaunchHostFunc(
+        &self,
+        hStream: sys::CUstream,
+        fn_: sys::CUhostFn,
+        userData: *mut c_void,
+    ) -> sys::CUresult {
+        let function = match fn_ {
+            Some(x) => x,
+            None => return INVALID_VALUE,
+        };
+        let data = UserData(userData);
+        self.lock().enqueue(hStream, Op::Host { function, data })
+    }
+
+    unsafe fn cuEventCreate(&self, phEvent: *mut sys::CUevent, Flags: c_uint) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        let handle = state.handle();
+        state.events.insert(
+            handle,
+            EventState {
+                timing: Flags & sys::CUevent_flags_enum_CU_EVENT_DISABLE_TIMING == 0,
+                stream: 0,
+                recorded: 0,
+                completed: 0,
+                time: None,
+            },
+        );
+        *phEvent = handle as sys::CUevent;
+        SUCCESS
+    }
+
+    unsafe fn cuEventDestroy_v2(&self, hEvent: sys::CUevent) -> sys::CUresult {
+        match self.lock().events.remove(&(hEvent as usize)) {
+            Some(_) => SUCCESS,
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuEventRecord(&self, hEvent: sys::CUevent, hStream: sys::CUstream) -> sys::CUresult {
+        let mut state = self.lock();
+        let key = match state.stream(hStream) {
+            Some((key, _)) => key,
+            None => return INVALID_HANDLE,
+        };
+        // joining streams into a capture through events is not supported
+        if let Some(capture) = state.capture(hStream) {
+            return capture.unsupported();
+        }
+        match state.record_op(hEvent as usize, key) {
+            Some(op) => state.enqueue(hStream, op),
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuEventQuery(&self, hEvent: sys::CUevent) -> sys::CUresult {
+        match self.lock().events.get(&(hEvent as usize)) {
+            Some(event) if event.completed >= event.recorded => SUCCESS,
+            Some(_) => NOT_READY,
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuEventSynchronize(&self, hEvent: sys::CUevent) -> sys::CUresult {
+        let event = hEvent as usize;
+        let (stream, seq) = match self.lock().events.get(&event) {
+            Some(x) => (x.stream, x.recorded),
+            None => return INVALID_HANDLE,
+        };
+        self.run(stream, |state| state.event_complete(event, seq));
+        let mut state = self.lock();
+        state.release_pools();
+        state.take_failure(current_context())
+    }
+
+    unsafe fn cuEventElapsedTime(
+        &self,
+        pMilliseconds: *mut f32,
+        hStart: sys::CUevent,
+        hEnd: sys::CUevent,
+    ) -> sys::CUresult {
+        let state = self.lock();
+        let (start, end) = match (
+            state.events.get(&(hStart as usize)),
+            state.events.get(&(hEnd as usize)),
+        ) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return INVALID_HANDLE,
+        };
+        if !start.timing || !end.timing {
+            return INVALID_HANDLE;
+        }
+        match (start.time, end.time) {
+            (Some(start_time), Some(end_time))
+                if start.completed >= start.recorded && end.completed >= end.recorded =>
+            {
+                *pMilliseconds = if end_time >= start_time {
+                    (end_time - start_time).as_secs_f32() * 1000.0
+                } else {
+                    -(start_time - end_time).as_secs_f32() * 1000.0
+                };
+                SUCCESS
+            }
+            _ => NOT_READY,
+        }
+    }
+
+    unsafe fn cuModuleLoadData(
+        &self,
+        module: *mut sys::CUmodule,
+        image: *const c_void,
+    ) -> sys::CUresult {
+        self.load_module(module, image)
+    }
+
+    unsafe fn cuModuleLoadFatBinary(
+        &self,
+        module: *mut sys::CUmodule,
+        fatCubin: *const c_void,
+    ) -> sys::CUresult {
+        self.load_module(module, fatCubin)
+    }
+
+    unsafe fn cuModuleUnload(&self, hmod: sys::CUmodule) -> sys::CUresult {
+        match self.lock().modules.remove(&(hmod as usize)) {
+            Some(_) => SUCCESS,
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuModuleGetFunction(
+        &self,
+        hfunc: *mut sys::CUfunction,
+        hmod: sys::CUmodule,
+        name: *const c_char,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        let programs = match state.modules.get(&(hmod as usize)) {
+            Some(x) => x,
+            None => return INVALID_HANDLE,
+        };
+        let name = CStr::from_ptr(name).to_string_lossy();
+        // registered kernels take precedence over interpreting the module
+        let program = programs.iter().find_map(|program| {
+            let params = program.kernel_params(&name)?;
+            Some(KernelEntry {
+                name: name.to_string(),
+                params: params.iter().map(|x| (x.size, x.align)).collect(),
+                kernel: MockKernel::Ptx(program.clone(), name.to_string()),
+            })
+        });
+        let entry = match state.kernels.iter().find(|x| x.name == name) {
+            Some(entry) => entry.clone(),
+            None => match program {
+                Some(entry) => entry,
+                None => return NOT_FOUND,
+            },
+        };
+        state.functions.push(entry);
+        *hfunc = function_handle(state.functions.len() - 1);
+        SUCCESS
+    }
+
+    unsafe fn cuModuleGetGlobal_v2(
+        &self,
+        dptr: *mut sys::CUdeviceptr,
+        bytes: *mut sys::size_t,
+        hmod: sys::CUmodule,
+        name: *const c_char,
+    ) -> sys::CUresult {
+        let state = self.lock();
+        let programs = match state.modules.get(&(hmod as usize)) {
+            Some(x) => x,
+            None => return INVALID_HANDLE,
+        };
+        let name = CStr::from_ptr(name).to_string_lossy();
+        match programs.iter().find_map(|x| x.global(&name)) {
+            Some((address, size)) => {
+                if !dptr.is_null() {
+                    *dptr = address;
+                }
+                if !bytes.is_null() {
+                    *bytes = size as sys::size_t;
+                }
+                SUCCESS
+            }
+            None => NOT_FOUND,
+        }
+    }
+
+    unsafe fn cuFuncGetAttribute(
+        &self,
+        pi: *mut c_int,
+        attrib: sys::CUfunction_attribute,
+        hfunc: sys::CUfunction,
+    ) -> sys::CUresult {
+        if function_index(hfunc) >= self.lock().functions.len() {
+            return INVALID_HANDLE;
+        }
+        *pi = match attrib {
+            sys::CUfunction_attribute_enum_CU_FUNC_ATTRIBUTE_MAX_THREADS_PER_BLOCK => 1024,
+            _ => 0,
+        };
+        SUCCESS
+    }
+
+    unsafe fn cuFuncSetAttribute(
+        &self,
+        hfunc: sys::CUfunction,
+        _attrib: sys::CUfunction_attribute,
+        _value: c_int,
+    ) -> sys::CUresult {
+        match function_index(hfunc) < self.lock().functions.len() {
+            true => SUCCESS,
+            false => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuFuncSetCacheConfig(
+        &self,
+        hfunc: sys::CUfunction,
+        _config: sys::CUfunc_cache,
+    ) -> sys::CUresult {
+        match function_index(hfunc) < self.lock().functions.len() {
+            true => SUCCESS,
+            false => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuFuncSetSharedMemConfig(
+        &self,
+        hfunc: sys::CUfunction,
+        _config: sys::CUsharedconfig,
+    ) -> sys::CUresult {
+        match function_index(hfunc) < self.lock().functions.len() {
+            true => SUCCESS,
+            false => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuLaunchKernel(
+        &self,
+        f: sys::CUfunction,
+        gridDimX: c_uint,
+        gridDimY: c_uint,
+        gridDimZ: c_uint,
+        blockDimX: c_uint,
+        blockDimY: c_uint,
+        blockDimZ: c_uint,
+        sharedMemBytes: c_uint,
+        hStream: sys::CUstream,
+        kernelParams: *mut *mut c_void,
+        extra: *mut *mut c_void,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        let op = state.launch_op(
+            f,
+            [gridDimX, gridDimY, gridDimZ],
+            [blockDimX, blockDimY, blockDimZ],
+            sharedMemBytes,
+            kernelParams,
+            extra,
+        );
+        match op {
+            Ok(op) => state.enqueue(hStream, op),
+            Err(e) => e,
+        }
+    }
+
+    unsafe fn cuGraphCreate(&self, phGraph: *mut sys::CUgraph, _flags: c_uint) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        let handle = state.handle();
+        state.graphs.insert(handle, vec![]);
+        *phGraph = handle as sys::CUgraph;
+        SUCCESS
+    }
+
+    unsafe fn cuGraphDestroy(&self, hGraph: sys::CUgraph) -> sys::CUresult {
+        match self.lock().graphs.remove(&(hGraph as usize)) {
+            Some(_) => SUCCESS,
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuGraphAddEmptyNode(
+        &self,
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+    ) -> sys::CUresult {
+        let kind = NodeKind::Empty;
+        self.lock()
+            .add_node(phGraphNode, hGraph, dependencies, numDependencies, kind)
+    }
+
+    unsafe fn cuGraphAddKernelNode(
+        &self,
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        nodeParams: *const sys::CUDA_KERNEL_NODE_PARAMS,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        let params = &*nodeParams;
+        let op = state.launch_op(
+            params.func,
+            [params.gridDimX, params.gridDimY, params.gridDimZ],
+            [params.blockDimX, params.blockDimY, params.blockDimZ],
+            params.sharedMemBytes,
+            params.kernelParams,
+            params.extra,
+        );
+        match op {
+            Ok(op) => {
+                let kind = NodeKind::Op(op);
+                state.add_node(phGraphNode, hGraph, dependencies, numDependencies, kind)
+            }
+            Err(e) => e,
+        }
+    }
+
+    unsafe fn cuGraphAddMemcpyNode(
+        &self,
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        copyParams: *const sys::CUDA_MEMCPY3D,
+        _ctx: sys::CUcontext,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        match state.memcpy_op(&*copyParams) {
+            Ok(op) => {
+                let kind = NodeKind::Op(op);
+                state.add_node(phGraphNode, hGraph, dependencies, numDependencies, kind)
+            }
+            Err(e) => e,
+        }
+    }
+
+    unsafe fn cuGraphAddMemsetNode(
+        &self,
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        memsetParams: *const sys::CUDA_MEMSET_NODE_PARAMS,
+        _ctx: sys::CUcontext,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        match state.memset_op(&*memsetParams) {
+            Ok(op) => {
+                let kind = NodeKind::Op(op);
+                state.add_node(phGraphNode, hGraph, dependencies, numDependencies, kind)
+            }
+            Err(e) => e,
+        }
+    }
+
+    unsafe fn cuGraphAddHostNode(
+        &self,
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        nodeParams: *const sys::CUDA_HOST_NODE_PARAMS,
+    ) -> sys::CUresult {
+        let params = &*nodeParams;
+        let function = match params.fn_ {
+            Some(x) => x,
+            None => return INVALID_VALUE,
+        };
+        let data = UserData(params.userData);
+        let kind = NodeKind::Op(Op::Host { function, data });
+        self.lock()
+            .add_node(phGraphNode, hGraph, dependencies, numDependencies, kind)
+    }
+
+    unsafe fn cuGraphAddEventRecordNode(
+        &self,
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        event: sys::CUevent,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        if !state.events.contains_key(&(event as usize)) {
+            return INVALID_HANDLE;
+        }
+        let kind = NodeKind::Record(event as usize);
+        state.add_node(phGraphNode, hGraph, dependencies, numDependencies, kind)
+    }
+
+    unsafe fn cuGraphAddEventWaitNode(
+        &self,
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        event: sys::CUevent,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        if !state.events.contains_key(&(event as usize)) {
+            return INVALID_HANDLE;
+        }
+        let kind = NodeKind::Wait(event as usize);
+        state.add_node(phGraphNode, hGraph, dependencies, numDependencies, kind)
+    }
+
+    unsafe fn cuGraphAddChildGraphNode(
+        &self,
+        phGraphNode: *mut sys::CUgraphNode,
+        hGraph: sys::CUgraph,
+        dependencies: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+        childGraph: sys::CUgraph,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        let child = match state.graphs.get(&(childGraph as usize)) {
+            Some(x) => launch_order(x),
+            None => return INVALID_HANDLE,
+        };
+        match child {
+            Some(child) => {
+                let kind = NodeKind::Child(child);
+                state.add_node(phGraphNode, hGraph, dependencies, numDependencies, kind)
+            }
+            None => INVALID_VALUE,
+        }
+    }
+
+    unsafe fn cuGraphAddDependencies(
+        &self,
+        hGraph: sys::CUgraph,
+        from: *const sys::CUgraphNode,
+        to: *const sys::CUgraphNode,
+        numDependencies: sys::size_t,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        let nodes = match state.graphs.get_mut(&(hGraph as usize)) {
+            Some(x) => x,
+            None => return INVALID_HANDLE,
+        };
+        for i in 0..numDependencies as usize {
+            let (from, to) = (*from.add(i) as usize, *to.add(i) as usize);
+            if !nodes.iter().any(|x| x.handle == from) {
+                return INVALID_VALUE;
+            }
+            match nodes.iter_mut().find(|x| x.handle == to) {
+                Some(node) => node.dependencies.push(from),
+                None => return INVALID_VALUE,
+            }
+        }
+        SUCCESS
+    }
+
+    unsafe fn cuGraphExecKernelNodeSetParams(
+        &self,
+        hGraphExec: sys::CUgraphExec,
+        hNode: sys::CUgraphNode,
+        nodeParams: *const sys::CUDA_KERNEL_NODE_PARAMS,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        let params = &*nodeParams;
+        let op = state.launch_op(
+            params.func,
+            [params.gridDimX, params.gridDimY, params.gridDimZ],
+            [params.blockDimX, params.blockDimY, params.blockDimZ],
+            params.sharedMemBytes,
+            params.kernelParams,
+            params.extra,
+        );
+        match op {
+            Ok(op) => state.set_exec_node(hGraphExec, hNode, op),
+            Err(e) => e,
+        }
+    }
+
+    unsafe fn cuGraphExecMemcpyNodeSetParams(
+        &self,
+        hGraphExec: sys::CUgraphExec,
+        hNode: sys::CUgraphNode,
+        copyParams: *const sys::CUDA_MEMCPY3D,
+        _ctx: sys::CUcontext,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        match state.memcpy_op(&*copyParams) {
+            Ok(op) => state.set_exec_node(hGraphExec, hNode, op),
+            Err(e) => e,
+        }
+    }
+
+    unsafe fn cuGraphExecMemsetNodeSetParams(
+        &self,
+        hGraphExec: sys::CUgraphExec,
+        hNode: sys::CUgraphNode,
+        memsetParams: *const sys::CUDA_MEMSET_NODE_PARAMS,
+        _ctx: sys::CUcontext,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        match state.memset_op(&*memsetParams) {
+            Ok(op) => state.set_exec_node(hGraphExec, hNode, op),
+            Err(e) => e,
+        }
+    }
+
+    unsafe fn cuGraphExecUpdate(
+        &self,
+        hGraphExec: sys::CUgraphExec,
+        hGraph: sys::CUgraph,
+        hErrorNode_out: *mut sys::CUgraphNode,
+        updateResult_out: *mut sys::CUgraphExecUpdateResult,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        let graph = match state.graphs.get(&(hGraph as usize)) {
+            Some(x) => launch_order(x),
+            None => return INVALID_HANDLE,
+        };
+        let graph = match graph {
+            Some(x) => x,
+            None => return INVALID_VALUE,
+        };
+        let exec = match state.graph_execs.get_mut(&(hGraphExec as usize)) {
+            Some(x) => x,
+            None => return INVALID_HANDLE,
+        };
+        *hErrorNode_out = null_mut();
+        match update_nodes(exec, &graph) {
+            Ok(()) => {
+                *updateResult_out = sys::CUgraphExecUpdateResult_enum_CU_GRAPH_EXEC_UPDATE_SUCCESS;
+                SUCCESS
+            }
+            Err((result, node)) => {
+                *updateResult_out = result;
+                if let Some(node) = node {
+                    *hErrorNode_out = node as sys::CUgraphNode;
+                }
+                UPDATE_FAILURE
+            }
+        }
+    }
+
+    unsafe fn cuGraphGetNodes(
+        &self,
+        hGraph: sys::CUgraph,
+        nodes: *mut sys::CUgraphNode,
+        numNodes: *mut sys::size_t,
+    ) -> sys::CUresult {
+        let state = self.lock();
+        let graph = match state.graphs.get(&(hGraph as usize)) {
+            Some(x) => x,
+            None => return INVALID_HANDLE,
+        };
+        if !nodes.is_null() {
+            for (i, node) in graph.iter().take(*numNodes as usize).enumerate() {
+                *nodes.add(i) = node.handle as sys::CUgraphNode;
+            }
+        }
+        *numNodes = graph.len() as sys::size_t;
+        SUCCESS
+    }
+
+    unsafe fn cuGraphInstantiate_v2(
+        &self,
+        phGraphExec: *mut sys::CUgraphExec,
+        hGraph: sys::CUgraph,
+        _phErrorNode: *mut sys::CUgraphNode,
+        _logBuffer: *mut c_char,
+        _bufferSize: sys::size_t,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        let nodes = match state.graphs.get(&(hGraph as usize)) {
+            Some(x) => launch_order(x),
+            None => return INVALID_HANDLE,
+        };
+        let nodes = match nodes {
+            Some(x) => x,
+            None => return INVALID_VALUE,
+        };
+        let handle = state.handle();
+        state.graph_execs.insert(handle, nodes);
+        *phGraphExec = handle as sys::CUgraphExec;
+        SUCCESS
+    }
+
+    unsafe fn cuGraphExecDestroy(&self, hGraphExec: sys::CUgraphExec) -> sys::CUresult {
+        match self.lock().graph_execs.remove(&(hGraphExec as usize)) {
+            Some(_) => SUCCESS,
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuGraphLaunch(
+        &self,
+        hGraphExec: sys::CUgraphExec,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        let key = match state.stream(hStream) {
+            Some((key, _)) => key,
+            None => return INVALID_HANDLE,
+        };
+        if let Some(capture) = state.capture(hStream) {
+            return capture.unsupported();
+        }
+        let nodes = match state.graph_execs.get(&(hGraphExec as usize)) {
+            Some(x) => x.clone(),
+            None => return INVALID_HANDLE,
+        };
+        // nodes run one at a time in launch order, which satisfies their dependencies
+        let mut ops = vec![];
+        state.graph_ops(&nodes, key, &mut ops);
+        for op in ops {
+            let result = state.enqueue(hStream, op);
+            if result != SUCCESS {
+                return result;
+            }
+        }
+        SUCCESS
+    }
+
+    unsafe fn cuGraphUpload(
+        &self,
+        hGraphExec: sys::CUgraphExec,
+        hStream: sys::CUstream,
+    ) -> sys::CUresult {
+        let mut state = self.lock();
+        if state.stream(hStream).is_none()
+            || !state.graph_execs.contains_key(&(hGraphExec as usize))
+        {
+            return INVALID_HANDLE;
+        }
+        SUCCESS
+    }
+
+    unsafe fn cuLinkCreate_v2(
+        &self,
+        _numOptions: c_uint,
+        _options: *mut sys::CUjit_option,
+        _optionValues: *mut *mut c_void,
+        stateOut: *mut sys::CUlinkState,
+    ) -> sys::CUresult {
+        let mut state = match self.lock_context() {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
+        let handle = state.handle();
+        state.links.insert(handle, vec![]);
+        *stateOut = handle as sys::CUlinkState;
+        SUCCESS
+    }
+
+    unsafe fn cuLinkAddData_v2(
+        &self,
+        state: sys::CUlinkState,
+        _type_: sys::CUjitInputType,
+        data: *mut c_void,
+        size: sys::size_t,
+        _name: *const c_char,
+        _numOptions: c_uint,
+        _options: *mut sys::CUjit_option,
+        _optionValues: *mut *mut c_void,
+    ) -> sys::CUresult {
+        match self.lock().links.get_mut(&(state as usize)) {
+            // the linked image is the concatenation of the inputs, so PTX signatures can still be read from it
+            Some(link) => {
+                let data = std::slice::from_raw_parts(data as *const u8, size as usize);
+                link.extend_from_slice(data);
+                SUCCESS
+            }
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuLinkComplete(
+        &self,
+        state: sys::CUlinkState,
+        cubinOut: *mut *mut c_void,
+        sizeOut: *mut sys::size_t,
+    ) -> sys::CUresult {
+        match self.lock().links.get_mut(&(state as usize)) {
+            Some(link) => {
+                *cubinOut = link.as_mut_ptr() as *mut c_void;
+                *sizeOut = link.len() as sys::size_t;
+                SUCCESS
+            }
+            None => INVALID_HANDLE,
+        }
+    }
+
+    unsafe fn cuLinkDestroy(&self, state: sys::CUlinkState) -> sys::CUresult {
+        match self.lock().links.remove(&(state as usize)) {
+            Some(_) => SUCCESS,
+            None => INVALID_HANDLE,
+        }
+    }
+}
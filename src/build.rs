@@ -0,0 +1,620 @@
+//! Helpers for build scripts (`build.rs`) of crates shipping CUDA kernels
+
+use std::{
+    error::Error,
+    fmt,
+    fmt::Write as _,
+    io,
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus},
+};
+
+use crate::{CudaVersion, FatbinEntryKind, KernelParam, KernelSignature, PtxParseError, PtxType};
+
+/// An error from a build script helper
+#[derive(Debug)]
+pub enum BuildError {
+    /// Failed to read or write `path`
+    Io { path: PathBuf, error: io::Error },
+    /// Failed to parse the PTX file `path`
+    Ptx { path: PathBuf, error: PtxParseError },
+    /// nvcc failed on `source`
+    Nvcc {
+        source: PathBuf,
+        status: ExitStatus,
+        stderr: String,
+    },
+    /// No output directory was set and `OUT_DIR` is not set, i.e. outside of a build script
+    MissingOutDir,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            BuildError::Ptx { path, error } => write!(f, "{}: {}", path.display(), error),
+            BuildError::Nvcc {
+                source,
+                status,
+                stderr,
+            } => write!(
+                f,
+                "nvcc failed to compile {} ({}):\n{}",
+                source.display(),
+                status,
+                stderr
+            ),
+            BuildError::MissingOutDir => write!(f, "OUT_DIR is not set, use Nvcc::out_dir"),
+        }
+    }
+}
+
+impl Error for BuildError {}
+
+/// Generates Rust bindings for the kernels of PTX files, for use in build scripts.
+/// Each PTX file becomes a module holding the embedded PTX and a `Module` type with one typed launch method per `.entry`, so a changed kernel signature fails to compile at its call sites.
+///
+/// ```no_run
+/// // build.rs
+/// cuda_oxide::build::Kernels::new()
+///     .ptx("kernels/saxpy.ptx")
+///     .write(std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("kernels.rs"))
+///     .unwrap();
+/// ```
+///
+/// Then `include!(concat!(env!("OUT_DIR"), "/kernels.rs"));` in the crate, and launch with `unsafe { saxpy::Module::load(&handle)?.saxpy(&mut stream, config, x, y, n) }`.
+/// 64-bit integer parameters accept any [`KernelArg64`](crate::KernelArg64), as PTX does not tell pointers from integers, which is why launch methods are unsafe. Array parameters (structs passed by value) accept any [`KernelArgs`](crate::KernelArgs) and are checked when launching.
+#[derive(Debug, Clone, Default)]
+pub struct Kernels {
+    files: Vec<(String, PathBuf)>,
+}
+
+impl Kernels {
+    /// Creates a new empty [`Kernels`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a PTX file, generating a module named after its file stem in snake case, i.e. `matrixMul_kernel.ptx` becomes `matrix_mul_kernel`
+    pub fn ptx<P: AsRef<Path>>(self, path: P) -> Self {
+        let path = path.as_ref();
+        let name = path
+            .file_stem()
+            .map(|x| snake_case(&x.to_string_lossy()))
+            .unwrap_or_default();
+        self.ptx_named(&name, path)
+    }
+
+    /// Adds a PTX file, generating a module named `name`
+    pub fn ptx_named<P: AsRef<Path>>(mut self, name: &str, path: P) -> Self {
+        self.files
+            .push((identifier(name), path.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Generates the bindings as Rust source code
+    pub fn generate(&self) -> Result<String, BuildError> {
+        let mut out = String::new();
+        writeln!(
+            out,
+            "// generated by cuda_oxide::build::Kernels, do not edit"
+        )
+        .unwrap();
+        for (name, path) in &self.files {
+            let io_error = |error| BuildError::Io {
+                path: path.clone(),
+                error,
+            };
+            let path = std::fs::canonicalize(path).map_err(io_error)?;
+            let ptx = std::fs::read_to_string(&path).map_err(io_error)?;
+            let signatures = KernelSignature::parse_ptx(&ptx).map_err(|error| BuildError::Ptx {
+                path: path.clone(),
+                error,
+            })?;
+            // PTX compiled by `Nvcc` is already NUL terminated
+            let include = if ptx.ends_with('\0') {
+                format!("include_bytes!({:?})", path.to_string_lossy())
+            } else {
+                format!(
+                    "concat!(include_str!({:?}), \"\\0\").as_bytes()",
+                    path.to_string_lossy()
+                )
+            };
+            generate_module(&mut out, name, &path, &include, &signatures);
+        }
+        Ok(out)
+    }
+
+    /// Generates the bindings into the file `out`, i.e. in `OUT_DIR`, and tells cargo to rerun the build script when a PTX file changes
+    pub fn write<P: AsRef<Path>>(&self, out: P) -> Result<(), BuildError> {
+        for (_, path) in &self.files {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+        let source = self.generate()?;
+        std::fs::write(out.as_ref(), source).map_err(|error| BuildError::Io {
+            path: out.as_ref().to_path_buf(),
+            error,
+        })
+    }
+}
+
+/// Embeds an output of [`Nvcc`](crate::build::Nvcc) from `OUT_DIR` as a `&'static [u8; N]`, i.e. `include_kernel!("saxpy.fatbin")` or `include_kernel!("saxpy.compute_80.ptx")`
+#[macro_export]
+macro_rules! include_kernel {
+    ($name:expr) => {
+        include_bytes!(concat!(env!("OUT_DIR"), "/", $name))
+    };
+}
+
+/// The kind of image [`Nvcc`] compiles sources to
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NvccOutput {
+    /// PTX, one `<stem>.compute_XY.ptx` per architecture. The PTX is NUL terminated, as [`Module::load`](crate::Module::load) expects.
+    Ptx,
+    /// Cubins, one `<stem>.sm_XY.cubin` per architecture
+    Cubin,
+    /// A single `<stem>.fatbin` holding a cubin per architecture, and PTX of the newest architecture for newer devices
+    Fatbin,
+}
+
+impl NvccOutput {
+    fn extension(self) -> &'static str {
+        match self {
+            NvccOutput::Ptx => "ptx",
+            NvccOutput::Cubin => "cubin",
+            NvccOutput::Fatbin => "fatbin",
+        }
+    }
+}
+
+/// Compiles `.cu` sources with nvcc, for use in build scripts.
+/// Outputs are written to `OUT_DIR` and can be embedded with [`include_kernel!`](crate::include_kernel).
+/// Cargo is told to rerun the build script when a source, or any header it includes, changes.
+///
+/// ```no_run
+/// // build.rs
+/// cuda_oxide::build::Nvcc::new()
+///     .source("kernels/saxpy.cu")
+///     .output(cuda_oxide::build::NvccOutput::Fatbin)
+///     .arch((7, 0).into())
+///     .arch((8, 0).into())
+///     .flag("-O3")
+///     .compile()
+///     .unwrap();
+/// ```
+///
+/// Then `let image = cuda_oxide::include_kernel!("saxpy.fatbin");` in the crate.
+/// The nvcc binary is `NVCC` if set, otherwise `bin/nvcc` of `CUDA_PATH` or `CUDA_HOME`, otherwise `nvcc` from `PATH`.
+#[derive(Debug, Clone)]
+pub struct Nvcc {
+    nvcc: Option<PathBuf>,
+    sources: Vec<PathBuf>,
+    output: NvccOutput,
+    archs: Vec<CudaVersion>,
+    flags: Vec<String>,
+    out_dir: Option<PathBuf>,
+}
+
+impl Default for Nvcc {
+    fn default() -> Self {
+        Nvcc {
+            nvcc: None,
+            sources: vec![],
+            output: NvccOutput::Ptx,
+            archs: vec![],
+            flags: vec![],
+            out_dir: None,
+        }
+    }
+}
+
+impl Nvcc {
+    /// Creates a new [`Nvcc`] compiling to PTX for nvcc's default architecture
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the nvcc binary to run
+    pub fn nvcc<P: AsRef<Path>>(mut self, nvcc: P) -> Self {
+        self.nvcc = Some(nvcc.as_ref().to_path_buf());
+        self
+    }
+
+    /// Adds a `.cu` source file
+    pub fn source<P: AsRef<Path>>(mut self, source: P) -> Self {
+        self.sources.push(source.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the kind of image to compile to, [`NvccOutput::Ptx`] by default
+    pub fn output(mut self, output: NvccOutput) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Adds a target architecture, i.e. `(8, 0).into()` for `sm_80`. Without any, nvcc picks its default architecture and outputs are named `<stem>.<ext>`.
+    pub fn arch(mut self, arch: CudaVersion) -> Self {
+        self.archs.push(arch);
+        self
+    }
+
+    /// Adds a flag passed to nvcc, i.e. `-O3` or `--use_fast_math`
+    pub fn flag(mut self, flag: &str) -> Self {
+        self.flags.push(flag.to_string());
+        self
+    }
+
+    /// Adds an include directory
+    pub fn include<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.flags.push(format!("-I{}", dir.as_ref().display()));
+        self
+    }
+
+    /// Defines a preprocessor macro, with an optional value
+    pub fn define(mut self, name: &str, value: Option<&str>) -> Self {
+        self.flags.push(match value {
+            Some(value) => format!("-D{}={}", name, value),
+            None => format!("-D{}", name),
+        });
+        self
+    }
+
+    /// Sets the directory outputs are written to, `OUT_DIR` by default
+    pub fn out_dir<P: AsRef<Path>>(mut self, out_dir: P) -> Self {
+        self.out_dir = Some(out_dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Compiles all sources, returning the paths of the outputs
+    pub fn compile(&self) -> Result<Vec<PathBuf>, BuildError> {
+        let out_dir = match &self.out_dir {
+            Some(x) => x.clone(),
+            None => std::env::var_os("OUT_DIR")
+                .map(PathBuf::from)
+                .ok_or(BuildError::MissingOutDir)?,
+        };
+        let nvcc = self.nvcc.clone().unwrap_or_else(find_nvcc);
+        for var in &["NVCC", "CUDA_PATH", "CUDA_HOME"] {
+            println!("cargo:rerun-if-env-changed={}", var);
+        }
+
+        let mut outputs = vec![];
+        for source in &self.sources {
+            for dependency in self.dependencies(&nvcc, source)? {
+                println!("cargo:rerun-if-changed={}", dependency.display());
+            }
+            let stem = source
+                .file_stem()
+                .map(|x| x.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let extension = self.output.extension();
+
+            // (output, arch flags)
+            let mut jobs = vec![];
+            match (self.output, self.archs.iter().max()) {
+                (_, None) => jobs.push((format!("{}.{}", stem, extension), vec![])),
+                (NvccOutput::Fatbin, Some(newest)) => {
+                    let mut flags: Vec<String> = self
+                        .archs
+                        .iter()
+                        .map(|arch| {
+                            format!(
+                                "-gencode=arch=compute_{0}{1},code=sm_{0}{1}",
+                                arch.major, arch.minor
+                            )
+                        })
+                        .collect();
+                    flags.push(format!(
+                        "-gencode=arch=compute_{0}{1},code=compute_{0}{1}",
+                        newest.major, newest.minor
+                    ));
+                    jobs.push((format!("{}.{}", stem, extension), flags));
+                }
+                (output, Some(_)) => {
+                    let kind = if output == NvccOutput::Ptx {
+                        FatbinEntryKind::Ptx
+                    } else {
+                        FatbinEntryKind::Cubin
+                    };
+                    for arch in &self.archs {
+                        let name = kind.arch_name(*arch);
+                        jobs.push((
+                            format!("{}.{}.{}", stem, name, extension),
+                            vec![format!("-arch={}", name)],
+                        ));
+                    }
+                }
+            }
+
+            for (name, arch_flags) in jobs {
+                let output = out_dir.join(name);
+                let mut command = Command::new(&nvcc);
+                command
+                    .arg(format!("--{}", extension))
+                    .args(&arch_flags)
+                    .args(&self.flags)
+                    .arg("-o")
+                    .arg(&output)
+                    .arg(source);
+                run(command, &nvcc, source)?;
+                if self.output == NvccOutput::Ptx {
+                    nul_terminate(&output)?;
+                }
+                outputs.push(output);
+            }
+        }
+        Ok(outputs)
+    }
+
+    /// Lists `source` and the headers it includes, with `nvcc -M`
+    fn dependencies(&self, nvcc: &Path, source: &Path) -> Result<Vec<PathBuf>, BuildError> {
+        let mut command = Command::new(nvcc);
+        command.arg("-M").args(&self.flags).arg(source);
+        let stdout = run(command, nvcc, source)?;
+        let mut dependencies = parse_dependencies(&stdout);
+        if !dependencies.iter().any(|x| x == source) {
+            dependencies.insert(0, source.to_path_buf());
+        }
+        Ok(dependencies)
+    }
+}
+
+/// Runs nvcc, forwarding its stderr as cargo warnings and returning its stdout
+fn run(mut command: Command, nvcc: &Path, source: &Path) -> Result<String, BuildError> {
+    let output = command.output().map_err(|error| BuildError::Io {
+        path: nvcc.to_path_buf(),
+        error,
+    })?;
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    if !output.status.success() {
+        return Err(BuildError::Nvcc {
+            source: source.to_path_buf(),
+            status: output.status,
+            stderr,
+        });
+    }
+    for line in stderr.lines().filter(|x| !x.trim().is_empty()) {
+        println!("cargo:warning={}", line);
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn find_nvcc() -> PathBuf {
+    if let Some(nvcc) = std::env::var_os("NVCC") {
+        return nvcc.into();
+    }
+    for var in &["CUDA_PATH", "CUDA_HOME"] {
+        if let Some(root) = std::env::var_os(var) {
+            let nvcc = Path::new(&root).join("bin").join("nvcc");
+            if nvcc.exists() {
+                return nvcc;
+            }
+        }
+    }
+    "nvcc".into()
+}
+
+/// Appends a NUL to the file `path` if it does not end with one
+fn nul_terminate(path: &Path) -> Result<(), BuildError> {
+    let io_error = |error| BuildError::Io {
+        path: path.to_path_buf(),
+        error,
+    };
+    let mut data = std::fs::read(path).map_err(io_error)?;
+    if data.last() != Some(&0) {
+        data.push(0);
+        std::fs::write(path, data).map_err(io_error)?;
+    }
+    Ok(())
+}
+
+/// Parses the prerequisites of make rules, as output by `nvcc -M`
+fn parse_dependencies(rules: &str) -> Vec<PathBuf> {
+    let rules = rules.replace("\\\r\n", " ").replace("\\\n", " ");
+    let mut out: Vec<PathBuf> = vec![];
+    for line in rules.lines() {
+        // the target ends at the first `: `, which skips drive letters in Windows paths
+        let prerequisites = match line.find(": ") {
+            Some(x) => &line[x + 2..],
+            None => continue,
+        };
+        let mut path = String::new();
+        let mut chars = prerequisites.chars().peekable();
+        loop {
+            match chars.next() {
+                Some('\\') if chars.peek() == Some(&' ') => path.push(chars.next().unwrap()),
+                Some(c) if !c.is_whitespace() => path.push(c),
+                c => {
+                    if !path.is_empty() {
+                        let dependency = PathBuf::from(std::mem::take(&mut path));
+                        if !out.contains(&dependency) {
+                            out.push(dependency);
+                        }
+                    }
+                    if c.is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+fn generate_module(
+    out: &mut String,
+    name: &str,
+    path: &Path,
+    include: &str,
+    signatures: &[KernelSignature],
+) {
+    let file = path
+        .file_name()
+        .map(|x| x.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    writeln!(
+        out,
+        r#"
+#[allow(non_snake_case, clippy::too_many_arguments)]
+pub mod {name} {{
+    /// The NUL terminated PTX source of `{file}`
+    pub const PTX: &[u8] = {include};
+
+    /// A loaded `{file}` module, with a launch method per kernel
+    pub struct Module<'a> {{
+        inner: ::cuda_oxide::Module<'a>,
+    }}
+
+    impl<'a> Module<'a> {{
+        /// Loads the module into the context of `handle`, JIT compiling it for the current device
+        pub fn load(handle: &::std::sync::Arc<::cuda_oxide::Handle<'a>>) -> ::cuda_oxide::CudaResult<Self> {{
+            Ok(Module {{
+                inner: ::cuda_oxide::Module::load(handle, PTX)?,
+            }})
+        }}
+
+        /// Gets the underlying [`Module`](::cuda_oxide::Module)
+        pub fn inner(&self) -> &::cuda_oxide::Module<'a> {{
+            &self.inner
+        }}"#,
+        name = name,
+        file = file,
+        include = include,
+    )
+    .unwrap();
+
+    for signature in signatures {
+        let mut method = identifier(&signature.name);
+        if method == "load" || method == "inner" {
+            method.push('_');
+        }
+        let params: Vec<(String, String)> = signature
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                let mut name = param
+                    .name
+                    .strip_prefix(&format!("{}_", signature.name))
+                    .map(identifier)
+                    .unwrap_or_else(|| identifier(&param.name));
+                if ["stream", "config", "function", "self"].contains(&&*name) {
+                    name.push('_');
+                }
+                if name.is_empty() {
+                    name = format!("param_{}", i);
+                }
+                (name, rust_type(param))
+            })
+            .collect();
+
+        writeln!(
+            out,
+            r#"
+        /// Launches `{kernel}` on `stream`, see [`Stream::launch_checked`](::cuda_oxide::Stream::launch_checked).
+        /// It is undefined behavior to pass in 64-bit integers the kernel uses as pointers which do not point to device memory it can access.
+        pub unsafe fn {method}(
+            &self,
+            stream: &mut ::cuda_oxide::Stream<'a>,
+            config: ::cuda_oxide::LaunchConfig,"#,
+            kernel = signature.name,
+            method = method,
+        )
+        .unwrap();
+        for (name, ty) in &params {
+            writeln!(out, "            {}: {},", name, ty).unwrap();
+        }
+        writeln!(
+            out,
+            r#"        ) -> Result<(), ::cuda_oxide::LaunchError> {{
+            let function = self.inner.get_function({kernel:?})?;
+            unsafe {{
+                stream.launch_checked(
+                    &function,
+                    config.grid_dim,
+                    config.block_dim,
+                    config.shared_mem_size,
+                    {args},
+                )
+            }}
+        }}"#,
+            kernel = signature.name,
+            args = tuple(&params.iter().map(|x| &*x.0).collect::<Vec<_>>()),
+        )
+        .unwrap();
+    }
+    writeln!(out, "    }}\n}}").unwrap();
+}
+
+/// Gets the Rust parameter type of a kernel parameter
+fn rust_type(param: &KernelParam) -> String {
+    if param.array_len.is_some() {
+        return "impl ::cuda_oxide::KernelArgs".to_string();
+    }
+    match param.ty {
+        Some(PtxType::B8) | Some(PtxType::U8) => "u8",
+        Some(PtxType::S8) => "i8",
+        // half precision floats are passed as their bits
+        Some(PtxType::B16) | Some(PtxType::U16) | Some(PtxType::F16) | Some(PtxType::BF16) => "u16",
+        Some(PtxType::S16) => "i16",
+        Some(PtxType::B32) | Some(PtxType::U32) | Some(PtxType::F16x2) | Some(PtxType::BF16x2) => {
+            "u32"
+        }
+        Some(PtxType::S32) => "i32",
+        Some(PtxType::F32) => "f32",
+        Some(PtxType::B64) | Some(PtxType::U64) => "impl ::cuda_oxide::KernelArg64",
+        Some(PtxType::S64) => "i64",
+        Some(PtxType::F64) => "f64",
+        None => "impl ::cuda_oxide::KernelArgs",
+    }
+    .to_string()
+}
+
+/// Formats `items` as a tuple expression, nesting tuples of more than 16 elements as [`KernelParameters`](crate::KernelParameters) is only implemented up to 16
+fn tuple(items: &[&str]) -> String {
+    if items.len() <= 16 {
+        return match items.len() {
+            1 => format!("({},)", items[0]),
+            _ => format!("({})", items.join(", ")),
+        };
+    }
+    let chunks: Vec<String> = items.chunks(16).map(tuple).collect();
+    tuple(&chunks.iter().map(|x| &**x).collect::<Vec<_>>())
+}
+
+/// Converts `name` to snake case, i.e. `matrixMul_kernel` to `matrix_mul_kernel`
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut last_lower = false;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() && last_lower {
+            out.push('_');
+        }
+        last_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        out.push(c.to_ascii_lowercase());
+    }
+    identifier(&out)
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Converts `name` into a valid Rust identifier
+fn identifier(name: &str) -> String {
+    let mut out: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+    if KEYWORDS.contains(&&*out) || out == "_" {
+        out.push('_');
+    }
+    out
+}
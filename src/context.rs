@@ -1,6 +1,6 @@
 use crate::*;
 use num_enum::TryFromPrimitive;
-use std::{ptr::null_mut, rc::Rc};
+use std::{marker::PhantomData, ptr::null_mut, sync::Arc};
 
 /// A CUDA application context.
 /// To start interacting with a device, you want to [`Context::enter`]
@@ -9,94 +9,115 @@ pub struct Context {
     pub(crate) inner: *mut sys::CUctx_st,
 }
 
+// libcuda contexts are not tied to the thread that created them, any thread can make one current and use it.
+// No operation relies on a context being current between calls: each makes its context current with a `ContextGuard` for the duration of the call,
+// so a `Context` can be used, and destroyed, from any thread. `Handle` only gives out `&Context`, so it is shared the same way.
+unsafe impl Send for Context {}
+unsafe impl Sync for Context {}
+
 impl Context {
     /// Creates a new [`Context`] for a given [`Device`]
     pub fn new(device: &Device) -> CudaResult<Context> {
         let mut inner = null_mut();
         cuda_error(unsafe {
-            sys::cuCtxCreate_v2(
+            backend::cuCtxCreate_v2(
                 &mut inner as *mut _,
                 sys::CUctx_flags_enum_CU_CTX_SCHED_BLOCKING_SYNC,
                 device.handle,
             )
         })?;
-        Ok(Context { inner })
+        let context = Context { inner };
+        // creating a context makes it current, it is only made current by `ContextGuard`s instead
+        cuda_error(unsafe { backend::cuCtxPopCurrent_v2(&mut null_mut()) })?;
+        Ok(context)
     }
 
     /// Gets the API version of the [`Context`].
     /// This is not the compute capability of the device and probably not what you are looking for. See [`Device::compute_capability`]
     pub fn version(&self) -> CudaResult<CudaVersion> {
         let mut out = 0u32;
-        cuda_error(unsafe { sys::cuCtxGetApiVersion(self.inner, &mut out as *mut u32) })?;
+        cuda_error(unsafe { backend::cuCtxGetApiVersion(self.inner, &mut out as *mut u32) })?;
         Ok(out.into())
     }
 
+    /// Gets the [`Device`] the [`Context`] was created for
+    pub fn device(&self) -> CudaResult<Device> {
+        let _current = self.push_current()?;
+        let mut out = 0;
+        cuda_error(unsafe { backend::cuCtxGetDevice(&mut out as *mut _) })?;
+        Ok(Device { handle: out })
+    }
+
     /// Synchronize a [`Context`], running all active handles to completion
     pub fn synchronize(&self) -> CudaResult<()> {
-        cuda_error(unsafe { sys::cuCtxSynchronize() })
+        let _current = self.push_current()?;
+        cuda_error(unsafe { backend::cuCtxSynchronize() })
     }
 
     /// Set a CUDA context limit
     pub fn set_limit(&mut self, limit: LimitType, value: u64) -> CudaResult<()> {
-        cuda_error(unsafe { sys::cuCtxSetLimit(limit as u32, value as sys::size_t) })
+        let _current = self.push_current()?;
+        cuda_error(unsafe { backend::cuCtxSetLimit(limit as u32, value as sys::size_t) })
     }
 
     /// Get a CUDA context limit
     pub fn get_limit(&self, limit: LimitType) -> CudaResult<u64> {
+        let _current = self.push_current()?;
         let mut out: sys::size_t = 0;
-        cuda_error(unsafe { sys::cuCtxGetLimit(&mut out as *mut sys::size_t, limit as u32) })?;
+        cuda_error(unsafe { backend::cuCtxGetLimit(&mut out as *mut sys::size_t, limit as u32) })?;
         Ok(out as u64)
     }
 
-    /// Enter a [`Context`], consuming a mutable reference to the context, and allowing thread-local operations to happen.
-    pub fn enter<'a>(&'a mut self) -> CudaResult<Rc<Handle<'a>>> {
-        cuda_error(unsafe { sys::cuCtxSetCurrent(self.inner) })?;
-        Ok(Rc::new(Handle {
-            context: self,
-            // async_stream_pool: RefCell::new(vec![]),
-        }))
+    /// Enter a [`Context`], consuming a mutable reference to the context, and allowing operations to happen.
+    /// The [`Handle`] can be shared between threads: operations make the context current on the calling thread while they run.
+    pub fn enter<'a>(&'a mut self) -> CudaResult<Arc<Handle<'a>>> {
+        Ok(Arc::new(Handle { context: self }))
+    }
+
+    /// Makes this [`Context`] current on the calling thread until the returned guard is dropped, restoring the previously current context.
+    /// Operations of this crate do this themselves, this is only needed to call `libcuda` directly.
+    pub fn push_current(&self) -> CudaResult<ContextGuard> {
+        cuda_error(unsafe { backend::cuCtxPushCurrent_v2(self.inner) })?;
+        Ok(ContextGuard { _p: PhantomData })
     }
 }
 
 impl Drop for Context {
     fn drop(&mut self) {
-        if let Err(e) = cuda_error(unsafe { sys::cuCtxDestroy_v2(self.inner) }) {
+        if let Err(e) = cuda_error(unsafe { backend::cuCtxDestroy_v2(self.inner) }) {
             eprintln!("CUDA: failed to destroy cuda context: {:?}", e);
         }
     }
 }
 
-/// A CUDA [`Context`] handle for executing thread-local operations.
+/// A CUDA [`Context`] handle for executing operations, from any thread.
+/// The [`Context`] stays mutably borrowed while the handle, or anything created with it, is alive, so it can't be dropped or entered again. The handle itself only gives shared access to it.
 pub struct Handle<'a> {
     pub(crate) context: &'a mut Context,
-    // async_stream_pool: RefCell<Vec<Stream<'a>>>,
 }
 
 impl<'a> Handle<'a> {
     /// Get an immutable reference to the source context.
     pub fn context(&self) -> &Context {
-        &self.context
+        self.context
+    }
+
+    /// Makes the context current on the calling thread for an operation, see [`Context::push_current`]
+    pub(crate) fn current(&self) -> CudaResult<ContextGuard> {
+        self.context.push_current()
     }
+}
 
-    // pub(crate) fn get_async_stream(self: &Rc<Handle<'a>>) -> CudaResult<Stream<'a>> {
-    //     let mut pool = self.async_stream_pool.borrow_mut();
-    //     if pool.is_empty() {
-    //         Stream::new(self)
-    //     } else {
-    //         Ok(pool.pop().unwrap())
-    //     }
-    // }
-
-    // pub(crate) fn reset_async_stream(self: &Rc<Handle<'a>>, stream: Stream<'a>) {
-    //     let mut pool = self.async_stream_pool.borrow_mut();
-    //     pool.push(stream);
-    // }
+/// Keeps a [`Context`] current on the thread it was created on, see [`Context::push_current`]
+pub struct ContextGuard {
+    // not `Send`, as the context must be popped from the thread it was pushed on
+    _p: PhantomData<*const ()>,
 }
 
-impl<'a> Drop for Handle<'a> {
+impl Drop for ContextGuard {
     fn drop(&mut self) {
-        if let Err(e) = cuda_error(unsafe { sys::cuCtxSetCurrent(null_mut()) }) {
-            eprintln!("CUDA: error dropping context handle: {:?}", e);
+        if let Err(e) = cuda_error(unsafe { backend::cuCtxPopCurrent_v2(&mut null_mut()) }) {
+            eprintln!("CUDA: failed to pop current context: {:?}", e);
         }
     }
 }
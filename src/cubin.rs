@@ -0,0 +1,520 @@
+use std::{convert::TryInto, error::Error, fmt};
+
+use crate::{KernelParam, KernelSignature};
+
+/// Information about a kernel entry point of a [`Module`](crate::Module), see [`Module::functions`](crate::Module::functions)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionInfo {
+    /// The parameter list of the kernel
+    pub signature: KernelSignature,
+    /// The number of registers used by each thread, if known
+    pub registers: Option<u32>,
+    /// The size in bytes of statically-allocated shared memory, if known
+    pub shared_size: Option<u64>,
+}
+
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+const ELFCLASS64: u8 = 2;
+const ELFDATA2LSB: u8 = 1;
+const EM_CUDA: u16 = 190;
+
+const SHT_SYMTAB: u32 = 2;
+const SHT_NOBITS: u32 = 8;
+const STT_FUNC: u8 = 2;
+/// Set in `st_other` of kernel entry point symbols
+const STO_CUDA_ENTRY: u8 = 0x10;
+
+// `.nv.info` attribute value formats
+const EIFMT_NVAL: u8 = 0x01;
+const EIFMT_BVAL: u8 = 0x02;
+const EIFMT_HVAL: u8 = 0x03;
+const EIFMT_SVAL: u8 = 0x04;
+
+// `.nv.info` attributes
+const EIATTR_KPARAM_INFO: u8 = 0x17;
+const EIATTR_REGCOUNT: u8 = 0x2f;
+
+/// Checks if `data` looks like an ELF image, i.e. a cubin
+pub(crate) fn is_cubin(data: &[u8]) -> bool {
+    data.starts_with(ELF_MAGIC)
+}
+
+struct Section<'a> {
+    name: &'a str,
+    kind: u32,
+    size: u64,
+    link: u32,
+    info: u32,
+    data: &'a [u8],
+}
+
+struct Symbol<'a> {
+    name: &'a str,
+    info: u8,
+    other: u8,
+}
+
+fn read<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N], CubinParseError> {
+    offset
+        .checked_add(N)
+        .and_then(|end| data.get(offset..end))
+        .map(|x| x.try_into().unwrap())
+        .ok_or_else(|| CubinParseError::new("unexpected end of data"))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, CubinParseError> {
+    Ok(u16::from_le_bytes(read(data, offset)?))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, CubinParseError> {
+    Ok(u32::from_le_bytes(read(data, offset)?))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64, CubinParseError> {
+    Ok(u64::from_le_bytes(read(data, offset)?))
+}
+
+fn slice(data: &[u8], offset: u64, size: u64) -> Result<&[u8], CubinParseError> {
+    offset
+        .checked_add(size)
+        .and_then(|end| data.get(offset as usize..end as usize))
+        .ok_or_else(|| CubinParseError::new("section out of bounds"))
+}
+
+/// Reads a NUL terminated string at `offset` of the string table `strtab`
+fn read_str(strtab: &[u8], offset: u32) -> Result<&str, CubinParseError> {
+    let tail = strtab
+        .get(offset as usize..)
+        .ok_or_else(|| CubinParseError::new("string out of bounds"))?;
+    let len = tail
+        .iter()
+        .position(|x| *x == 0)
+        .ok_or_else(|| CubinParseError::new("unterminated string"))?;
+    std::str::from_utf8(&tail[..len]).map_err(|_| CubinParseError::new("invalid UTF-8 string"))
+}
+
+fn sections(data: &[u8]) -> Result<Vec<Section<'_>>, CubinParseError> {
+    if !is_cubin(data) {
+        return Err(CubinParseError::new("not an ELF image"));
+    }
+    if data.get(4) != Some(&ELFCLASS64) || data.get(5) != Some(&ELFDATA2LSB) {
+        return Err(CubinParseError::new(
+            "only 64-bit little endian cubins are supported",
+        ));
+    }
+    if read_u16(data, 18)? != EM_CUDA {
+        return Err(CubinParseError::new("not a CUDA ELF image"));
+    }
+    let shoff = read_u64(data, 40)? as usize;
+    let shentsize = read_u16(data, 58)? as usize;
+    let shnum = read_u16(data, 60)? as usize;
+    let shstrndx = read_u16(data, 62)? as usize;
+
+    let mut headers = vec![];
+    for i in 0..shnum {
+        let header = i
+            .checked_mul(shentsize)
+            .and_then(|x| x.checked_add(shoff))
+            .ok_or_else(|| CubinParseError::new("section header out of bounds"))?;
+        let header = &read::<64>(data, header)?[..];
+        let name = read_u32(header, 0)?;
+        let kind = read_u32(header, 4)?;
+        let offset = read_u64(header, 24)?;
+        let size = read_u64(header, 32)?;
+        let link = read_u32(header, 40)?;
+        let info = read_u32(header, 44)?;
+        // SHT_NOBITS sections, i.e. `.nv.shared.*`, have a size but no data
+        let section_data = if kind == SHT_NOBITS {
+            &[][..]
+        } else {
+            slice(data, offset, size)?
+        };
+        headers.push((name, kind, size, link, info, section_data));
+    }
+    let strtab = headers
+        .get(shstrndx)
+        .ok_or_else(|| CubinParseError::new("missing section name table"))?
+        .5;
+    headers
+        .into_iter()
+        .map(|(name, kind, size, link, info, data)| {
+            Ok(Section {
+                name: read_str(strtab, name)?,
+                kind,
+                size,
+                link,
+                info,
+                data,
+            })
+        })
+        .collect()
+}
+
+fn symbols<'a>(sections: &[Section<'a>]) -> Result<Vec<Symbol<'a>>, CubinParseError> {
+    let symtab = match sections.iter().find(|x| x.kind == SHT_SYMTAB) {
+        Some(x) => x,
+        None => return Ok(vec![]),
+    };
+    let strtab = sections
+        .get(symtab.link as usize)
+        .ok_or_else(|| CubinParseError::new("missing symbol name table"))?
+        .data;
+    symtab
+        .data
+        .chunks_exact(24)
+        .map(|symbol| {
+            Ok(Symbol {
+                name: read_str(strtab, read_u32(symbol, 0)?)?,
+                info: symbol[4],
+                other: symbol[5],
+            })
+        })
+        .collect()
+}
+
+/// Iterates the `(attribute, value)` records of a `.nv.info` section
+fn attributes(data: &[u8]) -> Result<Vec<(u8, &[u8])>, CubinParseError> {
+    let mut out = vec![];
+    let mut offset = 0usize;
+    while offset < data.len() {
+        let [format, attribute] = read::<2>(data, offset)?;
+        offset += 2;
+        // records are 4 byte aligned, so values without a size are padded to 2 bytes
+        let len = match format {
+            EIFMT_NVAL | EIFMT_BVAL | EIFMT_HVAL => 2,
+            EIFMT_SVAL => {
+                offset += 2;
+                read_u16(data, offset - 2)? as usize
+            }
+            _ => {
+                return Err(CubinParseError::new(format!(
+                    "unknown .nv.info attribute format {:#x}",
+                    format
+                )))
+            }
+        };
+        out.push((attribute, slice(data, offset as u64, len as u64)?));
+        offset += len;
+    }
+    Ok(out)
+}
+
+/// Gets the largest power of two dividing `x`, up to 16
+fn inferred_align(x: usize) -> usize {
+    if x == 0 {
+        16
+    } else {
+        (1 << x.trailing_zeros()).min(16)
+    }
+}
+
+impl FunctionInfo {
+    /// Reads the kernel entry points of a cubin ELF image, with parameter offsets and sizes from its `.nv.info` sections.
+    /// Cubins do not record parameter names or alignment, so parameters are named like PTX parameters (`<kernel>_param_<n>`) and their alignment is inferred from their size and offset.
+    pub fn parse_cubin(data: &[u8]) -> Result<Vec<FunctionInfo>, CubinParseError> {
+        let sections = sections(data)?;
+        let symbols = symbols(&sections)?;
+
+        let mut registers = vec![None; symbols.len()];
+        if let Some(info) = sections.iter().find(|x| x.name == ".nv.info") {
+            for (attribute, value) in attributes(info.data)? {
+                if attribute == EIATTR_REGCOUNT {
+                    let symbol = read_u32(value, 0)? as usize;
+                    if let Some(x) = registers.get_mut(symbol) {
+                        *x = Some(read_u32(value, 4)?);
+                    }
+                }
+            }
+        }
+
+        let mut out = vec![];
+        for (index, symbol) in symbols.iter().enumerate() {
+            if symbol.info & 0xf != STT_FUNC || symbol.other & STO_CUDA_ENTRY == 0 {
+                continue;
+            }
+            let section = |prefix: &str| {
+                sections
+                    .iter()
+                    .find(|x| x.name.strip_prefix(prefix) == Some(symbol.name))
+            };
+
+            let mut params = vec![];
+            if let Some(info) = section(".nv.info.") {
+                for (attribute, value) in attributes(info.data)? {
+                    if attribute != EIATTR_KPARAM_INFO {
+                        continue;
+                    }
+                    let ordinal = read_u16(value, 4)? as usize;
+                    let offset = read_u16(value, 6)? as usize;
+                    let size = (read_u32(value, 8)? >> 18) as usize & 0x3fff;
+                    params.push((ordinal, offset, size));
+                }
+            }
+            params.sort_unstable();
+
+            // the register count is also in the high byte of sh_info of the kernel's .text section
+            let text_registers = section(".text.").map(|x| x.info >> 24);
+            out.push(FunctionInfo {
+                signature: KernelSignature {
+                    name: symbol.name.to_string(),
+                    params: params
+                        .into_iter()
+                        .map(|(ordinal, offset, size)| KernelParam {
+                            name: format!("{}_param_{}", symbol.name, ordinal),
+                            offset,
+                            size,
+                            align: inferred_align(size).min(inferred_align(offset)),
+                            ty: None,
+                            array_len: None,
+                        })
+                        .collect(),
+                },
+                registers: registers[index].or(text_registers),
+                shared_size: Some(section(".nv.shared.").map(|x| x.size).unwrap_or(0)),
+            });
+        }
+        Ok(out)
+    }
+}
+
+/// An error from [`FunctionInfo::parse_cubin`]
+#[derive(Clone, Debug)]
+pub struct CubinParseError {
+    message: String,
+}
+
+impl CubinParseError {
+    fn new(message: impl Into<String>) -> Self {
+        CubinParseError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for CubinParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse cubin: {}", self.message)
+    }
+}
+
+impl Error for CubinParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHT_PROGBITS: u32 = 1;
+    const SHT_STRTAB: u32 = 3;
+    const STB_GLOBAL: u8 = 1 << 4;
+
+    /// Builds a 64-bit CUDA ELF image with a section per `(name, kind, data, link, info)`, after the null section and the section name table
+    fn elf(sections: &[(&str, u32, Vec<u8>, u32, u32)]) -> Vec<u8> {
+        let mut names = vec![0u8];
+        let mut name_offsets = vec![];
+        for name in [".shstrtab"].iter().chain(sections.iter().map(|x| &x.0)) {
+            name_offsets.push(names.len() as u32);
+            names.extend_from_slice(name.as_bytes());
+            names.push(0);
+        }
+        let mut headers = vec![(0, 0, 0, 0, 0, 0)];
+        let mut out = vec![0u8; 64];
+        headers.push((name_offsets[0], SHT_STRTAB, out.len(), names.len(), 0, 0));
+        out.extend_from_slice(&names);
+        for (i, (_, kind, data, link, info)) in sections.iter().enumerate() {
+            // SHT_NOBITS sections take their size from the length of `data`, without storing it
+            let size = data.len();
+            headers.push((name_offsets[i + 1], *kind, out.len(), size, *link, *info));
+            if *kind != SHT_NOBITS {
+                out.extend_from_slice(data);
+            }
+        }
+        let shoff = out.len();
+        for (name, kind, offset, size, link, info) in &headers {
+            let mut header = [0u8; 64];
+            header[0..4].copy_from_slice(&name.to_le_bytes());
+            header[4..8].copy_from_slice(&kind.to_le_bytes());
+            header[24..32].copy_from_slice(&(*offset as u64).to_le_bytes());
+            header[32..40].copy_from_slice(&(*size as u64).to_le_bytes());
+            header[40..44].copy_from_slice(&link.to_le_bytes());
+            header[44..48].copy_from_slice(&info.to_le_bytes());
+            out.extend_from_slice(&header);
+        }
+        out[0..4].copy_from_slice(ELF_MAGIC);
+        out[4] = ELFCLASS64;
+        out[5] = ELFDATA2LSB;
+        out[18..20].copy_from_slice(&EM_CUDA.to_le_bytes());
+        out[40..48].copy_from_slice(&(shoff as u64).to_le_bytes());
+        out[58..60].copy_from_slice(&64u16.to_le_bytes());
+        out[60..62].copy_from_slice(&(headers.len() as u16).to_le_bytes());
+        // the section name table is always section 1
+        out[62..64].copy_from_slice(&1u16.to_le_bytes());
+        out
+    }
+
+    /// Builds a symbol table and its string table from `(name, info, other)`, after the null symbol
+    fn symtab(symbols: &[(&str, u8, u8)]) -> (Vec<u8>, Vec<u8>) {
+        let mut names = vec![0u8];
+        let mut out = vec![0u8; 24];
+        for (name, info, other) in symbols {
+            let mut symbol = [0u8; 24];
+            symbol[0..4].copy_from_slice(&(names.len() as u32).to_le_bytes());
+            symbol[4] = *info;
+            symbol[5] = *other;
+            out.extend_from_slice(&symbol);
+            names.extend_from_slice(name.as_bytes());
+            names.push(0);
+        }
+        (out, names)
+    }
+
+    /// Encodes an `EIFMT_SVAL` `.nv.info` record
+    fn sval(attribute: u8, value: &[u8]) -> Vec<u8> {
+        let mut out = vec![EIFMT_SVAL, attribute];
+        out.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        out.extend_from_slice(value);
+        out
+    }
+
+    /// Encodes an `EIATTR_KPARAM_INFO` record
+    fn kparam(ordinal: u16, offset: u16, size: u32) -> Vec<u8> {
+        let mut value = vec![0u8; 4];
+        value.extend_from_slice(&ordinal.to_le_bytes());
+        value.extend_from_slice(&offset.to_le_bytes());
+        value.extend_from_slice(&(size << 18).to_le_bytes());
+        sval(EIATTR_KPARAM_INFO, &value)
+    }
+
+    /// Encodes an `EIATTR_REGCOUNT` record
+    fn regcount(symbol: u32, registers: u32) -> Vec<u8> {
+        let mut value = symbol.to_le_bytes().to_vec();
+        value.extend_from_slice(&registers.to_le_bytes());
+        sval(EIATTR_REGCOUNT, &value)
+    }
+
+    const ENTRY: u8 = STB_GLOBAL | STT_FUNC;
+
+    /// A cubin with kernels `add` and `scale`, and a device function `helper`
+    fn cubin() -> Vec<u8> {
+        let (symbols, names) = symtab(&[
+            ("add", ENTRY, STO_CUDA_ENTRY),
+            ("helper", ENTRY, 0),
+            ("scale", ENTRY, STO_CUDA_ENTRY),
+        ]);
+        // records in reverse order, as emitted by ptxas, with a 2 byte value attribute in between
+        let mut add_info = kparam(2, 16, 24);
+        add_info.extend_from_slice(&[EIFMT_HVAL, 0x12, 0, 0]);
+        add_info.extend(kparam(1, 8, 4));
+        add_info.extend(kparam(0, 0, 8));
+        elf(&[
+            (".strtab", SHT_STRTAB, names, 0, 0),
+            // linked to the string table, section 2
+            (".symtab", SHT_SYMTAB, symbols, 2, 0),
+            (".nv.info", SHT_PROGBITS, regcount(1, 32), 0, 0),
+            (".nv.info.add", SHT_PROGBITS, add_info, 0, 0),
+            (".nv.info.scale", SHT_PROGBITS, kparam(0, 0, 4), 0, 0),
+            (".text.add", SHT_PROGBITS, vec![0; 16], 3, 8 << 24),
+            (".text.scale", SHT_PROGBITS, vec![0; 16], 3, 12 << 24),
+            (".nv.shared.add", SHT_NOBITS, vec![0; 1024], 0, 0),
+        ])
+    }
+
+    #[test]
+    fn entries() {
+        let functions = FunctionInfo::parse_cubin(&cubin()).unwrap();
+        assert_eq!(
+            functions
+                .iter()
+                .map(|x| &*x.signature.name)
+                .collect::<Vec<_>>(),
+            vec!["add", "scale"]
+        );
+
+        let add = &functions[0];
+        assert_eq!(
+            add.signature
+                .params
+                .iter()
+                .map(|x| (&*x.name, x.offset, x.size, x.align))
+                .collect::<Vec<_>>(),
+            vec![
+                ("add_param_0", 0, 8, 8),
+                ("add_param_1", 8, 4, 4),
+                ("add_param_2", 16, 24, 8),
+            ]
+        );
+        assert!(add
+            .signature
+            .params
+            .iter()
+            .all(|x| x.ty.is_none() && x.array_len.is_none()));
+        // `.nv.info` takes precedence over the `.text` section
+        assert_eq!(add.registers, Some(32));
+        assert_eq!(add.shared_size, Some(1024));
+
+        let scale = &functions[1];
+        assert_eq!(scale.signature.params.len(), 1);
+        assert_eq!(scale.registers, Some(12));
+        assert_eq!(scale.shared_size, Some(0));
+    }
+
+    #[test]
+    fn no_symbols() {
+        let image = elf(&[(".text", SHT_PROGBITS, vec![0; 4], 0, 0)]);
+        assert!(FunctionInfo::parse_cubin(&image).unwrap().is_empty());
+    }
+
+    fn parse_err(image: &[u8]) -> String {
+        FunctionInfo::parse_cubin(image).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn malformed() {
+        let image = cubin();
+        assert!(parse_err(b"\0asm").contains("not an ELF image"));
+        let mut bad = image.clone();
+        bad[4] = 1;
+        assert!(parse_err(&bad).contains("64-bit"));
+        let mut bad = image.clone();
+        bad[18] = 0x3e;
+        assert!(parse_err(&bad).contains("not a CUDA ELF image"));
+        let mut bad = image.clone();
+        bad[62] = 0xff;
+        assert!(parse_err(&bad).contains("missing section name table"));
+        let mut bad = image.clone();
+        bad[40..48].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(parse_err(&bad).contains("unexpected end of data"));
+        // a section extending past the end of the image
+        let mut bad = image.clone();
+        let shoff = u64::from_le_bytes(image[40..48].try_into().unwrap()) as usize;
+        bad[shoff + 64 + 32..shoff + 64 + 40].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(parse_err(&bad).contains("section out of bounds"));
+
+        // section headers are at the end, so every truncation is caught
+        for len in 0..image.len() {
+            assert!(FunctionInfo::parse_cubin(&image[..len]).is_err());
+        }
+    }
+
+    #[test]
+    fn malformed_nv_info() {
+        let (symbols, names) = symtab(&[("k", ENTRY, STO_CUDA_ENTRY)]);
+        let image = |info: Vec<u8>| {
+            elf(&[
+                (".strtab", SHT_STRTAB, names.clone(), 0, 0),
+                (".symtab", SHT_SYMTAB, symbols.clone(), 2, 0),
+                (".nv.info.k", SHT_PROGBITS, info, 0, 0),
+            ])
+        };
+        assert!(parse_err(&image(vec![0x7f, EIATTR_KPARAM_INFO, 0, 0]))
+            .contains("unknown .nv.info attribute format"));
+        // a record claiming more data than the section holds
+        let mut record = kparam(0, 0, 4);
+        record[2] = 0xff;
+        assert!(parse_err(&image(record)).contains("out of bounds"));
+        // a parameter record too short to hold its fields
+        assert!(
+            parse_err(&image(sval(EIATTR_KPARAM_INFO, &[0; 4]))).contains("unexpected end of data")
+        );
+        assert!(parse_err(&image(vec![EIFMT_SVAL])).contains("unexpected end of data"));
+    }
+}
@@ -25,7 +25,9 @@ impl Device {
     /// Fetches a human-readable name from the device
     pub fn name(&self) -> CudaResult<String> {
         let mut buf = [0u8; 256];
-        cuda_error(unsafe { sys::cuDeviceGetName(buf.as_mut_ptr() as *mut i8, 256, self.handle) })?;
+        cuda_error(unsafe {
+            backend::cuDeviceGetName(buf.as_mut_ptr() as *mut i8, 256, self.handle)
+        })?;
         Ok(
             String::from_utf8_lossy(&buf[..buf.iter().position(|x| *x == 0).unwrap_or(0)])
                 .into_owned(),
@@ -35,7 +37,9 @@ impl Device {
     /// Gets a UUID from the device
     pub fn uuid(&self) -> CudaResult<u128> {
         let mut out = 0u128;
-        cuda_error(unsafe { sys::cuDeviceGetUuid(&mut out as *mut u128 as *mut _, self.handle) })?;
+        cuda_error(unsafe {
+            backend::cuDeviceGetUuid(&mut out as *mut u128 as *mut _, self.handle)
+        })?;
         Ok(out)
     }
 
@@ -43,7 +47,7 @@ impl Device {
     pub fn memory_size(&self) -> CudaResult<usize> {
         let mut memory_size = 0usize;
         cuda_error(unsafe {
-            sys::cuDeviceTotalMem_v2(&mut memory_size as *mut usize as *mut _, self.handle)
+            backend::cuDeviceTotalMem_v2(&mut memory_size as *mut usize as *mut _, self.handle)
         })?;
         Ok(memory_size)
     }
@@ -52,7 +56,7 @@ impl Device {
     pub fn get_attribute(&self, attribute: DeviceAttribute) -> CudaResult<i32> {
         let mut out = 0i32;
         cuda_error(unsafe {
-            sys::cuDeviceGetAttribute(&mut out as *mut i32, attribute as u32, self.handle)
+            backend::cuDeviceGetAttribute(&mut out as *mut i32, attribute as u32, self.handle)
         })?;
         Ok(out)
     }
@@ -73,7 +77,7 @@ impl Device {
     ) -> CudaResult<usize> {
         let mut out = 0usize;
         cuda_error(unsafe {
-            sys::cuDeviceGetTexture1DLinearMaxWidth(
+            backend::cuDeviceGetTexture1DLinearMaxWidth(
                 &mut out as *mut usize as *mut _,
                 format as u32,
                 channels,
@@ -88,11 +92,11 @@ impl Cuda {
     /// List all CUDA-enabled devices on the host
     pub fn list_devices() -> CudaResult<Vec<Device>> {
         let mut count = 0i32;
-        cuda_error(unsafe { sys::cuDeviceGetCount(&mut count as *mut i32) })?;
+        cuda_error(unsafe { backend::cuDeviceGetCount(&mut count as *mut i32) })?;
         let mut out = Vec::with_capacity(count as usize);
         for i in 0..count {
             let mut device = Device { handle: 0 };
-            cuda_error(unsafe { sys::cuDeviceGet(&mut device.handle as *mut i32, i) })?;
+            cuda_error(unsafe { backend::cuDeviceGet(&mut device.handle as *mut i32, i) })?;
             out.push(device);
         }
         Ok(out)
@@ -0,0 +1,43 @@
+use std::marker::PhantomData;
+
+/// Types that can be bitwise copied to and from the device, i.e. for [`DeviceBox`](crate::DeviceBox) and [`DeviceSlice`](crate::DeviceSlice).
+/// Implemented for primitive numbers and arrays of [`DeviceCopy`] types.
+/// Every bit pattern must be a valid value, as device memory can hold anything (i.e. after [`DeviceBox::alloc`](crate::DeviceBox::alloc)) and is loaded back as is. So `bool`, `char` and enums are not [`DeviceCopy`], load them as integers instead.
+/// Tuples are not [`DeviceCopy`] either, as their layout is unspecified.
+/// References, raw pointers and heap allocating types are not [`DeviceCopy`], since they are meaningless on the device.
+/// [`DeviceCopy`] types are plain data, and so `Send` and `Sync`, which lets buffers of them cross threads.
+/// With the `derive` feature, it can be derived for `#[repr(C)]` or `#[repr(transparent)]` structs whose fields are all [`DeviceCopy`], which upholds the requirements below.
+///
+/// # Safety
+/// Every bit pattern of the size of the type must be a valid value, and the type must have a defined layout, i.e. `#[repr(C)]`.
+pub unsafe trait DeviceCopy: Copy + Send + Sync + 'static {}
+
+macro_rules! device_copy {
+    ($($t:ty),*) => {
+        $(
+            unsafe impl DeviceCopy for $t {}
+        )*
+    };
+}
+
+device_copy!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    ()
+);
+
+unsafe impl<T: DeviceCopy, const N: usize> DeviceCopy for [T; N] {}
+
+unsafe impl<T: Send + Sync + 'static> DeviceCopy for PhantomData<T> {}
@@ -1,6 +1,7 @@
 use std::ops::{Deref, DerefMut};
 
 /// A dimensional value equivalent to a 3-tuple of u32
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Dim3(pub (u32, u32, u32));
 
 impl Deref for Dim3 {
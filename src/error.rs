@@ -297,6 +297,9 @@ pub enum ErrorCode {
     GraphExecUpdateFailure = 910,
     #[doc = "This indicates that an unknown internal error has occurred."]
     Unknown = 999,
+    #[doc = "The CUDA driver library could not be loaded. Only returned with the `dynamic-loading` feature,"]
+    #[doc = "i.e. from `Cuda::init` on machines without a driver installed."]
+    DriverNotFound = 10000,
 }
 
 impl fmt::Display for ErrorCode {
@@ -0,0 +1,102 @@
+use std::{ptr::null_mut, sync::Arc, time::Duration};
+
+use crate::*;
+
+/// Options for creating an [`Event`], can generally just be defaulted.
+#[derive(Clone, Copy, Debug)]
+pub struct EventOptions {
+    /// Block the calling thread in [`Event::sync`] instead of spinning
+    pub blocking_sync: bool,
+    /// Record timing data, required for [`Event::elapsed_since`]. Events without timing have lower overhead when used for synchronization only.
+    pub timing: bool,
+}
+
+impl Default for EventOptions {
+    fn default() -> Self {
+        EventOptions {
+            blocking_sync: true,
+            timing: true,
+        }
+    }
+}
+
+/// A marker in a [`Stream`] that can be waited on, queried, or used to measure elapsed time between two points.
+pub struct Event<'a> {
+    pub(crate) inner: *mut sys::CUevent_st,
+    handle: Arc<Handle<'a>>,
+}
+
+// libcuda events can be used from any thread
+unsafe impl<'a> Send for Event<'a> {}
+unsafe impl<'a> Sync for Event<'a> {}
+
+impl<'a> Event<'a> {
+    /// Creates a new [`Event`] for a handle with default options
+    pub fn new(handle: &Arc<Handle<'a>>) -> CudaResult<Self> {
+        Self::new_with_options(handle, EventOptions::default())
+    }
+
+    /// Creates a new [`Event`] for a handle with the given options
+    pub fn new_with_options(handle: &Arc<Handle<'a>>, options: EventOptions) -> CudaResult<Self> {
+        let mut flags = sys::CUevent_flags_enum_CU_EVENT_DEFAULT;
+        if options.blocking_sync {
+            flags |= sys::CUevent_flags_enum_CU_EVENT_BLOCKING_SYNC;
+        }
+        if !options.timing {
+            flags |= sys::CUevent_flags_enum_CU_EVENT_DISABLE_TIMING;
+        }
+        let _current = handle.current()?;
+        let mut out = null_mut();
+        cuda_error(unsafe { backend::cuEventCreate(&mut out as *mut _, flags) })?;
+        Ok(Self {
+            inner: out,
+            handle: handle.clone(),
+        })
+    }
+
+    /// Returns `Ok(true)` if all work captured by the most recent [`Stream::record`] has completed, or if the event was never recorded.
+    pub fn is_complete(&self) -> CudaResult<bool> {
+        let _current = self.handle.current()?;
+        match cuda_error(unsafe { backend::cuEventQuery(self.inner) }) {
+            Ok(()) => Ok(true),
+            Err(ErrorCode::NotReady) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Blocks until all work captured by the most recent [`Stream::record`] has completed
+    pub fn sync(&self) -> CudaResult<()> {
+        let _current = self.handle.current()?;
+        cuda_error(unsafe { backend::cuEventSynchronize(self.inner) })
+    }
+
+    /// Gets a [`CudaFuture`] completing once all work captured by the most recent [`Stream::record`] has completed.
+    /// Recording the event again afterwards does not affect the future.
+    pub fn future(&self) -> CudaResult<CudaFuture<'a, ()>> {
+        let mut stream = Stream::new(&self.handle)?;
+        stream.wait_event(self)?;
+        let mut future = CudaFuture::new(&mut stream, ())?;
+        future.stream = Some(stream);
+        Ok(future)
+    }
+
+    /// Gets the time elapsed between `start` and `self` being reached. Both events must have been recorded, completed, and created with timing enabled.
+    /// Resolution is around 0.5 microseconds.
+    pub fn elapsed_since(&self, start: &Event<'_>) -> CudaResult<Duration> {
+        let _current = self.handle.current()?;
+        let mut out = 0f32;
+        cuda_error(unsafe {
+            backend::cuEventElapsedTime(&mut out as *mut f32, start.inner, self.inner)
+        })?;
+        Ok(Duration::from_secs_f64(out.max(0.0) as f64 / 1000.0))
+    }
+}
+
+impl<'a> Drop for Event<'a> {
+    fn drop(&mut self) {
+        let _current = self.handle.current();
+        if let Err(e) = cuda_error(unsafe { backend::cuEventDestroy_v2(self.inner) }) {
+            eprintln!("CUDA: failed to destroy event: {:?}", e);
+        }
+    }
+}
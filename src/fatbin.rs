@@ -0,0 +1,437 @@
+use std::{borrow::Cow, convert::TryInto, error::Error, fmt};
+
+use crate::{CudaVersion, ErrorCode};
+
+const FATBIN_MAGIC: u32 = 0xba55ed50;
+const FATBIN_VERSION: u16 = 1;
+const FATBIN_HEADER_SIZE: usize = 16;
+const FATBIN_ENTRY_HEADER_SIZE: usize = 64;
+const FATBIN_ENTRY_VERSION: u16 = 0x0101;
+
+const FATBIN_FLAG_64BIT: u64 = 0x1;
+const FATBIN_FLAG_LINUX: u64 = 0x10;
+const FATBIN_FLAG_COMPRESSED_LZ4: u64 = 0x2000;
+const FATBIN_FLAG_COMPRESSED_ZSTD: u64 = 0x8000;
+
+/// The kind of image held by a [`FatbinEntry`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FatbinEntryKind {
+    /// PTX assembly, JIT compiled for any device of at least its architecture
+    Ptx,
+    /// A cubin holding SASS, only runnable on devices of the same major architecture and at least its minor architecture
+    Cubin,
+}
+
+impl FatbinEntryKind {
+    fn from_raw(raw: u16) -> Option<Self> {
+        match raw {
+            1 => Some(FatbinEntryKind::Ptx),
+            2 => Some(FatbinEntryKind::Cubin),
+            _ => None,
+        }
+    }
+
+    fn to_raw(self) -> u16 {
+        match self {
+            FatbinEntryKind::Ptx => 1,
+            FatbinEntryKind::Cubin => 2,
+        }
+    }
+
+    /// Gets the architecture name of an image of this kind, i.e. `sm_80` or `compute_80`
+    pub fn arch_name(self, arch: CudaVersion) -> String {
+        match self {
+            FatbinEntryKind::Ptx => format!("compute_{}{}", arch.major, arch.minor),
+            FatbinEntryKind::Cubin => format!("sm_{}{}", arch.major, arch.minor),
+        }
+    }
+}
+
+/// The compression of a [`FatbinEntry`] payload
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FatbinCompression {
+    None,
+    /// LZ4 block compression, which can be decompressed by [`FatbinEntry::data`]
+    Lz4,
+    /// Zstandard compression, used by newer toolkits. Not supported by [`FatbinEntry::data`].
+    Zstd,
+}
+
+/// A single PTX or cubin image embedded in a [`Fatbin`]
+#[derive(Debug, Clone)]
+pub struct FatbinEntry<'a> {
+    /// The kind of image
+    pub kind: FatbinEntryKind,
+    /// The target architecture, i.e. 8.0 for `sm_80`
+    pub arch: CudaVersion,
+    /// The compression of the payload
+    pub compression: FatbinCompression,
+    payload: &'a [u8],
+    uncompressed_size: usize,
+}
+
+impl<'a> FatbinEntry<'a> {
+    /// Gets the raw, possibly compressed, payload of this entry
+    pub fn payload(&self) -> &'a [u8] {
+        self.payload
+    }
+
+    /// Gets the decompressed image of this entry
+    pub fn data(&self) -> Result<Cow<'a, [u8]>, FatbinError> {
+        match self.compression {
+            FatbinCompression::None => Ok(Cow::Borrowed(self.payload)),
+            FatbinCompression::Lz4 => Ok(Cow::Owned(lz4_decompress(
+                self.payload,
+                self.uncompressed_size,
+            )?)),
+            FatbinCompression::Zstd => Err(FatbinError::Invalid(
+                "zstd compressed fatbin entries are not supported".to_string(),
+            )),
+        }
+    }
+
+    /// Checks if this image can be loaded on a device of compute capability `target`
+    pub fn is_compatible(&self, target: CudaVersion) -> bool {
+        match self.kind {
+            FatbinEntryKind::Ptx => self.arch <= target,
+            FatbinEntryKind::Cubin => self.arch.major == target.major && self.arch <= target,
+        }
+    }
+}
+
+impl<'a> fmt::Display for FatbinEntry<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind.arch_name(self.arch))
+    }
+}
+
+/// A parsed fatbinary container, holding PTX and cubin images for several architectures
+#[derive(Debug, Clone)]
+pub struct Fatbin<'a> {
+    entries: Vec<FatbinEntry<'a>>,
+}
+
+fn read<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N], FatbinError> {
+    offset
+        .checked_add(N)
+        .and_then(|end| data.get(offset..end))
+        .map(|x| x.try_into().unwrap())
+        .ok_or_else(|| FatbinError::Invalid("unexpected end of data".to_string()))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, FatbinError> {
+    Ok(u16::from_le_bytes(read(data, offset)?))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, FatbinError> {
+    Ok(u32::from_le_bytes(read(data, offset)?))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64, FatbinError> {
+    Ok(u64::from_le_bytes(read(data, offset)?))
+}
+
+fn slice(data: &[u8], offset: usize, size: u64) -> Result<&[u8], FatbinError> {
+    offset
+        .checked_add(size as usize)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| FatbinError::Invalid("entry out of bounds".to_string()))
+}
+
+/// Checks if `data` starts with a fatbin header
+pub(crate) fn is_fatbin(data: &[u8]) -> bool {
+    read_u32(data, 0).ok() == Some(FATBIN_MAGIC)
+}
+
+impl<'a> Fatbin<'a> {
+    /// Parses a fatbin. Several concatenated containers, as found in the `.nv_fatbin` section of host binaries, are read as one.
+    pub fn parse(data: &'a [u8]) -> Result<Self, FatbinError> {
+        let mut entries = vec![];
+        let mut offset = 0usize;
+        while offset < data.len() {
+            if read_u32(data, offset)? != FATBIN_MAGIC {
+                if offset > 0 && data[offset..].iter().all(|x| *x == 0) {
+                    break;
+                }
+                return Err(FatbinError::Invalid("bad magic".to_string()));
+            }
+            let version = read_u16(data, offset + 4)?;
+            if version != FATBIN_VERSION {
+                return Err(FatbinError::Invalid(format!(
+                    "unsupported version {}",
+                    version
+                )));
+            }
+            let header_size = read_u16(data, offset + 6)? as usize;
+            if header_size < FATBIN_HEADER_SIZE {
+                return Err(FatbinError::Invalid("header too small".to_string()));
+            }
+            let container = slice(data, offset + header_size, read_u64(data, offset + 8)?)?;
+            offset += header_size + container.len();
+
+            let mut entry_offset = 0usize;
+            while entry_offset < container.len() {
+                let header = &container[entry_offset..];
+                let kind = read_u16(header, 0)?;
+                let header_size = read_u32(header, 4)? as usize;
+                if header_size < FATBIN_ENTRY_HEADER_SIZE {
+                    return Err(FatbinError::Invalid("entry header too small".to_string()));
+                }
+                let size = read_u64(header, 8)?;
+                let payload = slice(header, header_size, size)?;
+                entry_offset += header_size + payload.len();
+
+                let kind = match FatbinEntryKind::from_raw(kind) {
+                    Some(x) => x,
+                    // other entry kinds, i.e. LTO IR, are skipped
+                    None => continue,
+                };
+                let compressed_size = read_u32(header, 16)? as usize;
+                let arch = read_u32(header, 28)?;
+                let flags = read_u64(header, 40)?;
+                let uncompressed_size = read_u64(header, 56)? as usize;
+                let compression = if flags & FATBIN_FLAG_COMPRESSED_LZ4 != 0 {
+                    FatbinCompression::Lz4
+                } else if flags & FATBIN_FLAG_COMPRESSED_ZSTD != 0 {
+                    FatbinCompression::Zstd
+                } else {
+                    FatbinCompression::None
+                };
+                let payload = match compression {
+                    FatbinCompression::None => payload,
+                    _ => payload.get(..compressed_size).ok_or_else(|| {
+                        FatbinError::Invalid("compressed size out of bounds".to_string())
+                    })?,
+                };
+                entries.push(FatbinEntry {
+                    kind,
+                    arch: CudaVersion {
+                        major: arch / 10,
+                        minor: arch % 10,
+                    },
+                    compression,
+                    payload,
+                    uncompressed_size,
+                });
+            }
+        }
+        Ok(Fatbin { entries })
+    }
+
+    /// Gets all PTX and cubin entries of this fatbin
+    pub fn entries(&self) -> &[FatbinEntry<'a>] {
+        &self.entries
+    }
+
+    /// Picks the best entry for a device of compute capability `target`, i.e. from [`Device::compute_capability`](crate::Device::compute_capability).
+    /// The cubin of the highest compatible architecture is preferred, otherwise the PTX of the highest compatible architecture is JIT compiled.
+    pub fn select(&self, target: CudaVersion) -> Result<&FatbinEntry<'a>, FatbinError> {
+        let best = |kind: FatbinEntryKind| {
+            self.entries
+                .iter()
+                .filter(|x| x.kind == kind && x.is_compatible(target))
+                .max_by_key(|x| x.arch)
+        };
+        best(FatbinEntryKind::Cubin)
+            .or_else(|| best(FatbinEntryKind::Ptx))
+            .ok_or_else(|| FatbinError::NoCompatibleImage {
+                target,
+                available: self.entries.iter().map(|x| (x.kind, x.arch)).collect(),
+            })
+    }
+}
+
+/// Builds a fatbin from PTX and cubin images, loadable by [`Module::load_fatcubin`](crate::Module::load_fatcubin) and [`Linker::add`](crate::Linker::add) as a [`LinkerInputType::Fatbin`](crate::LinkerInputType::Fatbin).
+/// Images are stored uncompressed.
+#[derive(Debug, Clone, Default)]
+pub struct FatbinBuilder {
+    entries: Vec<(FatbinEntryKind, CudaVersion, Vec<u8>)>,
+}
+
+impl FatbinBuilder {
+    /// Creates a new empty [`FatbinBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an image of kind `kind` targeting `arch`, i.e. 8.0 for `sm_80` or `compute_80`
+    pub fn add(mut self, kind: FatbinEntryKind, arch: CudaVersion, data: &[u8]) -> Self {
+        let mut data = data.to_vec();
+        if kind == FatbinEntryKind::Ptx && data.last() != Some(&0) {
+            data.push(0);
+        }
+        self.entries.push((kind, arch, data));
+        self
+    }
+
+    /// Adds PTX assembly targeting `compute_<arch>`
+    pub fn add_ptx(self, arch: CudaVersion, ptx: &[u8]) -> Self {
+        self.add(FatbinEntryKind::Ptx, arch, ptx)
+    }
+
+    /// Adds a cubin targeting `sm_<arch>`
+    pub fn add_cubin(self, arch: CudaVersion, cubin: &[u8]) -> Self {
+        self.add(FatbinEntryKind::Cubin, arch, cubin)
+    }
+
+    /// Emits the fatbin
+    pub fn build(&self) -> Vec<u8> {
+        let mut entries = vec![];
+        for (kind, arch, data) in &self.entries {
+            // payloads are padded to keep entry headers 8 byte aligned
+            let size = data.len().next_multiple_of(8);
+            let (major, minor) = code_version(*kind, data);
+            let mut flags = FATBIN_FLAG_64BIT;
+            if cfg!(target_os = "linux") {
+                flags |= FATBIN_FLAG_LINUX;
+            }
+
+            entries.extend_from_slice(&kind.to_raw().to_le_bytes());
+            entries.extend_from_slice(&FATBIN_ENTRY_VERSION.to_le_bytes());
+            entries.extend_from_slice(&(FATBIN_ENTRY_HEADER_SIZE as u32).to_le_bytes());
+            entries.extend_from_slice(&(size as u64).to_le_bytes());
+            // compressed size and options offset
+            entries.extend_from_slice(&[0; 8]);
+            entries.extend_from_slice(&minor.to_le_bytes());
+            entries.extend_from_slice(&major.to_le_bytes());
+            entries.extend_from_slice(&(arch.major * 10 + arch.minor).to_le_bytes());
+            // object name offset and length
+            entries.extend_from_slice(&[0; 8]);
+            entries.extend_from_slice(&flags.to_le_bytes());
+            // reserved and uncompressed size
+            entries.extend_from_slice(&[0; 16]);
+            entries.extend_from_slice(data);
+            entries.resize(entries.len() + size - data.len(), 0);
+        }
+
+        let mut out = Vec::with_capacity(FATBIN_HEADER_SIZE + entries.len());
+        out.extend_from_slice(&FATBIN_MAGIC.to_le_bytes());
+        out.extend_from_slice(&FATBIN_VERSION.to_le_bytes());
+        out.extend_from_slice(&(FATBIN_HEADER_SIZE as u16).to_le_bytes());
+        out.extend_from_slice(&(entries.len() as u64).to_le_bytes());
+        out.extend_from_slice(&entries);
+        out
+    }
+}
+
+/// Gets the code version recorded in a fatbin entry header: the `.version` of PTX, or `1.<ELF ABI version>` of cubins
+fn code_version(kind: FatbinEntryKind, data: &[u8]) -> (u16, u16) {
+    match kind {
+        FatbinEntryKind::Cubin => (1, data.get(8).copied().unwrap_or(0) as u16),
+        FatbinEntryKind::Ptx => {
+            let len = data.iter().position(|x| *x == 0).unwrap_or(data.len());
+            String::from_utf8_lossy(&data[..len])
+                .lines()
+                .filter_map(|line| line.trim().strip_prefix(".version"))
+                .filter_map(|version| {
+                    let (major, minor) = version.trim().split_once('.')?;
+                    Some((major.parse().ok()?, minor.parse().ok()?))
+                })
+                .next()
+                .unwrap_or((0, 0))
+        }
+    }
+}
+
+/// Decompresses an LZ4 block of `size` decompressed bytes
+fn lz4_decompress(src: &[u8], size: usize) -> Result<Vec<u8>, FatbinError> {
+    let invalid = || FatbinError::Invalid("invalid LZ4 compressed entry".to_string());
+    let read_len = |src: &[u8], i: &mut usize, mut len: usize| -> Result<usize, FatbinError> {
+        if len == 15 {
+            loop {
+                let byte = *src.get(*i).ok_or_else(invalid)?;
+                *i += 1;
+                len += byte as usize;
+                if byte != 255 {
+                    break;
+                }
+            }
+        }
+        Ok(len)
+    };
+
+    // every input byte produces at most 255 output bytes, so larger sizes can only come from a malformed header
+    if size > src.len().saturating_mul(255) {
+        return Err(invalid());
+    }
+    let mut out = Vec::with_capacity(size);
+    let mut i = 0usize;
+    while i < src.len() {
+        let token = src[i];
+        i += 1;
+        let literals = read_len(src, &mut i, (token >> 4) as usize)?;
+        if out.len() + literals > size {
+            return Err(invalid());
+        }
+        out.extend_from_slice(src.get(i..i + literals).ok_or_else(invalid)?);
+        i += literals;
+        // the last sequence has no match
+        if i >= src.len() {
+            break;
+        }
+        let offset = u16::from_le_bytes(read(src, i).map_err(|_| invalid())?) as usize;
+        i += 2;
+        let len = read_len(src, &mut i, (token & 0xf) as usize)? + 4;
+        if offset == 0 || offset > out.len() || out.len() + len > size {
+            return Err(invalid());
+        }
+        let start = out.len() - offset;
+        // matches may overlap their own output, so copy byte by byte
+        for j in 0..len {
+            out.push(out[start + j]);
+        }
+    }
+    if out.len() != size {
+        return Err(invalid());
+    }
+    Ok(out)
+}
+
+/// An error from reading a [`Fatbin`] or loading it with [`Module::load_fatbin`](crate::Module::load_fatbin)
+#[derive(Debug, Clone)]
+pub enum FatbinError {
+    /// The fatbin is malformed or unsupported
+    Invalid(String),
+    /// No entry can be loaded on a device of compute capability `target`
+    NoCompatibleImage {
+        target: CudaVersion,
+        /// The kind and architecture of every entry
+        available: Vec<(FatbinEntryKind, CudaVersion)>,
+    },
+    /// Any other error
+    Cuda(ErrorCode),
+}
+
+impl fmt::Display for FatbinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FatbinError::Invalid(message) => write!(f, "invalid fatbin: {}", message),
+            FatbinError::NoCompatibleImage { target, available } => {
+                write!(
+                    f,
+                    "no compatible image for {}; available: ",
+                    FatbinEntryKind::Cubin.arch_name(*target)
+                )?;
+                if available.is_empty() {
+                    return write!(f, "none");
+                }
+                for (i, (kind, arch)) in available.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", kind.arch_name(*arch))?;
+                }
+                Ok(())
+            }
+            FatbinError::Cuda(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for FatbinError {}
+
+impl From<ErrorCode> for FatbinError {
+    fn from(e: ErrorCode) -> Self {
+        FatbinError::Cuda(e)
+    }
+}
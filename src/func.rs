@@ -1,5 +1,6 @@
 use crate::*;
 use num_enum::TryFromPrimitive;
+use std::marker::PhantomData;
 
 /// A [`Function`]-specific attribute type
 #[derive(Debug, Copy, Clone, TryFromPrimitive)]
@@ -57,6 +58,7 @@ pub enum FuncSharedConfig {
 pub struct Function<'a, 'b> {
     pub(crate) module: &'b Module<'a>,
     pub(crate) inner: *mut sys::CUfunc_st,
+    pub(crate) info: Option<&'b FunctionInfo>,
 }
 
 impl<'a, 'b> Function<'a, 'b> {
@@ -65,27 +67,97 @@ impl<'a, 'b> Function<'a, 'b> {
         self.module
     }
 
+    /// Returns the parameter list of this function, if known. Signatures are known for kernels loaded from PTX or cubin images, see [`Module::functions`].
+    pub fn signature(&self) -> Option<&'b KernelSignature> {
+        self.info.map(|x| &x.signature)
+    }
+
     /// Returns information about a function.
     pub fn get_attribute(&self, attribute: FunctionAttribute) -> CudaResult<i32> {
+        let _current = self.module.handle.current()?;
         let mut out = 0i32;
         cuda_error(unsafe {
-            sys::cuFuncGetAttribute(&mut out as *mut i32, attribute as u32, self.inner)
+            backend::cuFuncGetAttribute(&mut out as *mut i32, attribute as u32, self.inner)
         })?;
         Ok(out)
     }
 
     /// Sets information about a function.
     pub fn set_attribute(&mut self, attribute: FunctionAttribute, value: i32) -> CudaResult<()> {
-        cuda_error(unsafe { sys::cuFuncSetAttribute(self.inner, attribute as u32, value) })
+        let _current = self.module.handle.current()?;
+        cuda_error(unsafe { backend::cuFuncSetAttribute(self.inner, attribute as u32, value) })
     }
 
     /// Sets the preferred cache configuration for a device function.
     pub fn set_cache_config(&mut self, func_cache: FuncCache) -> CudaResult<()> {
-        cuda_error(unsafe { sys::cuFuncSetCacheConfig(self.inner, func_cache as u32) })
+        let _current = self.module.handle.current()?;
+        cuda_error(unsafe { backend::cuFuncSetCacheConfig(self.inner, func_cache as u32) })
     }
 
     /// Sets the shared memory configuration for a device function.
     pub fn set_shared_mem_config(&mut self, config: FuncSharedConfig) -> CudaResult<()> {
-        cuda_error(unsafe { sys::cuFuncSetSharedMemConfig(self.inner, config as u32) })
+        let _current = self.module.handle.current()?;
+        cuda_error(unsafe { backend::cuFuncSetSharedMemConfig(self.inner, config as u32) })
+    }
+}
+
+/// The grid and block dimensions, and dynamic shared memory size of a kernel launch
+#[derive(Debug, Copy, Clone)]
+pub struct LaunchConfig {
+    pub grid_dim: Dim3,
+    pub block_dim: Dim3,
+    /// Dynamically-allocated shared memory in bytes
+    pub shared_mem_size: u32,
+}
+
+impl LaunchConfig {
+    /// Creates a new [`LaunchConfig`] without dynamic shared memory
+    pub fn new<D1: Into<Dim3>, D2: Into<Dim3>>(grid_dim: D1, block_dim: D2) -> Self {
+        LaunchConfig {
+            grid_dim: grid_dim.into(),
+            block_dim: block_dim.into(),
+            shared_mem_size: 0,
+        }
+    }
+}
+
+/// A [`Function`] whose signature has been checked to match `Args`, see [`Module::kernel`]
+pub struct Kernel<'a, 'b, Args: KernelArgs> {
+    function: Function<'a, 'b>,
+    _p: PhantomData<fn() -> Args>,
+}
+
+impl<'a, 'b, Args: KernelArgs> Kernel<'a, 'b, Args> {
+    pub(crate) fn new(function: Function<'a, 'b>) -> Result<Self, LaunchError> {
+        let signature = function.signature().ok_or(LaunchError::UnknownSignature)?;
+        let mut layout = KernelParameterBuffer::new();
+        Args::layout(&mut layout);
+        signature.check(&layout)?;
+        Ok(Kernel {
+            function,
+            _p: PhantomData,
+        })
+    }
+
+    /// Returns the underlying [`Function`]
+    pub fn function(&self) -> &Function<'a, 'b> {
+        &self.function
+    }
+
+    /// Launch this kernel on `stream`. Unlike [`Stream::launch`], the layout of `Args` was checked against the kernel signature on lookup.
+    /// As with [`Stream::launch_checked`], a PTX `.u64` parameter does not tell device pointers from integers, so it is undefined behavior to pass in values the kernel uses as pointers which do not point to device memory it can access.
+    pub unsafe fn launch(
+        &self,
+        stream: &mut Stream<'a>,
+        config: LaunchConfig,
+        args: Args,
+    ) -> CudaResult<()> {
+        stream.launch(
+            &self.function,
+            config.grid_dim,
+            config.block_dim,
+            config.shared_mem_size,
+            args,
+        )
     }
 }
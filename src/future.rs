@@ -1,75 +1,126 @@
 use std::{
+    any::Any,
     future::Future,
     pin::Pin,
-    rc::Rc,
-    sync::{Arc, Mutex},
-    task::{Context, Poll},
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
 };
 
 use crate::*;
 
-enum InteriorState<T> {
-    Waiting,
-    Some(T),
-    Taken,
+/// State shared between a [`CudaFuture`] and its host callback
+#[derive(Default)]
+struct Shared {
+    done: bool,
+    waker: Option<Waker>,
+    /// The value of a future dropped before completion, kept alive until the work using it completes
+    orphan: Option<Box<dyn Any + Send>>,
 }
 
-pub struct CudaFuture<'a, T> {
-    interior: Arc<Mutex<InteriorState<CudaResult<T>>>>,
-    active_stream: Option<Stream<'a>>,
-    handle: Rc<Handle<'a>>,
+fn lock(shared: &Mutex<Shared>) -> MutexGuard<'_, Shared> {
+    shared.lock().unwrap_or_else(|e| e.into_inner())
 }
 
-impl<'a> Unpin for CudaFuture<'a, ()> {}
+/// A [`Future`] completing once a point in a [`Stream`] is reached, see [`Stream::future`] and [`Event::future`].
+/// It is woken by a single host callback queued with it, so it works with any executor. Polling does not queue more work.
+///
+/// A future can own host buffers used by the work before its point, i.e. [`DeviceSlice::store_async`] and [`DeviceSlice::load_async`].
+/// If it is dropped before completion, its buffers are kept alive until the work completes.
+/// As with [`Stream::callback`], they are leaked if the stream errors out before reaching the point.
+pub struct CudaFuture<'a, T: Send + 'static> {
+    shared: Arc<Mutex<Shared>>,
+    value: Option<T>,
+    event: Event<'a>,
+    // the stream waiting on an event for `Event::future`
+    pub(crate) stream: Option<Stream<'a>>,
+}
 
-impl<'a> Future for CudaFuture<'a, ()> {
-    type Output = CudaResult<()>;
+impl<'a, T: Send + 'static> Unpin for CudaFuture<'a, T> {}
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mut interior = self.interior.lock().unwrap();
-        match &mut *interior {
-            InteriorState::Taken => panic!("over polled CudaFuture (do you need to fuse?)"),
-            x @ InteriorState::Some(_) => {
-                let mut state = InteriorState::Taken;
-                std::mem::swap(x, &mut state);
-                if let InteriorState::Some(x) = state {
-                    Poll::Ready(x)
-                } else {
-                    unimplemented!()
+impl<'a, T: Send + 'static> CudaFuture<'a, T> {
+    /// Creates a future completing with `value` once the work currently queued on `stream` completes
+    pub(crate) fn new(stream: &mut Stream<'a>, value: T) -> CudaResult<Self> {
+        let (event, shared) = match Self::queue(stream) {
+            Ok(x) => x,
+            Err(e) => {
+                // `value` may be used by work queued before, which must complete before it is dropped
+                if let Err(e) = stream.sync() {
+                    eprintln!(
+                        "CUDA: failed to synchronize stream of failed future: {:?}",
+                        e
+                    );
                 }
+                return Err(e);
             }
-            InteriorState::Waiting => {
-                let waker = cx.waker().clone();
-                drop(interior);
-                let new_self = self.interior.clone();
-                match self.active_stream.as_mut().unwrap().callback(move || {
-                    let mut inner = new_self.lock().unwrap();
-                    if matches!(&*inner, InteriorState::Waiting) {
-                        *inner = InteriorState::Some(Ok(()));
-                    }
-                    waker.wake()
-                }) {
-                    Ok(()) => Poll::Pending,
-                    Err(e) => Poll::Ready(Err(e)),
-                }
+        };
+        Ok(CudaFuture {
+            shared,
+            value: Some(value),
+            event,
+            stream: None,
+        })
+    }
+
+    /// Queues the event and host callback of a future on `stream`
+    fn queue(stream: &mut Stream<'a>) -> CudaResult<(Event<'a>, Arc<Mutex<Shared>>)> {
+        let event = Event::new_with_options(
+            &stream.handle,
+            EventOptions {
+                blocking_sync: true,
+                timing: false,
+            },
+        )?;
+        stream.record(&event)?;
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let callback_shared = shared.clone();
+        stream.callback(move || {
+            let mut shared = lock(&callback_shared);
+            shared.done = true;
+            let waker = shared.waker.take();
+            let orphan = shared.orphan.take();
+            drop(shared);
+            if let Some(waker) = waker {
+                waker.wake();
             }
-        }
+            drop(orphan);
+        })?;
+        Ok((event, shared))
     }
 }
 
-impl<'a> CudaFuture<'a, ()> {
-    pub(crate) fn new(handle: Rc<Handle<'a>>, stream: Stream<'a>) -> Self {
-        CudaFuture {
-            interior: Arc::new(Mutex::new(InteriorState::Waiting)),
-            active_stream: Some(stream),
-            handle,
+impl<'a, T: Send + 'static> Future for CudaFuture<'a, T> {
+    type Output = CudaResult<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        {
+            let mut shared = lock(&self.shared);
+            if !shared.done {
+                if !shared
+                    .waker
+                    .as_ref()
+                    .is_some_and(|x| x.will_wake(cx.waker()))
+                {
+                    shared.waker = Some(cx.waker().clone());
+                }
+                return Poll::Pending;
+            }
         }
+        let value = self
+            .value
+            .take()
+            .expect("over polled CudaFuture (do you need to fuse?)");
+        // the event has completed, so this does not block. It reports errors of the work before it, such as failed kernels.
+        Poll::Ready(self.event.sync().map(|_| value))
     }
 }
 
-impl<'a, T> Drop for CudaFuture<'a, T> {
+impl<'a, T: Send + 'static> Drop for CudaFuture<'a, T> {
     fn drop(&mut self) {
-        self.handle
-            .reset_async_stream(self.active_stream.take().unwrap());
+        let mut shared = lock(&self.shared);
+        if !shared.done {
+            if let Some(value) = self.value.take() {
+                shared.orphan = Some(Box::new(value));
+            }
+        }
     }
 }
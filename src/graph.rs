@@ -0,0 +1,674 @@
+use num_enum::TryFromPrimitive;
+use std::{any::Any, error::Error, ffi::c_void, fmt, ops::Deref, ptr::null_mut, rc::Rc, sync::Arc};
+
+use crate::*;
+
+type HostCallback<'a> = Rc<Box<dyn Fn() + Send + Sync + 'a>>;
+
+/// Host-side objects referenced by graph nodes, which must outlive every launch of the graph.
+#[derive(Clone, Default)]
+pub(crate) struct GraphResources<'a> {
+    pub(crate) host_callbacks: Vec<HostCallback<'a>>,
+    pub(crate) host_buffers: Vec<Rc<dyn Any>>,
+}
+
+impl<'a> GraphResources<'a> {
+    pub(crate) fn extend(&mut self, other: &GraphResources<'a>) {
+        self.host_callbacks
+            .extend(other.host_callbacks.iter().cloned());
+        self.host_buffers.extend(other.host_buffers.iter().cloned());
+    }
+}
+
+unsafe extern "C" fn graph_host_callback(arg: *mut c_void) {
+    let closure = &*(arg as *const Box<dyn Fn() + Send + Sync>);
+    closure();
+}
+
+/// A node within a [`Graph`], used to express dependencies between operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GraphNode {
+    pub(crate) inner: *mut sys::CUgraphNode_st,
+}
+
+/// A kernel launch node within a [`Graph`], see [`Graph::add_kernel`]. Dereferences to its [`GraphNode`].
+#[derive(Clone, Copy, Debug)]
+pub struct KernelNode {
+    pub(crate) node: GraphNode,
+    pub(crate) func: *mut sys::CUfunc_st,
+    pub(crate) grid_dim: (u32, u32, u32),
+    pub(crate) block_dim: (u32, u32, u32),
+    pub(crate) shared_mem_size: u32,
+}
+
+/// A device memory copy node within a [`Graph`], see [`Graph::add_memcpy`]. Dereferences to its [`GraphNode`].
+#[derive(Clone, Copy, Debug)]
+pub struct MemcpyNode {
+    pub(crate) node: GraphNode,
+    // in bytes
+    pub(crate) len: u64,
+}
+
+/// A device memory set node within a [`Graph`], see [`Graph::add_memset_d8`]/etc. Dereferences to its [`GraphNode`].
+#[derive(Clone, Copy, Debug)]
+pub struct MemsetNode {
+    pub(crate) node: GraphNode,
+    pub(crate) element_size: u32,
+}
+
+impl KernelNode {
+    fn params(&self, kernel_params: &mut KernelParameterBuffer) -> sys::CUDA_KERNEL_NODE_PARAMS {
+        sys::CUDA_KERNEL_NODE_PARAMS {
+            func: self.func,
+            gridDimX: self.grid_dim.0,
+            gridDimY: self.grid_dim.1,
+            gridDimZ: self.grid_dim.2,
+            blockDimX: self.block_dim.0,
+            blockDimY: self.block_dim.1,
+            blockDimZ: self.block_dim.2,
+            sharedMemBytes: self.shared_mem_size,
+            kernelParams: kernel_params.pointers(),
+            extra: null_mut(),
+        }
+    }
+}
+
+macro_rules! node_deref {
+    ($($name:ident)+) => {
+        $(
+            impl Deref for $name {
+                type Target = GraphNode;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.node
+                }
+            }
+
+            impl From<$name> for GraphNode {
+                fn from(node: $name) -> Self {
+                    node.node
+                }
+            }
+        )+
+    }
+}
+
+node_deref! {
+    KernelNode
+    MemcpyNode
+    MemsetNode
+}
+
+/// A reusable set of operations and their dependencies, which can be instantiated into a [`GraphExec`] and launched repeatedly with low overhead.
+/// Memory, [`Module`]s and [`Event`]s referenced by a [`Graph`] must outlive any [`GraphExec`] that is launched from it.
+pub struct Graph<'a> {
+    pub(crate) inner: *mut sys::CUgraph_st,
+    pub(crate) handle: Arc<Handle<'a>>,
+    pub(crate) resources: GraphResources<'a>,
+}
+
+impl<'a> Graph<'a> {
+    /// Creates a new empty [`Graph`]
+    pub fn new(handle: &Arc<Handle<'a>>) -> CudaResult<Self> {
+        let mut inner = null_mut();
+        let _current = handle.current()?;
+        cuda_error(unsafe { backend::cuGraphCreate(&mut inner as *mut _, 0) })?;
+        Ok(Graph {
+            inner,
+            handle: handle.clone(),
+            resources: GraphResources::default(),
+        })
+    }
+
+    fn dependencies(dependencies: &[GraphNode]) -> (*const *mut sys::CUgraphNode_st, sys::size_t) {
+        if dependencies.is_empty() {
+            (std::ptr::null(), 0)
+        } else {
+            (
+                dependencies.as_ptr() as *const *mut sys::CUgraphNode_st,
+                dependencies.len() as sys::size_t,
+            )
+        }
+    }
+
+    /// Adds a node that does nothing, useful for joining several dependencies into one.
+    pub fn add_empty(&mut self, dependencies: &[GraphNode]) -> CudaResult<GraphNode> {
+        let (deps, deps_len) = Self::dependencies(dependencies);
+        let mut out = null_mut();
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuGraphAddEmptyNode(&mut out as *mut _, self.inner, deps, deps_len)
+        })?;
+        Ok(GraphNode { inner: out })
+    }
+
+    /// Adds a kernel launch node, see [`Stream::launch`].
+    /// It is undefined behavior to pass in `parameters` that do not conform to the passed CUDA kernel.
+    /// If your `parameters` is accurate to the kernel definition, then this function is otherwise safe.
+    pub unsafe fn add_kernel<'b, D1: Into<Dim3>, D2: Into<Dim3>, K: KernelParameters>(
+        &mut self,
+        dependencies: &[GraphNode],
+        f: &Function<'a, 'b>,
+        grid_dim: D1,
+        block_dim: D2,
+        shared_mem_size: u32,
+        parameters: K,
+    ) -> CudaResult<KernelNode> {
+        let mut node = KernelNode {
+            node: GraphNode { inner: null_mut() },
+            func: f.inner,
+            grid_dim: grid_dim.into().0,
+            block_dim: block_dim.into().0,
+            shared_mem_size,
+        };
+        let mut kernel_params = KernelParameterBuffer::new();
+        parameters.params(&mut kernel_params);
+        let params = node.params(&mut kernel_params);
+        let (deps, deps_len) = Self::dependencies(dependencies);
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuGraphAddKernelNode(
+            &mut node.node.inner as *mut _,
+            self.inner,
+            deps,
+            deps_len,
+            &params,
+        ))?;
+        Ok(node)
+    }
+
+    /// Adds a node copying device memory from `source` to `target`. Panics if length is not equal.
+    /// The node only keeps the device addresses, so it is undefined behavior to launch a [`GraphExec`] of this graph after `source` or `target` is freed.
+    pub unsafe fn add_memcpy<T: DeviceCopy>(
+        &mut self,
+        dependencies: &[GraphNode],
+        source: &DeviceSlice<'a, T>,
+        target: &DeviceSlice<'a, T>,
+    ) -> CudaResult<MemcpyNode> {
+        if source.len > target.len {
+            panic!("overflow in Graph::add_memcpy");
+        } else if source.len < target.len {
+            panic!("underflow in Graph::add_memcpy");
+        }
+        let params = memcpy_params(source, target);
+        let (deps, deps_len) = Self::dependencies(dependencies);
+        let mut out = null_mut();
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuGraphAddMemcpyNode(
+            &mut out as *mut _,
+            self.inner,
+            deps,
+            deps_len,
+            &params,
+            self.handle.context.inner,
+        ))?;
+        Ok(MemcpyNode {
+            node: GraphNode { inner: out },
+            len: source.byte_len(),
+        })
+    }
+
+    unsafe fn add_memset<T: DeviceCopy>(
+        &mut self,
+        dependencies: &[GraphNode],
+        target: &DeviceSlice<'a, T>,
+        value: u32,
+        element_size: u32,
+    ) -> CudaResult<MemsetNode> {
+        let params = memset_params(target, value, element_size);
+        let (deps, deps_len) = Self::dependencies(dependencies);
+        let mut out = null_mut();
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuGraphAddMemsetNode(
+            &mut out as *mut _,
+            self.inner,
+            deps,
+            deps_len,
+            &params,
+            self.handle.context.inner,
+        ))?;
+        Ok(MemsetNode {
+            node: GraphNode { inner: out },
+            element_size,
+        })
+    }
+
+    /// Adds a node setting the contents of `target` to `value` repeated to fill length.
+    /// The node only keeps the device address, so it is undefined behavior to launch a [`GraphExec`] of this graph after `target` is freed.
+    pub unsafe fn add_memset_d8<T: DeviceCopy>(
+        &mut self,
+        dependencies: &[GraphNode],
+        target: &DeviceSlice<'a, T>,
+        value: u8,
+    ) -> CudaResult<MemsetNode> {
+        self.add_memset(dependencies, target, value as u32, 1)
+    }
+
+    /// Adds a node setting the contents of `target` to `value` repeated to fill length.
+    /// Panics if [`DeviceSlice::byte_len`] is not a multiple of 2.
+    /// The node only keeps the device address, so it is undefined behavior to launch a [`GraphExec`] of this graph after `target` is freed.
+    pub unsafe fn add_memset_d16<T: DeviceCopy>(
+        &mut self,
+        dependencies: &[GraphNode],
+        target: &DeviceSlice<'a, T>,
+        value: u16,
+    ) -> CudaResult<MemsetNode> {
+        if target.byte_len() % 2 != 0 {
+            panic!("alignment failure in Graph::add_memset_d16");
+        }
+        self.add_memset(dependencies, target, value as u32, 2)
+    }
+
+    /// Adds a node setting the contents of `target` to `value` repeated to fill length.
+    /// Panics if [`DeviceSlice::byte_len`] is not a multiple of 4.
+    /// The node only keeps the device address, so it is undefined behavior to launch a [`GraphExec`] of this graph after `target` is freed.
+    pub unsafe fn add_memset_d32<T: DeviceCopy>(
+        &mut self,
+        dependencies: &[GraphNode],
+        target: &DeviceSlice<'a, T>,
+        value: u32,
+    ) -> CudaResult<MemsetNode> {
+        if target.byte_len() % 4 != 0 {
+            panic!("alignment failure in Graph::add_memset_d32");
+        }
+        self.add_memset(dependencies, target, value, 4)
+    }
+
+    /// Adds a node calling `callback` on the host every time the graph is executed.
+    /// Also note that it is erroneous in `libcuda` to make any calls to `libcuda` from this callback.
+    /// The callback is called from a CUDA internal thread, however this is an implementation detail of `libcuda` and not guaranteed.
+    pub fn add_host<F: Fn() + Send + Sync + 'a>(
+        &mut self,
+        dependencies: &[GraphNode],
+        callback: F,
+    ) -> CudaResult<GraphNode> {
+        let callback: HostCallback<'a> = Rc::new(Box::new(callback));
+        let params = sys::CUDA_HOST_NODE_PARAMS {
+            fn_: Some(graph_host_callback),
+            userData: &*callback as *const Box<dyn Fn() + Send + Sync + 'a> as *mut c_void,
+        };
+        let (deps, deps_len) = Self::dependencies(dependencies);
+        let mut out = null_mut();
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuGraphAddHostNode(&mut out as *mut _, self.inner, deps, deps_len, &params)
+        })?;
+        self.resources.host_callbacks.push(callback);
+        Ok(GraphNode { inner: out })
+    }
+
+    /// Adds a node recording `event`, see [`Stream::record`].
+    /// The node only keeps the event handle, so it is undefined behavior to launch a [`GraphExec`] of this graph after `event` is dropped.
+    pub unsafe fn add_event_record(
+        &mut self,
+        dependencies: &[GraphNode],
+        event: &Event<'a>,
+    ) -> CudaResult<GraphNode> {
+        let (deps, deps_len) = Self::dependencies(dependencies);
+        let mut out = null_mut();
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuGraphAddEventRecordNode(
+            &mut out as *mut _,
+            self.inner,
+            deps,
+            deps_len,
+            event.inner,
+        ))?;
+        Ok(GraphNode { inner: out })
+    }
+
+    /// Adds a node waiting for `event` to complete, see [`Stream::wait_event`].
+    /// The node only keeps the event handle, so it is undefined behavior to launch a [`GraphExec`] of this graph after `event` is dropped.
+    pub unsafe fn add_event_wait(
+        &mut self,
+        dependencies: &[GraphNode],
+        event: &Event<'a>,
+    ) -> CudaResult<GraphNode> {
+        let (deps, deps_len) = Self::dependencies(dependencies);
+        let mut out = null_mut();
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuGraphAddEventWaitNode(
+            &mut out as *mut _,
+            self.inner,
+            deps,
+            deps_len,
+            event.inner,
+        ))?;
+        Ok(GraphNode { inner: out })
+    }
+
+    /// Adds a node executing a copy of `child`. Later changes to `child` are not reflected in this graph.
+    pub fn add_child_graph(
+        &mut self,
+        dependencies: &[GraphNode],
+        child: &Graph<'a>,
+    ) -> CudaResult<GraphNode> {
+        let (deps, deps_len) = Self::dependencies(dependencies);
+        let mut out = null_mut();
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuGraphAddChildGraphNode(
+                &mut out as *mut _,
+                self.inner,
+                deps,
+                deps_len,
+                child.inner,
+            )
+        })?;
+        self.resources.extend(&child.resources);
+        Ok(GraphNode { inner: out })
+    }
+
+    /// Makes `to` depend on `from`, in addition to the dependencies given when `to` was added.
+    pub fn add_dependency(&mut self, from: GraphNode, to: GraphNode) -> CudaResult<()> {
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuGraphAddDependencies(self.inner, &from.inner, &to.inner, 1)
+        })
+    }
+
+    /// Gets the number of nodes in this [`Graph`]
+    pub fn node_count(&self) -> CudaResult<usize> {
+        let mut out: sys::size_t = 0;
+        let _current = self.handle.current()?;
+        cuda_error(unsafe { backend::cuGraphGetNodes(self.inner, null_mut(), &mut out) })?;
+        Ok(out as usize)
+    }
+
+    /// Instantiates this [`Graph`] into an executable [`GraphExec`]. The [`Graph`] can be further modified or dropped without affecting the [`GraphExec`].
+    pub fn instantiate(&self) -> CudaResult<GraphExec<'a>> {
+        let mut inner = null_mut();
+        let mut log = vec![0u8; 4096];
+        let _current = self.handle.current()?;
+        let out = cuda_error(unsafe {
+            backend::cuGraphInstantiate_v2(
+                &mut inner as *mut _,
+                self.inner,
+                null_mut(),
+                log.as_mut_ptr() as *mut _,
+                log.len() as sys::size_t,
+            )
+        });
+        if let Err(e) = out {
+            let end = log.iter().position(|x| *x == 0).unwrap_or(log.len());
+            let log = String::from_utf8_lossy(&log[..end]);
+            if !log.is_empty() {
+                log.split('\n').for_each(|line| {
+                    println!("[CUDA ERROR] {}", line);
+                });
+            }
+            return Err(e);
+        }
+        let launched = Event::new_with_options(
+            &self.handle,
+            EventOptions {
+                blocking_sync: true,
+                timing: false,
+            },
+        );
+        let launched = match launched {
+            Ok(x) => x,
+            Err(e) => {
+                unsafe { backend::cuGraphExecDestroy(inner) };
+                return Err(e);
+            }
+        };
+        Ok(GraphExec {
+            inner,
+            handle: self.handle.clone(),
+            resources: self.resources.clone(),
+            launched,
+            retired: vec![],
+            params: KernelParameterBuffer::new(),
+        })
+    }
+}
+
+impl<'a> Drop for Graph<'a> {
+    fn drop(&mut self) {
+        let _current = self.handle.current();
+        if let Err(e) = cuda_error(unsafe { backend::cuGraphDestroy(self.inner) }) {
+            eprintln!("CUDA: failed to destroy graph: {:?}", e);
+        }
+    }
+}
+
+fn memcpy_params<T: DeviceCopy>(
+    source: &DeviceSlice<'_, T>,
+    target: &DeviceSlice<'_, T>,
+) -> sys::CUDA_MEMCPY3D {
+    sys::CUDA_MEMCPY3D {
+        srcXInBytes: 0,
+        srcY: 0,
+        srcZ: 0,
+        srcLOD: 0,
+        srcMemoryType: sys::CUmemorytype_enum_CU_MEMORYTYPE_DEVICE,
+        srcHost: std::ptr::null(),
+        srcDevice: source.inner,
+        srcArray: null_mut(),
+        reserved0: null_mut(),
+        srcPitch: 0,
+        srcHeight: 0,
+        dstXInBytes: 0,
+        dstY: 0,
+        dstZ: 0,
+        dstLOD: 0,
+        dstMemoryType: sys::CUmemorytype_enum_CU_MEMORYTYPE_DEVICE,
+        dstHost: null_mut(),
+        dstDevice: target.inner,
+        dstArray: null_mut(),
+        reserved1: null_mut(),
+        dstPitch: 0,
+        dstHeight: 0,
+        WidthInBytes: source.byte_len() as sys::size_t,
+        Height: 1,
+        Depth: 1,
+    }
+}
+
+fn memset_params<T: DeviceCopy>(
+    target: &DeviceSlice<'_, T>,
+    value: u32,
+    element_size: u32,
+) -> sys::CUDA_MEMSET_NODE_PARAMS {
+    sys::CUDA_MEMSET_NODE_PARAMS {
+        dst: target.inner,
+        pitch: target.byte_len() as sys::size_t,
+        value,
+        elementSize: element_size,
+        width: (target.byte_len() / element_size as u64) as sys::size_t,
+        height: 1,
+    }
+}
+
+/// The reason a [`GraphExec::update`] was rejected
+#[derive(Debug, Copy, Clone, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u32)]
+pub enum GraphExecUpdateReason {
+    /// The update failed for an unexpected reason which is described in the return value of the function
+    Error = 0x1,
+    /// The update failed because the topology changed
+    TopologyChanged = 0x2,
+    /// The update failed because a node type changed
+    NodeTypeChanged = 0x3,
+    /// The update failed because the function of a kernel node changed (CUDA driver < 11.2)
+    FunctionChanged = 0x4,
+    /// The update failed because the parameters changed in a way that is not supported
+    ParametersChanged = 0x5,
+    /// The update failed because something about the node is not supported
+    NotSupported = 0x6,
+    /// The update failed because the function of a kernel node changed in an unsupported way
+    UnsupportedFunctionChange = 0x7,
+}
+
+/// An error from [`GraphExec::update`]
+#[derive(Debug, Copy, Clone)]
+pub enum GraphExecUpdateError {
+    /// `libcuda` rejected the update. The [`GraphExec`] is unchanged.
+    Rejected {
+        /// Why the update was rejected
+        reason: GraphExecUpdateReason,
+        /// The node responsible for the rejection, if known
+        node: Option<GraphNode>,
+    },
+    /// Any other error
+    Cuda(ErrorCode),
+}
+
+impl fmt::Display for GraphExecUpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphExecUpdateError::Rejected { reason, .. } => {
+                write!(f, "graph exec update rejected: {:?}", reason)
+            }
+            GraphExecUpdateError::Cuda(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for GraphExecUpdateError {}
+
+impl From<ErrorCode> for GraphExecUpdateError {
+    fn from(e: ErrorCode) -> Self {
+        GraphExecUpdateError::Cuda(e)
+    }
+}
+
+/// An instantiated [`Graph`], ready to be launched on a [`Stream`].
+/// Dropping a [`GraphExec`] blocks until its launches complete, as they use its host callbacks and buffers.
+pub struct GraphExec<'a> {
+    pub(crate) inner: *mut sys::CUgraphExec_st,
+    handle: Arc<Handle<'a>>,
+    pub(crate) resources: GraphResources<'a>,
+    // recorded after every launch. Launches are ordered, so once the last one completes, all have
+    launched: Event<'a>,
+    // resources replaced by `update`, which enqueued launches may still use
+    retired: Vec<GraphResources<'a>>,
+    params: KernelParameterBuffer,
+}
+
+impl<'a> GraphExec<'a> {
+    /// Launches this [`GraphExec`] on `stream`. Launches of the same [`GraphExec`] are ordered with respect to each other, even across streams.
+    /// Returns [`ErrorCode::StreamCaptureUnsupported`] while `stream` is capturing, as the captured graph would not keep the resources of this [`GraphExec`] alive.
+    pub fn launch(&self, stream: &mut Stream<'a>) -> CudaResult<()> {
+        if stream.capturing {
+            return Err(ErrorCode::StreamCaptureUnsupported);
+        }
+        let _current = self.handle.current()?;
+        cuda_error(unsafe { backend::cuGraphLaunch(self.inner, stream.inner) })?;
+        stream.record(&self.launched)
+    }
+
+    /// Uploads this [`GraphExec`] to the device on `stream` without launching it, hiding upload latency from the first [`GraphExec::launch`].
+    pub fn upload(&self, stream: &mut Stream<'a>) -> CudaResult<()> {
+        let _current = self.handle.current()?;
+        cuda_error(unsafe { backend::cuGraphUpload(self.inner, stream.inner) })
+    }
+
+    /// Replaces the parameters of kernel node `node` for future launches, keeping the kernel, grid and block dimensions, and shared memory size.
+    /// Already enqueued launches are not affected.
+    /// It is undefined behavior to pass in `parameters` that do not conform to the kernel of `node`.
+    pub unsafe fn set_kernel_parameters<K: KernelParameters>(
+        &mut self,
+        node: &KernelNode,
+        parameters: K,
+    ) -> CudaResult<()> {
+        self.params.clear();
+        parameters.params(&mut self.params);
+        let params = node.params(&mut self.params);
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuGraphExecKernelNodeSetParams(
+            self.inner,
+            node.node.inner,
+            &params,
+        ))
+    }
+
+    /// Replaces the endpoints of memcpy node `node` for future launches. The new endpoints must be in the same context as the original ones.
+    /// Already enqueued launches are not affected. Panics if the length of `source` or `target` differs from the original copy.
+    /// It is undefined behavior to launch this [`GraphExec`] after `source` or `target` is freed.
+    pub unsafe fn set_memcpy<T: DeviceCopy>(
+        &mut self,
+        node: &MemcpyNode,
+        source: &DeviceSlice<'a, T>,
+        target: &DeviceSlice<'a, T>,
+    ) -> CudaResult<()> {
+        if source.byte_len() != node.len || target.byte_len() != node.len {
+            panic!("length mismatch in GraphExec::set_memcpy");
+        }
+        let params = memcpy_params(source, target);
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuGraphExecMemcpyNodeSetParams(
+            self.inner,
+            node.node.inner,
+            &params,
+            target.handle.context.inner,
+        ))
+    }
+
+    /// Replaces the target and value of memset node `node` for future launches. `value` is truncated to the element size of the original memset.
+    /// Already enqueued launches are not affected. Panics if [`DeviceSlice::byte_len`] is not a multiple of the element size.
+    /// It is undefined behavior to launch this [`GraphExec`] after `target` is freed.
+    pub unsafe fn set_memset<T: DeviceCopy>(
+        &mut self,
+        node: &MemsetNode,
+        target: &DeviceSlice<'a, T>,
+        value: u32,
+    ) -> CudaResult<()> {
+        if target.byte_len() % node.element_size as u64 != 0 {
+            panic!("alignment failure in GraphExec::set_memset");
+        }
+        let params = memset_params(target, value, node.element_size);
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuGraphExecMemsetNodeSetParams(
+            self.inner,
+            node.node.inner,
+            &params,
+            target.handle.context.inner,
+        ))
+    }
+
+    /// Updates all node parameters of this [`GraphExec`] to match `graph`, which must have the same topology as the instantiated graph.
+    /// This is cheaper than instantiating `graph`. Already enqueued launches are not affected.
+    /// Host-side resources of `graph` replace those of the previously instantiated or updated graph, which are released once already enqueued launches complete.
+    pub fn update(&mut self, graph: &Graph<'a>) -> Result<(), GraphExecUpdateError> {
+        let mut error_node = null_mut();
+        let mut result = 0u32;
+        let _current = self.handle.current()?;
+        let out = cuda_error(unsafe {
+            backend::cuGraphExecUpdate(self.inner, graph.inner, &mut error_node, &mut result)
+        });
+        match out {
+            Ok(()) => {
+                let replaced = std::mem::replace(&mut self.resources, graph.resources.clone());
+                self.retired.push(replaced);
+                if let Ok(true) = self.launched.is_complete() {
+                    self.retired.clear();
+                }
+                Ok(())
+            }
+            Err(ErrorCode::GraphExecUpdateFailure) => Err(GraphExecUpdateError::Rejected {
+                reason: GraphExecUpdateReason::try_from_primitive(result)
+                    .unwrap_or(GraphExecUpdateReason::Error),
+                node: if error_node.is_null() {
+                    None
+                } else {
+                    Some(GraphNode { inner: error_node })
+                },
+            }),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl<'a> Drop for GraphExec<'a> {
+    fn drop(&mut self) {
+        // host callbacks and buffers are released after this, so launches using them must have completed
+        if let Err(e) = self.launched.sync() {
+            eprintln!("CUDA: failed to wait for graph exec launches: {:?}", e);
+        }
+        let _current = self.handle.current();
+        if let Err(e) = cuda_error(unsafe { backend::cuGraphExecDestroy(self.inner) }) {
+            eprintln!("CUDA: failed to destroy graph exec: {:?}", e);
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use std::{
+    ffi::c_void,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::{null_mut, NonNull},
+    sync::Arc,
+};
+
+use crate::*;
+
+/// Options for allocating a [`HostBox`], can generally just be defaulted.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HostAllocOptions {
+    /// The memory is page-locked for all contexts, not just the current one
+    pub portable: bool,
+    /// The memory is mapped into the device address space, see [`HostBox::device_ptr`]
+    pub mapped: bool,
+    /// The memory is write-combined, which can speed up host to device transfers but makes host reads very slow
+    pub write_combined: bool,
+}
+
+/// Host memory that is page-locked (pinned) for as long as it is borrowed, see [`HostBox`] and [`HostRegistration`].
+pub trait PinnedHostMemory<T: DeviceCopy>: DerefMut<Target = [T]> {
+    /// Gets the length of this memory in bytes
+    fn byte_len(&self) -> u64 {
+        std::mem::size_of_val::<[T]>(self) as u64
+    }
+}
+
+fn mapped_device_ptr<'a, T: DeviceCopy>(
+    handle: &Arc<Handle<'a>>,
+    data: &[T],
+) -> CudaResult<DeviceSlice<'a, T>> {
+    let _current = handle.current()?;
+    let mut out = 0u64;
+    cuda_error(unsafe {
+        backend::cuMemHostGetDevicePointer_v2(&mut out, data.as_ptr() as *mut c_void, 0)
+    })?;
+    Ok(DeviceSlice {
+        handle: handle.clone(),
+        inner: out,
+        len: data.len() as u64,
+        _p: PhantomData,
+    })
+}
+
+/// An owned page-locked (pinned) host buffer. Copies between pinned host memory and the device can run truly asynchronously, overlapping with kernel execution.
+pub struct HostBox<'a, T: DeviceCopy> {
+    handle: Arc<Handle<'a>>,
+    inner: NonNull<T>,
+    len: usize,
+}
+
+impl<'a, T: DeviceCopy> HostBox<'a, T> {
+    /// Allocates a new pinned buffer with default options matching the size and content of `input`
+    pub fn new(handle: &Arc<Handle<'a>>, input: &[T]) -> CudaResult<Self> {
+        Self::new_with_options(handle, input, HostAllocOptions::default())
+    }
+
+    /// Allocates a new pinned buffer with `options` matching the size and content of `input`
+    pub fn new_with_options(
+        handle: &Arc<Handle<'a>>,
+        input: &[T],
+        options: HostAllocOptions,
+    ) -> CudaResult<Self> {
+        let out = unsafe { Self::alloc(handle, input.len(), options) }?;
+        unsafe {
+            std::ptr::copy_nonoverlapping(input.as_ptr(), out.inner.as_ptr(), input.len());
+        }
+        Ok(out)
+    }
+
+    /// Allocates a new pinned buffer with `options` of `len` elements all set to `value`
+    pub fn filled(
+        handle: &Arc<Handle<'a>>,
+        value: T,
+        len: usize,
+        options: HostAllocOptions,
+    ) -> CudaResult<Self> {
+        let out = unsafe { Self::alloc(handle, len, options) }?;
+        for i in 0..len {
+            unsafe { out.inner.as_ptr().add(i).write(value) };
+        }
+        Ok(out)
+    }
+
+    unsafe fn alloc(
+        handle: &Arc<Handle<'a>>,
+        len: usize,
+        options: HostAllocOptions,
+    ) -> CudaResult<Self> {
+        let size = len
+            .checked_mul(std::mem::size_of::<T>())
+            .expect("overflow in HostBox::alloc");
+        if size == 0 {
+            return Ok(HostBox {
+                handle: handle.clone(),
+                inner: NonNull::dangling(),
+                len,
+            });
+        }
+        let mut flags = 0u32;
+        if options.portable {
+            flags |= sys::CU_MEMHOSTALLOC_PORTABLE;
+        }
+        if options.mapped {
+            flags |= sys::CU_MEMHOSTALLOC_DEVICEMAP;
+        }
+        if options.write_combined {
+            flags |= sys::CU_MEMHOSTALLOC_WRITECOMBINED;
+        }
+        let _current = handle.current()?;
+        let mut out: *mut c_void = null_mut();
+        if flags == 0 {
+            cuda_error(backend::cuMemAllocHost_v2(&mut out, size as sys::size_t))?;
+        } else {
+            cuda_error(backend::cuMemHostAlloc(
+                &mut out,
+                size as sys::size_t,
+                flags,
+            ))?;
+        }
+        Ok(HostBox {
+            handle: handle.clone(),
+            inner: NonNull::new(out as *mut T).ok_or(ErrorCode::OutOfMemory)?,
+            len,
+        })
+    }
+
+    /// Gets a [`DeviceSlice`] through which the device can directly access this buffer.
+    /// The buffer must have been allocated with [`HostAllocOptions::mapped`].
+    /// The [`DeviceSlice`] must not be used after this [`HostBox`] is dropped.
+    pub fn device_ptr(&self) -> CudaResult<DeviceSlice<'a, T>> {
+        mapped_device_ptr(&self.handle, self)
+    }
+
+    /// Gets a reference to the owning handle
+    pub fn handle(&self) -> &Arc<Handle<'a>> {
+        &self.handle
+    }
+}
+
+impl<'a, T: DeviceCopy> PinnedHostMemory<T> for HostBox<'a, T> {}
+
+impl<'a, T: DeviceCopy> Drop for HostBox<'a, T> {
+    fn drop(&mut self) {
+        if self.len == 0 || std::mem::size_of::<T>() == 0 {
+            return;
+        }
+        let _current = self.handle.current();
+        if let Err(e) =
+            cuda_error(unsafe { backend::cuMemFreeHost(self.inner.as_ptr() as *mut c_void) })
+        {
+            eprintln!("CUDA: failed freeing host buffer: {:?}", e);
+        }
+    }
+}
+
+impl<'a, T: DeviceCopy> Deref for HostBox<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::slice::from_raw_parts(self.inner.as_ptr(), self.len) }
+    }
+}
+
+impl<'a, T: DeviceCopy> DerefMut for HostBox<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { std::slice::from_raw_parts_mut(self.inner.as_ptr(), self.len) }
+    }
+}
+
+/// Options for registering host memory with [`HostRegistration`], can generally just be defaulted.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HostRegisterOptions {
+    /// The memory is page-locked for all contexts, not just the current one
+    pub portable: bool,
+    /// The memory is mapped into the device address space, see [`HostRegistration::device_ptr`]
+    pub mapped: bool,
+    /// The memory will only be read by the device. Required for memory that is read-only on the host.
+    pub read_only: bool,
+}
+
+/// Existing host memory page-locked (pinned) with `libcuda` for as long as it is borrowed.
+/// The memory is unregistered when the [`HostRegistration`] is dropped.
+pub struct HostRegistration<'a, 'b, T: DeviceCopy> {
+    handle: Arc<Handle<'a>>,
+    inner: &'b mut [T],
+}
+
+impl<'a, 'b, T: DeviceCopy> HostRegistration<'a, 'b, T> {
+    /// Page-locks `data` for the lifetime of the returned [`HostRegistration`]
+    pub fn new(
+        handle: &Arc<Handle<'a>>,
+        data: &'b mut [T],
+        options: HostRegisterOptions,
+    ) -> CudaResult<Self> {
+        let size = std::mem::size_of_val::<[T]>(data);
+        if size > 0 {
+            let mut flags = 0u32;
+            if options.portable {
+                flags |= sys::CU_MEMHOSTREGISTER_PORTABLE;
+            }
+            if options.mapped {
+                flags |= sys::CU_MEMHOSTREGISTER_DEVICEMAP;
+            }
+            if options.read_only {
+                flags |= sys::CU_MEMHOSTREGISTER_READ_ONLY;
+            }
+            let _current = handle.current()?;
+            cuda_error(unsafe {
+                backend::cuMemHostRegister_v2(
+                    data.as_mut_ptr() as *mut c_void,
+                    size as sys::size_t,
+                    flags,
+                )
+            })?;
+        }
+        Ok(HostRegistration {
+            handle: handle.clone(),
+            inner: data,
+        })
+    }
+
+    /// Gets a [`DeviceSlice`] through which the device can directly access this memory.
+    /// The memory must have been registered with [`HostRegisterOptions::mapped`].
+    /// The [`DeviceSlice`] must not be used after this [`HostRegistration`] is dropped.
+    pub fn device_ptr(&self) -> CudaResult<DeviceSlice<'a, T>> {
+        mapped_device_ptr(&self.handle, self)
+    }
+
+    /// Gets a reference to the owning handle
+    pub fn handle(&self) -> &Arc<Handle<'a>> {
+        &self.handle
+    }
+}
+
+impl<'a, 'b, T: DeviceCopy> PinnedHostMemory<T> for HostRegistration<'a, 'b, T> {}
+
+impl<'a, 'b, T: DeviceCopy> Drop for HostRegistration<'a, 'b, T> {
+    fn drop(&mut self) {
+        if self.inner.is_empty() || std::mem::size_of::<T>() == 0 {
+            return;
+        }
+        let _current = self.handle.current();
+        if let Err(e) = cuda_error(unsafe {
+            backend::cuMemHostUnregister(self.inner.as_mut_ptr() as *mut c_void)
+        }) {
+            eprintln!("CUDA: failed unregistering host memory: {:?}", e);
+        }
+    }
+}
+
+impl<'a, 'b, T: DeviceCopy> Deref for HostRegistration<'a, 'b, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl<'a, 'b, T: DeviceCopy> DerefMut for HostRegistration<'a, 'b, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner
+    }
+}
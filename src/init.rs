@@ -1,6 +1,6 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::{error::*, sys, Cuda};
+use crate::{backend, error::*, Cuda};
 
 static CHECK_INIT: AtomicBool = AtomicBool::new(false);
 impl Cuda {
@@ -9,7 +9,7 @@ impl Cuda {
         if CHECK_INIT.load(Ordering::SeqCst) {
             return Ok(());
         }
-        cuda_error(unsafe { sys::cuInit(0) })?;
+        cuda_error(unsafe { backend::cuInit(0) })?;
         CHECK_INIT.store(true, Ordering::SeqCst);
         Ok(())
     }
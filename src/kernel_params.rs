@@ -1,130 +1,367 @@
-use crate::{DeviceBox, DevicePtr};
+use std::{ffi::c_void, mem::MaybeUninit};
+
+use crate::{DeviceBox, DeviceCopy, DeviceSlice, DeviceVec, ManagedBox};
 
 /// Some data able to represent one or more kernel parameters
 pub trait KernelParameters {
-    fn params(&self, out: &mut Vec<Vec<u8>>);
+    fn params(&self, out: &mut KernelParameterBuffer);
+}
+
+/// [`KernelParameters`] whose layout is known from the type alone, so it can be checked against a kernel signature before any launch, see [`Module::kernel`]
+pub trait KernelArgs: KernelParameters {
+    /// Pushes a zeroed placeholder for each parameter, with the same sizes and alignments as [`KernelParameters::params`]
+    fn layout(out: &mut KernelParameterBuffer);
+}
+
+/// A value passed as a single 64-bit kernel parameter, i.e. a PTX `.u64` parameter: an integer or a device pointer.
+/// Used by bindings generated with [`build::Kernels`](crate::build::Kernels), where PTX does not tell the two apart, so integers are accepted in place of pointers and launching is unsafe.
+pub trait KernelArg64: KernelArgs {}
+
+impl KernelArg64 for u64 {}
+impl KernelArg64 for usize {}
+impl<'a, T: DeviceCopy> KernelArg64 for DeviceSlice<'a, T> {}
+impl<'a, 'b, T: DeviceCopy> KernelArg64 for &'b DeviceBox<'a, T> {}
+impl<'a, 'b, T: DeviceCopy> KernelArg64 for &'b ManagedBox<'a, T> {}
+impl<'a, 'b, T: DeviceCopy> KernelArg64 for &'b DeviceVec<'a, T> {}
+
+// `extra` launch option keys, these are pointer macros in cuda.h
+pub(crate) const CU_LAUNCH_PARAM_END: *mut c_void = std::ptr::null_mut();
+#[allow(clippy::manual_dangling_ptr)]
+pub(crate) const CU_LAUNCH_PARAM_BUFFER_POINTER: *mut c_void = 0x01 as *mut c_void;
+pub(crate) const CU_LAUNCH_PARAM_BUFFER_SIZE: *mut c_void = 0x02 as *mut c_void;
+
+/// Maximum alignment of a single kernel parameter
+const MAX_ALIGN: usize = 16;
+
+// padding within pushed values is copied as is, so the buffer may hold uninitialized bytes
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+struct AlignedChunk([MaybeUninit<u8>; MAX_ALIGN]);
+
+/// A single buffer holding packed kernel parameters, each at its natural alignment, as laid out by the kernel's parameter list.
+/// Buffers are reused across launches (i.e. each [`Stream`](crate::Stream) owns one), so packing parameters does not allocate once the buffer has grown to fit.
+pub struct KernelParameterBuffer {
+    chunks: Vec<AlignedChunk>,
+    len: usize,
+    offsets: Vec<usize>,
+    sizes: Vec<usize>,
+    pointers: Vec<*mut c_void>,
+}
+
+impl KernelParameterBuffer {
+    /// Creates a new empty buffer
+    pub fn new() -> Self {
+        KernelParameterBuffer {
+            chunks: vec![],
+            len: 0,
+            offsets: vec![],
+            sizes: vec![],
+            pointers: vec![],
+        }
+    }
+
+    /// Removes all parameters, keeping allocated capacity
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.offsets.clear();
+        self.sizes.clear();
+    }
+
+    /// Appends `value` as a single parameter, aligned to its natural alignment
+    pub fn push<T: DeviceCopy>(&mut self, value: &T) {
+        let offset = self.reserve(std::mem::size_of::<T>(), std::mem::align_of::<T>());
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                value as *const T as *const u8,
+                self.as_mut_ptr().add(offset),
+                std::mem::size_of::<T>(),
+            )
+        };
+    }
+
+    /// Appends `bytes` as a single parameter, aligned to `align` bytes.
+    /// Panics if `align` is not a power of two no greater than 16.
+    pub fn push_bytes(&mut self, bytes: &[u8], align: usize) {
+        let offset = self.reserve(bytes.len(), align);
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                self.as_mut_ptr().add(offset),
+                bytes.len(),
+            )
+        };
+    }
+
+    /// Appends `size` zero bytes as a single parameter, aligned to `align` bytes.
+    /// Panics if `align` is not a power of two no greater than 16.
+    pub fn push_zeroed(&mut self, size: usize, align: usize) {
+        let offset = self.reserve(size, align);
+        unsafe { std::ptr::write_bytes(self.as_mut_ptr().add(offset), 0, size) };
+    }
+
+    fn reserve(&mut self, size: usize, align: usize) -> usize {
+        if !align.is_power_of_two() || align > MAX_ALIGN {
+            panic!("alignment failure in KernelParameterBuffer::push");
+        }
+        let offset = self.len.next_multiple_of(align);
+        self.len = offset + size;
+        let chunks = self.len.div_ceil(MAX_ALIGN);
+        if chunks > self.chunks.len() {
+            self.chunks
+                .resize(chunks, AlignedChunk([MaybeUninit::new(0); MAX_ALIGN]));
+        }
+        self.offsets.push(offset);
+        self.sizes.push(size);
+        offset
+    }
+
+    /// Gets the number of parameters in the buffer
+    pub fn count(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Gets the byte offset of each parameter in the buffer
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Gets the size in bytes of each parameter in the buffer
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+
+    /// Gets the length of the packed parameters in bytes
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the buffer's length is 0
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.chunks.as_mut_ptr() as *mut u8
+    }
+
+    /// Gets an array of pointers to each parameter, as expected by the `kernelParams` argument of `libcuda` launch calls.
+    /// The pointers are invalidated by any further mutation of the buffer.
+    pub(crate) fn pointers(&mut self) -> *mut *mut c_void {
+        let base = self.as_mut_ptr();
+        self.pointers.clear();
+        self.pointers.extend(
+            self.offsets
+                .iter()
+                .map(|offset| unsafe { base.add(*offset) } as *mut c_void),
+        );
+        self.pointers.as_mut_ptr()
+    }
+}
+
+impl Default for KernelParameterBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl KernelParameters for u8 {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(vec![*self]);
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(self);
     }
 }
 
 impl KernelParameters for u16 {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.to_le_bytes().to_vec());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(self);
     }
 }
 
 impl KernelParameters for u32 {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.to_le_bytes().to_vec());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(self);
     }
 }
 
 impl KernelParameters for u64 {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.to_le_bytes().to_vec());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(self);
     }
 }
 
 impl KernelParameters for usize {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.to_le_bytes().to_vec());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(self);
     }
 }
 
 impl KernelParameters for i8 {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.to_le_bytes().to_vec());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(self);
     }
 }
 
 impl KernelParameters for i16 {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.to_le_bytes().to_vec());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(self);
     }
 }
 
 impl KernelParameters for i32 {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.to_le_bytes().to_vec());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(self);
     }
 }
 
 impl KernelParameters for i64 {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.to_le_bytes().to_vec());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(self);
     }
 }
 
 impl KernelParameters for f32 {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.to_le_bytes().to_vec());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(self);
     }
 }
 
 impl KernelParameters for f64 {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.to_le_bytes().to_vec());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(self);
+    }
+}
+
+macro_rules! kernel_args {
+    ($($t:ty),*) => {
+        $(
+            impl KernelArgs for $t {
+                fn layout(out: &mut KernelParameterBuffer) {
+                    out.push_zeroed(std::mem::size_of::<$t>(), std::mem::align_of::<$t>());
+                }
+            }
+        )*
+    };
+}
+
+kernel_args!(u8, u16, u32, u64, usize, i8, i16, i32, i64, f32, f64);
+
+/// WARNING: this is unsafe!
+impl<'a, T: DeviceCopy> KernelParameters for DeviceSlice<'a, T> {
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(&self.inner);
     }
 }
 
 /// WARNING: this is unsafe!
-impl<'a> KernelParameters for DevicePtr<'a> {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.inner.to_le_bytes().to_vec());
+impl<'a, 'b, T: DeviceCopy> KernelParameters for &'b DeviceBox<'a, T> {
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(&self.inner.inner);
     }
 }
 
 /// WARNING: this is unsafe!
-impl<'a, 'b> KernelParameters for &'b DeviceBox<'a> {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.inner.inner.to_le_bytes().to_vec());
+impl<'a, 'b, T: DeviceCopy> KernelParameters for &'b ManagedBox<'a, T> {
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(&(self.as_ptr() as u64));
+    }
+}
+
+/// WARNING: this is unsafe!
+impl<'a, 'b, T: DeviceCopy> KernelParameters for &'b DeviceVec<'a, T> {
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push(&self.as_raw());
+    }
+}
+
+impl<'a, T: DeviceCopy> KernelArgs for DeviceSlice<'a, T> {
+    fn layout(out: &mut KernelParameterBuffer) {
+        u64::layout(out);
+    }
+}
+
+impl<'a, 'b, T: DeviceCopy> KernelArgs for &'b DeviceBox<'a, T> {
+    fn layout(out: &mut KernelParameterBuffer) {
+        u64::layout(out);
+    }
+}
+
+impl<'a, 'b, T: DeviceCopy> KernelArgs for &'b ManagedBox<'a, T> {
+    fn layout(out: &mut KernelParameterBuffer) {
+        u64::layout(out);
+    }
+}
+
+impl<'a, 'b, T: DeviceCopy> KernelArgs for &'b DeviceVec<'a, T> {
+    fn layout(out: &mut KernelParameterBuffer) {
+        u64::layout(out);
     }
 }
 
 impl KernelParameters for &[u8] {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.to_vec());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push_bytes(self, 1);
     }
 }
 
 impl KernelParameters for Vec<u8> {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
-        out.push(self.clone());
+    fn params(&self, out: &mut KernelParameterBuffer) {
+        out.push_bytes(self, 1);
     }
 }
 
 impl<T: KernelParameters + Default + Copy, const N: usize> KernelParameters for [T; N] {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
+    fn params(&self, out: &mut KernelParameterBuffer) {
         for x in self {
             x.params(out);
         }
     }
 }
 
+impl<T: KernelArgs + Default + Copy, const N: usize> KernelArgs for [T; N] {
+    fn layout(out: &mut KernelParameterBuffer) {
+        for _ in 0..N {
+            T::layout(out);
+        }
+    }
+}
+
 impl<T: KernelParameters> KernelParameters for Box<T> {
-    fn params(&self, out: &mut Vec<Vec<u8>>) {
+    fn params(&self, out: &mut KernelParameterBuffer) {
         (&**self).params(out);
     }
 }
 
+impl<T: KernelArgs> KernelArgs for Box<T> {
+    fn layout(out: &mut KernelParameterBuffer) {
+        T::layout(out);
+    }
+}
+
 impl KernelParameters for () {
-    fn params(&self, _out: &mut Vec<Vec<u8>>) {}
+    fn params(&self, _out: &mut KernelParameterBuffer) {}
+}
+
+impl KernelArgs for () {
+    fn layout(_out: &mut KernelParameterBuffer) {}
 }
 
 macro_rules! tuple_impls {
     ($($len:expr => ($($n:tt $name:ident)+))+) => {
         $(
             impl<$($name: KernelParameters),+> KernelParameters for ($($name,)+) {
-                fn params(&self, out: &mut Vec<Vec<u8>>) {
+                fn params(&self, out: &mut KernelParameterBuffer) {
                     $(
                         $name::params(&self.$n, out);
                     )+
                 }
             }
+
+            impl<$($name: KernelArgs),+> KernelArgs for ($($name,)+) {
+                fn layout(out: &mut KernelParameterBuffer) {
+                    $(
+                        $name::layout(out);
+                    )+
+                }
+            }
         )+
     }
 }
@@ -1,5 +1,8 @@
 #![allow(clippy::missing_safety_doc)]
 
+#[macro_use]
+mod loader;
+
 #[allow(
     non_upper_case_globals,
     non_snake_case,
@@ -9,30 +12,54 @@
 #[doc(hidden)]
 pub mod sys;
 
+pub mod backend;
+pub mod build;
 pub mod context;
+pub mod cubin;
 pub mod device;
+pub mod device_copy;
 pub mod dim3;
 pub mod error;
+pub mod event;
+pub mod fatbin;
 pub mod func;
-// pub mod future;
+pub mod future;
+pub mod graph;
+pub mod host;
 pub mod init;
 pub mod kernel_params;
+pub mod managed;
 pub mod mem;
 pub mod module;
+pub mod pool;
+pub mod ptx;
 pub mod stream;
 pub mod version;
+pub mod vmm;
 
 pub struct Cuda;
 
 pub use context::*;
+pub use cubin::*;
+#[cfg(feature = "derive")]
+pub use cuda_oxide_derive::{DeviceCopy, KernelParameters};
 pub use device::*;
+pub use device_copy::DeviceCopy;
 pub use dim3::*;
 pub(crate) use error::cuda_error;
 pub use error::{CudaResult, ErrorCode};
+pub use event::*;
+pub use fatbin::*;
 pub use func::*;
-// pub use future::*;
+pub use future::*;
+pub use graph::*;
+pub use host::*;
 pub use kernel_params::*;
+pub use managed::*;
 pub use mem::*;
 pub use module::*;
+pub use pool::*;
+pub use ptx::*;
 pub use stream::*;
 pub use version::*;
+pub use vmm::*;
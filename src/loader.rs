@@ -0,0 +1,118 @@
+//! Resolution of driver entry points. By default `libcuda` is linked, with the `dynamic-loading` feature it is opened at runtime instead.
+
+/// Declares a driver entry point of [`sys`](crate::sys).
+/// Without the `dynamic-loading` feature this is a plain `extern "C"` declaration, linked against `libcuda`.
+/// With it, the entry point is resolved from the driver library on first call, returning `CUDA_ERROR_SHARED_OBJECT_SYMBOL_NOT_FOUND` if the driver is too old to have it,
+/// or [`ErrorCode::DriverNotFound`](crate::ErrorCode::DriverNotFound) if there is no driver.
+macro_rules! cuda_extern {
+    ($(#[$meta:meta])* pub fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;) => {
+        #[cfg(not(feature = "dynamic-loading"))]
+        extern "C" {
+            $(#[$meta])*
+            pub fn $name($($arg: $ty),*) -> $ret;
+        }
+
+        #[cfg(feature = "dynamic-loading")]
+        $(#[$meta])*
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name($($arg: $ty),*) -> $ret {
+            static SYMBOL: ::std::sync::atomic::AtomicPtr<::std::ffi::c_void> =
+                ::std::sync::atomic::AtomicPtr::new(::std::ptr::null_mut());
+            match $crate::loader::symbol(&SYMBOL, concat!(stringify!($name), "\0")) {
+                Ok(symbol) => ::std::mem::transmute::<
+                    *mut ::std::ffi::c_void,
+                    unsafe extern "C" fn($($ty),*) -> $ret,
+                >(symbol)($($arg),*),
+                Err(error) => error,
+            }
+        }
+    };
+}
+
+#[cfg(feature = "dynamic-loading")]
+pub(crate) use dynamic::*;
+
+#[cfg(feature = "dynamic-loading")]
+mod dynamic {
+    use std::{
+        ffi::c_void,
+        os::raw::{c_char, c_int},
+        sync::{
+            atomic::{AtomicPtr, Ordering},
+            OnceLock,
+        },
+    };
+
+    use crate::{sys, ErrorCode};
+
+    #[cfg(unix)]
+    const LIBRARY: &str = "libcuda.so.1\0";
+    #[cfg(windows)]
+    const LIBRARY: &str = "nvcuda.dll\0";
+
+    #[cfg(unix)]
+    const RTLD_NOW: c_int = 2;
+
+    #[cfg(unix)]
+    extern "C" {
+        fn dlopen(filename: *const c_char, flags: c_int) -> *mut c_void;
+        fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    }
+
+    #[cfg(windows)]
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn LoadLibraryA(filename: *const c_char) -> *mut c_void;
+        fn GetProcAddress(module: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    }
+
+    #[cfg(unix)]
+    unsafe fn open(filename: &str) -> *mut c_void {
+        dlopen(filename.as_ptr() as *const c_char, RTLD_NOW)
+    }
+
+    #[cfg(windows)]
+    unsafe fn open(filename: &str) -> *mut c_void {
+        LoadLibraryA(filename.as_ptr() as *const c_char)
+    }
+
+    #[cfg(unix)]
+    unsafe fn lookup(library: *mut c_void, symbol: &str) -> *mut c_void {
+        dlsym(library, symbol.as_ptr() as *const c_char)
+    }
+
+    #[cfg(windows)]
+    unsafe fn lookup(library: *mut c_void, symbol: &str) -> *mut c_void {
+        GetProcAddress(library, symbol.as_ptr() as *const c_char)
+    }
+
+    /// The driver library handle, or 0 if it failed to load. It is never closed.
+    static LIBRARY_HANDLE: OnceLock<usize> = OnceLock::new();
+
+    fn library() -> Option<*mut c_void> {
+        let handle = *LIBRARY_HANDLE.get_or_init(|| unsafe { open(LIBRARY) } as usize);
+        if handle == 0 {
+            None
+        } else {
+            Some(handle as *mut c_void)
+        }
+    }
+
+    /// Resolves the NUL terminated entry point `name`, caching it in `cache`
+    pub(crate) fn symbol(
+        cache: &AtomicPtr<c_void>,
+        name: &str,
+    ) -> Result<*mut c_void, sys::CUresult> {
+        let cached = cache.load(Ordering::Relaxed);
+        if !cached.is_null() {
+            return Ok(cached);
+        }
+        let library = library().ok_or(ErrorCode::DriverNotFound as sys::CUresult)?;
+        let symbol = unsafe { lookup(library, name) };
+        if symbol.is_null() {
+            return Err(sys::cudaError_enum_CUDA_ERROR_SHARED_OBJECT_SYMBOL_NOT_FOUND);
+        }
+        cache.store(symbol, Ordering::Relaxed);
+        Ok(symbol)
+    }
+}
@@ -0,0 +1,195 @@
+use num_enum::TryFromPrimitive;
+use std::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+    sync::Arc,
+};
+
+use crate::*;
+
+/// The `CUdevice` value referring to the host in managed memory calls
+const DEVICE_CPU: i32 = -1;
+
+/// Which streams may access a [`ManagedBox`], see [`Stream::attach_mem`]
+#[derive(Debug, Copy, Clone, TryFromPrimitive)]
+#[repr(u32)]
+pub enum MemAttach {
+    /// The memory can be accessed by any stream on any device
+    Global = 0x1,
+    /// The memory cannot be accessed by any stream on any device
+    Host = 0x2,
+    /// The memory can only be accessed by the stream it is attached to
+    Single = 0x4,
+}
+
+/// Usage hints for a [`ManagedBox`], see [`ManagedBox::advise`]
+#[derive(Debug, Copy, Clone, TryFromPrimitive)]
+#[repr(u32)]
+pub enum MemAdvice {
+    /// Data will mostly be read and only occasionally be written to, so read-only copies may be created on each accessing processor
+    SetReadMostly = 1,
+    /// Undo the effect of [`MemAdvice::SetReadMostly`]
+    UnsetReadMostly = 2,
+    /// Set the preferred location for the data as the given processor
+    SetPreferredLocation = 3,
+    /// Clear the preferred location for the data
+    UnsetPreferredLocation = 4,
+    /// Data will be accessed by the given processor, so keep it mapped there
+    SetAccessedBy = 5,
+    /// Undo the effect of [`MemAdvice::SetAccessedBy`] for the given processor
+    UnsetAccessedBy = 6,
+}
+
+/// An owned buffer of managed (unified) memory, accessible from both the host and the device through the same address.
+/// Pages are migrated on demand to whichever processor accesses them.
+/// The host must not access the buffer while a kernel using it may be running, unless the device supports [`DeviceAttribute::ConcurrentManagedAccess`]: synchronize the relevant [`Stream`] or [`Context`] first.
+pub struct ManagedBox<'a, T: DeviceCopy> {
+    handle: Arc<Handle<'a>>,
+    pub(crate) inner: NonNull<T>,
+    len: usize,
+}
+
+impl<'a, T: DeviceCopy> ManagedBox<'a, T> {
+    /// Allocates a new managed buffer attached globally, matching the size and content of `input`
+    pub fn new(handle: &Arc<Handle<'a>>, input: &[T]) -> CudaResult<Self> {
+        Self::new_with_attach(handle, input, MemAttach::Global)
+    }
+
+    /// Allocates a new managed buffer with initial attachment `attach`, matching the size and content of `input`.
+    /// [`MemAttach::Single`] is not allowed here.
+    pub fn new_with_attach(
+        handle: &Arc<Handle<'a>>,
+        input: &[T],
+        attach: MemAttach,
+    ) -> CudaResult<Self> {
+        let out = unsafe { Self::alloc(handle, input.len(), attach) }?;
+        unsafe {
+            std::ptr::copy_nonoverlapping(input.as_ptr(), out.inner.as_ptr(), input.len());
+        }
+        Ok(out)
+    }
+
+    /// Allocates a new managed buffer attached globally of `len` elements all set to `value`
+    pub fn filled(handle: &Arc<Handle<'a>>, value: T, len: usize) -> CudaResult<Self> {
+        let out = unsafe { Self::alloc(handle, len, MemAttach::Global) }?;
+        for i in 0..len {
+            unsafe { out.inner.as_ptr().add(i).write(value) };
+        }
+        Ok(out)
+    }
+
+    unsafe fn alloc(handle: &Arc<Handle<'a>>, len: usize, attach: MemAttach) -> CudaResult<Self> {
+        let size = len
+            .checked_mul(std::mem::size_of::<T>())
+            .expect("overflow in ManagedBox::alloc");
+        if size == 0 {
+            return Ok(ManagedBox {
+                handle: handle.clone(),
+                inner: NonNull::dangling(),
+                len,
+            });
+        }
+        let _current = handle.current()?;
+        let mut out = 0u64;
+        cuda_error(backend::cuMemAllocManaged(
+            &mut out as *mut u64,
+            size as sys::size_t,
+            attach as u32,
+        ))?;
+        Ok(ManagedBox {
+            handle: handle.clone(),
+            inner: NonNull::new(out as *mut T).ok_or(ErrorCode::OutOfMemory)?,
+            len,
+        })
+    }
+
+    /// Gets the length of this buffer in bytes
+    pub fn byte_len(&self) -> u64 {
+        std::mem::size_of_val::<[T]>(self) as u64
+    }
+
+    /// Gets a [`DeviceSlice`] to this buffer, i.e. for use with [`DeviceSlice::copy_to_stream`].
+    /// The [`DeviceSlice`] must not be used after this [`ManagedBox`] is dropped.
+    pub fn device_ptr(&self) -> DeviceSlice<'a, T> {
+        DeviceSlice {
+            handle: self.handle.clone(),
+            inner: self.inner.as_ptr() as u64,
+            len: self.len as u64,
+            _p: PhantomData,
+        }
+    }
+
+    /// Asynchronously migrates this buffer to `device` on `stream`, avoiding page faults when it is next accessed there.
+    pub fn prefetch_to(&self, device: &Device, stream: &mut Stream<'a>) -> CudaResult<()> {
+        self.prefetch(device.handle, stream)
+    }
+
+    /// Asynchronously migrates this buffer to host memory on `stream`, avoiding page faults when it is next accessed by the host.
+    pub fn prefetch_to_host(&self, stream: &mut Stream<'a>) -> CudaResult<()> {
+        self.prefetch(DEVICE_CPU, stream)
+    }
+
+    fn prefetch(&self, device: i32, stream: &mut Stream<'a>) -> CudaResult<()> {
+        if self.byte_len() == 0 {
+            return Ok(());
+        }
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuMemPrefetchAsync(
+                self.inner.as_ptr() as u64,
+                self.byte_len() as sys::size_t,
+                device,
+                stream.inner,
+            )
+        })
+    }
+
+    /// Advises `libcuda` about the usage pattern of this buffer by `device`, or the host if `device` is `None`.
+    /// [`MemAdvice::SetReadMostly`] and [`MemAdvice::UnsetReadMostly`] ignore `device`.
+    pub fn advise(&self, advice: MemAdvice, device: Option<&Device>) -> CudaResult<()> {
+        if self.byte_len() == 0 {
+            return Ok(());
+        }
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuMemAdvise(
+                self.inner.as_ptr() as u64,
+                self.byte_len() as sys::size_t,
+                advice as u32,
+                device.map(|x| x.handle).unwrap_or(DEVICE_CPU),
+            )
+        })
+    }
+
+    /// Gets a reference to the owning handle
+    pub fn handle(&self) -> &Arc<Handle<'a>> {
+        &self.handle
+    }
+}
+
+impl<'a, T: DeviceCopy> Drop for ManagedBox<'a, T> {
+    fn drop(&mut self) {
+        if self.byte_len() == 0 {
+            return;
+        }
+        let _current = self.handle.current();
+        if let Err(e) = cuda_error(unsafe { backend::cuMemFree_v2(self.inner.as_ptr() as u64) }) {
+            eprintln!("CUDA: failed freeing managed buffer: {:?}", e);
+        }
+    }
+}
+
+impl<'a, T: DeviceCopy> Deref for ManagedBox<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::slice::from_raw_parts(self.inner.as_ptr(), self.len) }
+    }
+}
+
+impl<'a, T: DeviceCopy> DerefMut for ManagedBox<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { std::slice::from_raw_parts_mut(self.inner.as_ptr(), self.len) }
+    }
+}
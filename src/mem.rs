@@ -1,50 +1,59 @@
 use std::{
+    marker::PhantomData,
     ops::{Deref, DerefMut},
-    pin::Pin,
-    rc::Rc,
+    sync::Arc,
 };
 
 use crate::*;
 
-/// A slice into the device memory.
+/// A typed slice into the device memory, with length in elements of [`T`].
 #[derive(Clone)]
-pub struct DevicePtr<'a> {
-    pub(crate) handle: Rc<Handle<'a>>,
+pub struct DeviceSlice<'a, T: DeviceCopy = u8> {
+    pub(crate) handle: Arc<Handle<'a>>,
     pub(crate) inner: u64,
     pub(crate) len: u64,
+    pub(crate) _p: PhantomData<T>,
 }
 
-impl<'a> DevicePtr<'a> {
+/// An untyped slice into the device memory, with length in bytes.
+pub type DevicePtr<'a> = DeviceSlice<'a, u8>;
+
+impl<'a, T: DeviceCopy> DeviceSlice<'a, T> {
     pub fn as_raw(&self) -> u64 {
         self.inner
     }
 
-    pub unsafe fn from_raw_parts(handle: Rc<Handle<'a>>, ptr: u64, len: u64) -> Self {
+    /// Constructs a [`DeviceSlice`] of `len` elements starting at device address `ptr`.
+    pub unsafe fn from_raw_parts(handle: Arc<Handle<'a>>, ptr: u64, len: u64) -> Self {
         Self {
             handle,
             inner: ptr,
             len,
+            _p: PhantomData,
         }
     }
 
     /// Synchronously copies data from `self` to `target`. Panics if length is not equal.
-    pub fn copy_to<'b>(&self, target: &DevicePtr<'b>) -> CudaResult<()> {
+    pub fn copy_to<'b>(&self, target: &DeviceSlice<'b, T>) -> CudaResult<()> {
         if self.len > target.len {
-            panic!("overflow in DevicePtr::copy_to");
+            panic!("overflow in DeviceSlice::copy_to");
         } else if self.len < target.len {
-            panic!("underflow in DevicePtr::copy_to");
+            panic!("underflow in DeviceSlice::copy_to");
         }
 
+        let _current = self.handle.current()?;
         if std::ptr::eq(self.handle.context, target.handle.context) {
-            cuda_error(unsafe { sys::cuMemcpy(target.inner, self.inner, self.len as sys::size_t) })
+            cuda_error(unsafe {
+                backend::cuMemcpy(target.inner, self.inner, self.byte_len() as sys::size_t)
+            })
         } else {
             cuda_error(unsafe {
-                sys::cuMemcpyPeer(
+                backend::cuMemcpyPeer(
                     target.inner,
                     target.handle.context.inner,
                     self.inner,
                     self.handle.context.inner,
-                    self.len as sys::size_t,
+                    self.byte_len() as sys::size_t,
                 )
             })
         }
@@ -53,94 +62,121 @@ impl<'a> DevicePtr<'a> {
     /// Asynchronously copies data from `self` to `target`. Panics if length is not equal.
     pub fn copy_to_stream<'b, 'c: 'b + 'a>(
         &self,
-        target: &DevicePtr<'b>,
+        target: &DeviceSlice<'b, T>,
         stream: &mut Stream<'c>,
     ) -> CudaResult<()>
     where
         'a: 'b,
     {
         if self.len > target.len {
-            panic!("overflow in DevicePtr::copy_to");
+            panic!("overflow in DeviceSlice::copy_to");
         } else if self.len < target.len {
-            panic!("underflow in DevicePtr::copy_to");
+            panic!("underflow in DeviceSlice::copy_to");
         }
 
+        let _current = self.handle.current()?;
         if std::ptr::eq(self.handle.context, target.handle.context) {
             cuda_error(unsafe {
-                sys::cuMemcpyAsync(
+                backend::cuMemcpyAsync(
                     target.inner,
                     self.inner,
-                    self.len as sys::size_t,
+                    self.byte_len() as sys::size_t,
                     stream.inner,
                 )
             })
         } else {
             cuda_error(unsafe {
-                sys::cuMemcpyPeerAsync(
+                backend::cuMemcpyPeerAsync(
                     target.inner,
                     target.handle.context.inner,
                     self.inner,
                     self.handle.context.inner,
-                    self.len as sys::size_t,
+                    self.byte_len() as sys::size_t,
                     stream.inner,
                 )
             })
         }
     }
 
-    // pub fn copy_to_async<'b>(&self, target: &DevicePtr<'b>) -> CudaResult<CudaFuture<'a, ()>>
-    // where
-    //     'a: 'b,
-    // {
-    //     let mut stream = self.handle.get_async_stream()?;
-    //     unsafe { self.copy_to_stream(target, &mut stream) }?;
-    //     Ok(CudaFuture::new(self.handle.clone(), stream))
-    // }
+    /// Asynchronously copies data from `self` to `target`, returning a [`CudaFuture`] completing with the copy. Panics if length is not equal.
+    pub fn copy_to_async<'b, 'c: 'b + 'a>(
+        &self,
+        target: &DeviceSlice<'b, T>,
+        stream: &mut Stream<'c>,
+    ) -> CudaResult<CudaFuture<'c, ()>>
+    where
+        'a: 'b,
+    {
+        self.copy_to_stream(target, stream)?;
+        CudaFuture::new(stream, ())
+    }
 
     /// Synchronously copies data from `source` to `self`. Panics if length is not equal.
-    pub fn copy_from<'b>(&self, source: &DevicePtr<'b>) -> CudaResult<()> {
+    pub fn copy_from<'b>(&self, source: &DeviceSlice<'b, T>) -> CudaResult<()> {
         source.copy_to(self)
     }
 
     /// Asynchronously copies data from `source` to `self`. Panics if length is not equal.
     pub fn copy_from_stream<'b: 'a, 'c: 'a + 'b>(
         &self,
-        source: &DevicePtr<'b>,
+        source: &DeviceSlice<'b, T>,
         stream: &mut Stream<'c>,
     ) -> CudaResult<()> {
         source.copy_to_stream(self, stream)
     }
 
-    /// Gets a subslice of this slice from `[from:to]`
+    /// Gets a subslice of this slice from elements `[from:to]`
     pub fn subslice(&self, from: u64, to: u64) -> Self {
         if from > self.len || from > to || to > self.len {
-            panic!("overflow in DevicePtr::subslice");
+            panic!("overflow in DeviceSlice::subslice");
         }
         Self {
             handle: self.handle.clone(),
-            inner: self.inner + from,
+            inner: self.inner + from * std::mem::size_of::<T>() as u64,
             len: to - from,
+            _p: PhantomData,
+        }
+    }
+
+    /// Reinterprets this slice as a slice of [`U`], i.e. `cast::<u8>()` to get an untyped [`DevicePtr`].
+    /// Panics if the length in bytes is not a multiple of the size of [`U`].
+    pub fn cast<U: DeviceCopy>(&self) -> DeviceSlice<'a, U> {
+        let size = std::mem::size_of::<U>() as u64;
+        if size == 0 || self.byte_len() % size != 0 {
+            panic!("alignment failure in DeviceSlice::cast");
+        }
+        DeviceSlice {
+            handle: self.handle.clone(),
+            inner: self.inner,
+            len: self.byte_len() / size,
+            _p: PhantomData,
         }
     }
 
-    /// Gets the length of this slice
+    /// Gets the length of this slice in elements
     pub fn len(&self) -> u64 {
         self.len
     }
 
+    /// Gets the length of this slice in bytes
+    pub fn byte_len(&self) -> u64 {
+        self.len * std::mem::size_of::<T>() as u64
+    }
+
     /// Check if the slice's length is 0
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
 
     /// Synchronously loads the data from this slice into a local buffer
-    pub fn load(&self) -> CudaResult<Vec<u8>> {
+    pub fn load(&self) -> CudaResult<Vec<T>> {
         let mut buf = Vec::with_capacity(self.len as usize);
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuMemcpyDtoH_v2(
+            backend::cuMemcpyDtoH_v2(
                 buf.as_mut_ptr() as *mut _,
                 self.inner,
-                self.len as sys::size_t,
+                self.byte_len() as sys::size_t,
             )
         })?;
         unsafe { buf.set_len(self.len as usize) };
@@ -150,30 +186,44 @@ impl<'a> DevicePtr<'a> {
     /// Asynchronously loads the data from this slice into a local buffer.
     /// The contents of the buffer are undefined until `stream.sync` is called.
     /// The output must not be dropped until the stream is synced.
-    pub unsafe fn load_stream(&self, stream: &mut Stream<'a>) -> CudaResult<Vec<u8>> {
+    pub unsafe fn load_stream(&self, stream: &mut Stream<'a>) -> CudaResult<Vec<T>> {
         let mut buf = Vec::with_capacity(self.len as usize);
-        cuda_error(sys::cuMemcpyDtoHAsync_v2(
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuMemcpyDtoHAsync_v2(
             buf.as_mut_ptr() as *mut _,
             self.inner,
-            self.len as sys::size_t,
+            self.byte_len() as sys::size_t,
             stream.inner,
         ))?;
         buf.set_len(self.len as usize);
         Ok(buf)
     }
 
+    /// Asynchronously loads the data from this slice, returning a [`CudaFuture`] completing with a local buffer of the data.
+    /// Returns [`ErrorCode::StreamCaptureUnsupported`] if `stream` is capturing.
+    pub fn load_async(&self, stream: &mut Stream<'a>) -> CudaResult<CudaFuture<'a, Vec<T>>> {
+        // captured copies would outlive the buffer
+        if stream.capturing {
+            return Err(ErrorCode::StreamCaptureUnsupported);
+        }
+        // the future owns the buffer, so it can't be read before the copy completes
+        let buf = unsafe { self.load_stream(stream) }?;
+        CudaFuture::new(stream, buf)
+    }
+
     /// Synchronously stores host data from `data` to `self`.
-    pub fn store(&self, data: &[u8]) -> CudaResult<()> {
+    pub fn store(&self, data: &[T]) -> CudaResult<()> {
         if data.len() > self.len as usize {
-            panic!("overflow in DevicePtr::store");
+            panic!("overflow in DeviceSlice::store");
         } else if data.len() < self.len as usize {
-            panic!("underflow in DevicePtr::store");
+            panic!("underflow in DeviceSlice::store");
         }
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuMemcpyHtoD_v2(
+            backend::cuMemcpyHtoD_v2(
                 self.inner,
                 data.as_ptr() as *const _,
-                self.len as sys::size_t,
+                self.byte_len() as sys::size_t,
             )
         })?;
         Ok(())
@@ -181,17 +231,18 @@ impl<'a> DevicePtr<'a> {
 
     /// Asynchronously stores host data from `data` to `self`.
     /// The `data` must not be dropped or mutated until `stream.sync` is called.
-    pub fn store_stream<'b>(&self, data: &'b [u8], stream: &'b mut Stream<'a>) -> CudaResult<()> {
+    pub fn store_stream<'b>(&self, data: &'b [T], stream: &'b mut Stream<'a>) -> CudaResult<()> {
         if data.len() > self.len as usize {
-            panic!("overflow in DevicePtr::store");
+            panic!("overflow in DeviceSlice::store");
         } else if data.len() < self.len as usize {
-            panic!("underflow in DevicePtr::store");
+            panic!("underflow in DeviceSlice::store");
         }
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuMemcpyHtoDAsync_v2(
+            backend::cuMemcpyHtoDAsync_v2(
                 self.inner,
                 data.as_ptr() as *const _,
-                self.len as sys::size_t,
+                self.byte_len() as sys::size_t,
                 stream.inner,
             )
         })?;
@@ -200,104 +251,265 @@ impl<'a> DevicePtr<'a> {
 
     /// Asynchronously stores host data from `data` to `self`.
     /// `data` will be dropped once the [`Stream`] is synced or dropped.
-    pub fn store_stream_buf(&self, data: Vec<u8>, stream: &mut Stream<'a>) -> CudaResult<()> {
+    pub fn store_stream_buf(&self, data: Vec<T>, stream: &mut Stream<'a>) -> CudaResult<()> {
         if data.len() > self.len as usize {
-            panic!("overflow in DevicePtr::store");
+            panic!("overflow in DeviceSlice::store");
         } else if data.len() < self.len as usize {
-            panic!("underflow in DevicePtr::store");
+            panic!("underflow in DeviceSlice::store");
         }
-        let data: Pin<Box<[u8]>> = data.into_boxed_slice().into();
+        // moving the box does not move its heap allocation
+        let data = Box::new(data.into_boxed_slice());
+        let ptr = data.as_ptr();
         stream.pending_stores.push(data);
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuMemcpyHtoDAsync_v2(
+            backend::cuMemcpyHtoDAsync_v2(
                 self.inner,
-                stream.pending_stores.last().unwrap().as_ptr() as *const _,
-                self.len as sys::size_t,
+                ptr as *const _,
+                self.byte_len() as sys::size_t,
                 stream.inner,
             )
         })?;
         Ok(())
     }
 
+    /// Asynchronously stores host data from `data` to `self`, returning a [`CudaFuture`] completing with `data` once it has been copied, so it can be reused.
+    /// Returns [`ErrorCode::StreamCaptureUnsupported`] if `stream` is capturing.
+    pub fn store_async(
+        &self,
+        data: Vec<T>,
+        stream: &mut Stream<'a>,
+    ) -> CudaResult<CudaFuture<'a, Vec<T>>> {
+        if data.len() > self.len as usize {
+            panic!("overflow in DeviceSlice::store_async");
+        } else if data.len() < self.len as usize {
+            panic!("underflow in DeviceSlice::store_async");
+        }
+        // captured copies would outlive the buffer
+        if stream.capturing {
+            return Err(ErrorCode::StreamCaptureUnsupported);
+        }
+        // moving the vec into the future does not move its heap allocation
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuMemcpyHtoDAsync_v2(
+                self.inner,
+                data.as_ptr() as *const _,
+                self.byte_len() as sys::size_t,
+                stream.inner,
+            )
+        })?;
+        CudaFuture::new(stream, data)
+    }
+
+    /// Asynchronously stores pinned host data from `data` (i.e. a [`HostBox`] or [`HostRegistration`]) to `self`. Unlike [`DeviceSlice::store_stream`], the copy does not block the host and can overlap with kernel execution.
+    /// The `data` must not be dropped or mutated until `stream.sync` is called.
+    pub fn store_stream_host<'b, P: PinnedHostMemory<T>>(
+        &self,
+        data: &'b P,
+        stream: &'b mut Stream<'a>,
+    ) -> CudaResult<()> {
+        if data.byte_len() > self.byte_len() {
+            panic!("overflow in DeviceSlice::store_stream_host");
+        } else if data.byte_len() < self.byte_len() {
+            panic!("underflow in DeviceSlice::store_stream_host");
+        }
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuMemcpyHtoDAsync_v2(
+                self.inner,
+                data.as_ptr() as *const _,
+                self.byte_len() as sys::size_t,
+                stream.inner,
+            )
+        })
+    }
+
+    /// Asynchronously loads the data from this slice into pinned host memory `target` (i.e. a [`HostBox`] or [`HostRegistration`]). Unlike [`DeviceSlice::load_stream`], the copy does not block the host and can overlap with kernel execution.
+    /// The contents of `target` are undefined until `stream.sync` is called.
+    /// `target` must not be dropped or accessed until the stream is synced.
+    pub unsafe fn load_stream_host<P: PinnedHostMemory<T>>(
+        &self,
+        target: &mut P,
+        stream: &mut Stream<'a>,
+    ) -> CudaResult<()> {
+        if target.byte_len() > self.byte_len() {
+            panic!("underflow in DeviceSlice::load_stream_host");
+        } else if target.byte_len() < self.byte_len() {
+            panic!("overflow in DeviceSlice::load_stream_host");
+        }
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuMemcpyDtoHAsync_v2(
+            target.as_mut_ptr() as *mut _,
+            self.inner,
+            self.byte_len() as sys::size_t,
+            stream.inner,
+        ))
+    }
+
     /// Synchronously set the contents of `self` to `data` repeated to fill length
     pub fn memset_d8(&self, data: u8) -> CudaResult<()> {
-        cuda_error(unsafe { sys::cuMemsetD8_v2(self.inner, data, self.len as sys::size_t) })
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuMemsetD8_v2(self.inner, data, self.byte_len() as sys::size_t)
+        })
     }
 
     /// Asynchronously set the contents of `self` to `data` repeated to fill length
     pub fn memset_d8_stream(&self, data: u8, stream: &mut Stream<'a>) -> CudaResult<()> {
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuMemsetD8Async(self.inner, data, self.len as sys::size_t, stream.inner)
+            backend::cuMemsetD8Async(
+                self.inner,
+                data,
+                self.byte_len() as sys::size_t,
+                stream.inner,
+            )
         })
     }
 
     /// Synchronously set the contents of `self` to `data` repeated to fill length.
-    /// Panics if [`Self::len`] is not a multiple of 2.
+    /// Panics if [`Self::byte_len`] is not a multiple of 2.
     pub fn memset_d16(&self, data: u16) -> CudaResult<()> {
-        if self.len % 2 != 0 {
-            panic!("alignment failure in DevicePtr::memset_d16");
+        if self.byte_len() % 2 != 0 {
+            panic!("alignment failure in DeviceSlice::memset_d16");
         }
-        cuda_error(unsafe { sys::cuMemsetD16_v2(self.inner, data, self.len as sys::size_t / 2) })
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuMemsetD16_v2(self.inner, data, self.byte_len() as sys::size_t / 2)
+        })
     }
 
     /// Asynchronously set the contents of `self` to `data` repeated to fill length.
-    /// Panics if [`Self::len`] is not a multiple of 2.
+    /// Panics if [`Self::byte_len`] is not a multiple of 2.
     pub fn memset_d16_stream(&self, data: u16, stream: &mut Stream<'a>) -> CudaResult<()> {
-        if self.len % 2 != 0 {
-            panic!("alignment failure in DevicePtr::memset_d16_stream");
+        if self.byte_len() % 2 != 0 {
+            panic!("alignment failure in DeviceSlice::memset_d16_stream");
         }
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuMemsetD16Async(self.inner, data, self.len as sys::size_t / 2, stream.inner)
+            backend::cuMemsetD16Async(
+                self.inner,
+                data,
+                self.byte_len() as sys::size_t / 2,
+                stream.inner,
+            )
         })
     }
 
     /// Synchronously set the contents of `self` to `data` repeated to fill length.
-    /// Panics if [`Self::len`] is not a multiple of 4.
+    /// Panics if [`Self::byte_len`] is not a multiple of 4.
     pub fn memset_d32(&self, data: u32) -> CudaResult<()> {
-        if self.len % 4 != 0 {
-            panic!("alignment failure in DevicePtr::memset_d32");
+        if self.byte_len() % 4 != 0 {
+            panic!("alignment failure in DeviceSlice::memset_d32");
         }
-        cuda_error(unsafe { sys::cuMemsetD32_v2(self.inner, data, self.len as sys::size_t / 4) })
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuMemsetD32_v2(self.inner, data, self.byte_len() as sys::size_t / 4)
+        })
     }
 
     /// Asynchronously set the contents of `self` to `data` repeated to fill length.
-    /// Panics if [`Self::len`] is not a multiple of 4.
+    /// Panics if [`Self::byte_len`] is not a multiple of 4.
     pub fn memset_d32_stream(&self, data: u32, stream: &mut Stream<'a>) -> CudaResult<()> {
-        if self.len % 4 != 0 {
-            panic!("alignment failure in DevicePtr::memset_d32_stream");
+        if self.byte_len() % 4 != 0 {
+            panic!("alignment failure in DeviceSlice::memset_d32_stream");
         }
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuMemsetD32Async(self.inner, data, self.len as sys::size_t / 4, stream.inner)
+            backend::cuMemsetD32Async(
+                self.inner,
+                data,
+                self.byte_len() as sys::size_t / 4,
+                stream.inner,
+            )
         })
     }
 
     /// Gets a reference to the owning handle
-    pub fn handle(&self) -> &Rc<Handle<'a>> {
+    pub fn handle(&self) -> &Arc<Handle<'a>> {
         &self.handle
     }
 }
 
-/// An owned device-allocated buffer
-pub struct DeviceBox<'a> {
-    pub(crate) inner: DevicePtr<'a>,
+/// An owned device-allocated buffer of [`T`]
+pub struct DeviceBox<'a, T: DeviceCopy = u8> {
+    pub(crate) inner: DeviceSlice<'a, T>,
+    // set for stream-ordered allocations, which are freed on their allocating stream
+    pub(crate) free_stream: Option<Arc<RawStream<'a>>>,
 }
 
-impl<'a> DeviceBox<'a> {
-    /// Allocate an uninitialized buffer of size `size` on the device
-    pub fn alloc(handle: &Rc<Handle<'a>>, size: u64) -> CudaResult<Self> {
+impl<'a, T: DeviceCopy> DeviceBox<'a, T> {
+    fn alloc_size(len: u64) -> sys::size_t {
+        len.checked_mul(std::mem::size_of::<T>() as u64)
+            .expect("overflow in DeviceBox::alloc") as sys::size_t
+    }
+
+    /// Allocate an uninitialized buffer of `len` elements on the device
+    pub fn alloc(handle: &Arc<Handle<'a>>, len: u64) -> CudaResult<Self> {
         let mut out = 0u64;
-        cuda_error(unsafe { sys::cuMemAlloc_v2(&mut out as *mut u64, size as sys::size_t) })?;
+        let _current = handle.current()?;
+        cuda_error(unsafe { backend::cuMemAlloc_v2(&mut out as *mut u64, Self::alloc_size(len)) })?;
         Ok(DeviceBox {
-            inner: DevicePtr {
+            inner: DeviceSlice {
                 handle: handle.clone(),
                 inner: out,
-                len: size,
+                len,
+                _p: PhantomData,
             },
+            free_stream: None,
+        })
+    }
+
+    /// Allocate an uninitialized buffer of `len` elements on the device from the current [`MemPool`] of the device, ordered on `stream`.
+    /// The buffer may only be used by work ordered after the allocation on `stream`, and is freed on `stream` when dropped.
+    pub fn alloc_async(stream: &mut Stream<'a>, len: u64) -> CudaResult<Self> {
+        let mut out = 0u64;
+        let _current = stream.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuMemAllocAsync(&mut out as *mut u64, Self::alloc_size(len), stream.inner)
+        })?;
+        Ok(DeviceBox {
+            inner: DeviceSlice {
+                handle: stream.handle.clone(),
+                inner: out,
+                len,
+                _p: PhantomData,
+            },
+            free_stream: Some(stream.raw.clone()),
+        })
+    }
+
+    /// Allocate an uninitialized buffer of `len` elements on the device from `pool`, ordered on `stream`.
+    /// The buffer may only be used by work ordered after the allocation on `stream`, and is freed on `stream` when dropped.
+    pub fn alloc_async_from_pool(
+        pool: &MemPool,
+        stream: &mut Stream<'a>,
+        len: u64,
+    ) -> CudaResult<Self> {
+        let mut out = 0u64;
+        let _current = stream.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuMemAllocFromPoolAsync(
+                &mut out as *mut u64,
+                Self::alloc_size(len),
+                pool.inner,
+                stream.inner,
+            )
+        })?;
+        Ok(DeviceBox {
+            inner: DeviceSlice {
+                handle: stream.handle.clone(),
+                inner: out,
+                len,
+                _p: PhantomData,
+            },
+            free_stream: Some(stream.raw.clone()),
         })
     }
 
     /// Allocate a new initialized buffer on the device matching the size and content of `input`.
-    pub fn new(handle: &Rc<Handle<'a>>, input: &[u8]) -> CudaResult<Self> {
+    pub fn new(handle: &Arc<Handle<'a>>, input: &[T]) -> CudaResult<Self> {
         let buf = Self::alloc(handle, input.len() as u64)?;
         buf.store(input)?;
         Ok(buf)
@@ -307,8 +519,8 @@ impl<'a> DeviceBox<'a> {
     /// `input` must not be dropped or mutated until `stream.sync` is called.
     /// Does not allocate the memory asynchronously.
     pub fn new_stream<'b>(
-        handle: &Rc<Handle<'a>>,
-        input: &'b [u8],
+        handle: &Arc<Handle<'a>>,
+        input: &'b [T],
         stream: &'b mut Stream<'a>,
     ) -> CudaResult<Self> {
         let buf = Self::alloc(handle, input.len() as u64)?;
@@ -320,8 +532,8 @@ impl<'a> DeviceBox<'a> {
     /// `input` will be dropped when the stream is synced or dropped.
     /// Does not allocate the memory asynchronously.
     pub fn new_stream_buf(
-        handle: &Rc<Handle<'a>>,
-        input: Vec<u8>,
+        handle: &Arc<Handle<'a>>,
+        input: Vec<T>,
         stream: &mut Stream<'a>,
     ) -> CudaResult<Self> {
         let buf = Self::alloc(handle, input.len() as u64)?;
@@ -329,96 +541,88 @@ impl<'a> DeviceBox<'a> {
         Ok(buf)
     }
 
+    /// Leaks the DeviceBox, similar to [`Box::leak`].
+    pub fn leak(self) {
+        std::mem::forget(self);
+    }
+
+    /// Constructs a [`DeviceBox`] from a device pointer.
+    pub unsafe fn from_raw(raw: DeviceSlice<'a, T>) -> Self {
+        Self {
+            inner: raw,
+            free_stream: None,
+        }
+    }
+}
+
+impl<'a> DeviceBox<'a> {
     /// Allocates a new initialized buffer on the device matching the size and content of `input`.
-    /// Note that memory is directly copied, so [`T`] must be [`Sized`] should not contain any pointers, references, unsized types, or other non-FFI safe types.
-    pub fn new_ffi<T>(handle: &Rc<Handle<'a>>, input: &[T]) -> CudaResult<Self> {
-        let raw = unsafe {
-            std::slice::from_raw_parts(
-                input.as_ptr() as *const u8,
-                input.len() * std::mem::size_of::<T>(),
-            )
-        };
-        let buf = Self::alloc(handle, raw.len() as u64)?;
-        buf.store(raw)?;
+    #[deprecated(note = "`DeviceBox` is typed, use `DeviceBox::new`")]
+    pub fn new_ffi<T: DeviceCopy>(handle: &Arc<Handle<'a>>, input: &[T]) -> CudaResult<Self> {
+        let buf = Self::alloc(handle, std::mem::size_of_val(input) as u64)?;
+        buf.cast::<T>().store(input)?;
         Ok(buf)
     }
 
     /// Allocates a new uninitialized buffer on the device, then synchronously fills it with `input`.
-    /// Note that memory is directly copied, so [`T`] must be [`Sized`] *should* not contain any pointers, references, unsized types, or other non-FFI safe types.
     /// `input` must not be dropped or mutated until `stream.sync` is called.
     /// Does not allocate the memory asynchronously.
-    pub fn new_ffi_stream<'b, T>(
-        handle: &Rc<Handle<'a>>,
+    #[deprecated(note = "`DeviceBox` is typed, use `DeviceBox::new_stream`")]
+    pub fn new_ffi_stream<'b, T: DeviceCopy>(
+        handle: &Arc<Handle<'a>>,
         input: &'b [T],
         stream: &'b mut Stream<'a>,
     ) -> CudaResult<Self> {
-        let raw = unsafe {
-            std::slice::from_raw_parts(
-                input.as_ptr() as *const u8,
-                input.len() * std::mem::size_of::<T>(),
-            )
-        };
-        let buf = Self::alloc(handle, raw.len() as u64)?;
-        buf.store_stream(raw, stream)?;
+        let buf = Self::alloc(handle, std::mem::size_of_val(input) as u64)?;
+        buf.cast::<T>().store_stream(input, stream)?;
         Ok(buf)
     }
 
     /// Allocates a new uninitialized buffer on the device, then synchronously fills it with `input`.
-    /// Note that memory is directly copied, so [`T`] must be [`Sized`] *should* not contain any pointers, references, unsized types, or other non-FFI safe types.
     /// `input` will be dropped when the stream is synced or dropped.
     /// Does not allocate the memory asynchronously.
-    pub fn new_ffi_stream_buf<'b, T>(
-        handle: &Rc<Handle<'a>>,
-        mut input: Vec<T>,
+    #[deprecated(note = "`DeviceBox` is typed, use `DeviceBox::new_stream_buf`")]
+    pub fn new_ffi_stream_buf<'b, T: DeviceCopy>(
+        handle: &Arc<Handle<'a>>,
+        input: Vec<T>,
         stream: &'b mut Stream<'a>,
     ) -> CudaResult<Self> {
-        let raw = unsafe {
-            Vec::from_raw_parts(
-                input.as_mut_ptr() as *mut u8,
-                input.len() * std::mem::size_of::<T>(),
-                input.capacity() * std::mem::size_of::<T>(),
-            )
-        };
-        std::mem::forget(input);
-        let buf = Self::alloc(handle, raw.len() as u64)?;
-        buf.store_stream_buf(raw, stream)?;
+        let buf = Self::alloc(handle, std::mem::size_of_val(&input[..]) as u64)?;
+        buf.cast::<T>().store_stream_buf(input, stream)?;
         Ok(buf)
     }
-
-    /// Leaks the DeviceBox, similar to [`Box::leak`].
-    pub fn leak(self) {
-        std::mem::forget(self);
-    }
-
-    /// Constructs a [`DeviceBox`] from a device pointer.
-    pub unsafe fn from_raw(raw: DevicePtr<'a>) -> Self {
-        Self { inner: raw }
-    }
 }
 
-impl<'a> Drop for DeviceBox<'a> {
+impl<'a, T: DeviceCopy> Drop for DeviceBox<'a, T> {
     fn drop(&mut self) {
-        if let Err(e) = cuda_error(unsafe { sys::cuMemFree_v2(self.inner.inner) }) {
+        let _current = self.inner.handle.current();
+        let out = match &self.free_stream {
+            Some(stream) => {
+                cuda_error(unsafe { backend::cuMemFreeAsync(self.inner.inner, stream.0) })
+            }
+            None => cuda_error(unsafe { backend::cuMemFree_v2(self.inner.inner) }),
+        };
+        if let Err(e) = out {
             eprintln!("CUDA: failed freeing device buffer: {:?}", e);
         }
     }
 }
 
-impl<'a> AsRef<DevicePtr<'a>> for DeviceBox<'a> {
-    fn as_ref(&self) -> &DevicePtr<'a> {
+impl<'a, T: DeviceCopy> AsRef<DeviceSlice<'a, T>> for DeviceBox<'a, T> {
+    fn as_ref(&self) -> &DeviceSlice<'a, T> {
         &self.inner
     }
 }
 
-impl<'a> Deref for DeviceBox<'a> {
-    type Target = DevicePtr<'a>;
+impl<'a, T: DeviceCopy> Deref for DeviceBox<'a, T> {
+    type Target = DeviceSlice<'a, T>;
 
     fn deref(&self) -> &Self::Target {
         &self.inner
     }
 }
 
-impl<'a> DerefMut for DeviceBox<'a> {
+impl<'a, T: DeviceCopy> DerefMut for DeviceBox<'a, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.inner
     }
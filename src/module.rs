@@ -1,8 +1,9 @@
 use std::{
     borrow::Cow,
     ffi::{c_void, CString},
+    marker::PhantomData,
     ptr::null_mut,
-    rc::Rc,
+    sync::Arc,
 };
 
 use crate::*;
@@ -13,7 +14,8 @@ pub struct Linker<'a> {
     inner: *mut sys::CUlinkState_st,
     info_buf: Vec<u8>, // both info_buf and errors_buf contain uninitialized memory! they should always be NUL terminated strings
     errors_buf: Vec<u8>,
-    handle: Rc<Handle<'a>>,
+    handle: Arc<Handle<'a>>,
+    signatures: Vec<KernelSignature>,
 }
 
 /// The type of input to the linker
@@ -51,7 +53,7 @@ impl Default for LinkerOptions {
 impl<'a> Linker<'a> {
     /// Creates a new [`Linker`] for the given context handle, compute capability, and linker options.
     pub fn new(
-        handle: &Rc<Handle<'a>>,
+        handle: &Arc<Handle<'a>>,
         compute_capability: CudaVersion,
         options: LinkerOptions,
     ) -> CudaResult<Self> {
@@ -74,6 +76,7 @@ impl<'a> Linker<'a> {
                 vec![]
             },
             handle: handle.clone(),
+            signatures: vec![],
         };
         let log_verbose = if options.verbose_logs { 1u32 } else { 0u32 };
         let debug_info = if options.debug_info { 1u32 } else { 0u32 };
@@ -117,8 +120,9 @@ impl<'a> Linker<'a> {
             log_verbose as u64 as *mut c_void,
             debug_info as u64 as *mut c_void,
         ];
+        let _current = linker.handle.current()?;
         cuda_error(unsafe {
-            sys::cuLinkCreate_v2(
+            backend::cuLinkCreate_v2(
                 options.len() as u32,
                 options.as_mut_ptr(),
                 values.as_mut_ptr(),
@@ -150,9 +154,14 @@ impl<'a> Linker<'a> {
     }
 
     /// Add an input file to the linker context. `name` is only used for logs
-    pub fn add(self, name: &str, format: LinkerInputType, in_data: &[u8]) -> CudaResult<Self> {
+    /// The `.entry` signatures of PTX inputs are parsed and attached to the [`Function`]s of the built [`Module`], see [`Function::signature`].
+    pub fn add(mut self, name: &str, format: LinkerInputType, in_data: &[u8]) -> CudaResult<Self> {
         let mut data = Cow::Borrowed(in_data);
         if format == LinkerInputType::Ptx {
+            // unparseable PTX is left to libcuda to report, its kernels just have no signature
+            if let Ok(signatures) = KernelSignature::parse_ptx(&String::from_utf8_lossy(in_data)) {
+                self.signatures.extend(signatures);
+            }
             let mut new_data = Vec::with_capacity(in_data.len() + 1);
             new_data.extend_from_slice(in_data);
             new_data.push(0);
@@ -166,8 +175,9 @@ impl<'a> Linker<'a> {
         };
         let name = CString::new(name).unwrap();
 
+        let _current = self.handle.current()?;
         let out = cuda_error(unsafe {
-            sys::cuLinkAddData_v2(
+            backend::cuLinkAddData_v2(
                 self.inner,
                 format,
                 data.as_ptr() as *mut u8 as *mut c_void,
@@ -188,10 +198,11 @@ impl<'a> Linker<'a> {
 
     /// Emit the cubin assembly binary. You probably want [`Linker::build_module`]
     pub fn build(&self) -> CudaResult<&[u8]> {
+        let _current = self.handle.current()?;
         let mut cubin_out: *mut c_void = null_mut();
         let mut size_out: sys::size_t = 0;
         let out = cuda_error(unsafe {
-            sys::cuLinkComplete(
+            backend::cuLinkComplete(
                 self.inner,
                 &mut cubin_out as *mut *mut c_void,
                 &mut size_out as *mut sys::size_t,
@@ -207,13 +218,25 @@ impl<'a> Linker<'a> {
     /// Build a CUDA module from this [`Linker`].
     pub fn build_module(&self) -> CudaResult<Module<'a>> {
         let built = self.build()?;
-        Module::load(&self.handle, built)
+        let mut module = Module::load(&self.handle, built)?;
+        // PTX signatures also carry parameter names and alignment, which cubins lack
+        for function in &mut module.functions {
+            if let Some(signature) = self
+                .signatures
+                .iter()
+                .find(|x| x.name == function.signature.name)
+            {
+                function.signature = signature.clone();
+            }
+        }
+        Ok(module)
     }
 }
 
 impl<'a> Drop for Linker<'a> {
     fn drop(&mut self) {
-        if let Err(e) = cuda_error(unsafe { sys::cuLinkDestroy(self.inner) }) {
+        let _current = self.handle.current();
+        if let Err(e) = cuda_error(unsafe { backend::cuLinkDestroy(self.inner) }) {
             eprintln!("CUDA: failed to destroy cuda linker state: {:?}", e);
         }
     }
@@ -221,59 +244,139 @@ impl<'a> Drop for Linker<'a> {
 
 /// A loaded CUDA module
 pub struct Module<'a> {
-    handle: Rc<Handle<'a>>,
+    pub(crate) handle: Arc<Handle<'a>>,
     inner: *mut sys::CUmod_st,
+    functions: Vec<FunctionInfo>,
+}
+
+// libcuda modules can be used from any thread, and are not mutated once loaded
+unsafe impl<'a> Send for Module<'a> {}
+unsafe impl<'a> Sync for Module<'a> {}
+
+/// Reads the kernel entry points of a cubin, fatbin or NUL terminated PTX image. Images that fail to parse have no known kernels.
+fn module_functions(module: &[u8]) -> Vec<FunctionInfo> {
+    if is_cubin(module) {
+        return FunctionInfo::parse_cubin(module).unwrap_or_default();
+    }
+    if is_fatbin(module) {
+        // every entry holds the same kernels, PTX is preferred as it carries parameter names and alignment
+        let fatbin = match Fatbin::parse(module) {
+            Ok(x) => x,
+            Err(_) => return vec![],
+        };
+        let mut entries = fatbin.entries().to_vec();
+        entries.sort_by_key(|x| x.kind != FatbinEntryKind::Ptx);
+        return entries
+            .iter()
+            .filter_map(|x| x.data().ok())
+            .map(|x| module_functions(&x))
+            .find(|x| !x.is_empty())
+            .unwrap_or_default();
+    }
+    let len = module.iter().position(|x| *x == 0).unwrap_or(module.len());
+    match std::str::from_utf8(&module[..len]) {
+        Ok(ptx) => KernelSignature::parse_ptx(ptx)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|signature| FunctionInfo {
+                signature,
+                registers: None,
+                shared_size: None,
+            })
+            .collect(),
+        Err(_) => vec![],
+    }
 }
 
 impl<'a> Module<'a> {
     /// Takes a raw CUDA kernel image and loads the corresponding module module into the current context.
     /// The pointer can be a cubin or PTX or fatbin file as a NULL-terminated text string
-    pub fn load(handle: &Rc<Handle<'a>>, module: &[u8]) -> CudaResult<Self> {
+    pub fn load(handle: &Arc<Handle<'a>>, module: &[u8]) -> CudaResult<Self> {
+        let _current = handle.current()?;
         let mut inner = null_mut();
         cuda_error(unsafe {
-            sys::cuModuleLoadData(&mut inner as *mut _, module.as_ptr() as *const _)
+            backend::cuModuleLoadData(&mut inner as *mut _, module.as_ptr() as *const _)
         })?;
         Ok(Module {
             inner,
             handle: handle.clone(),
+            functions: module_functions(module),
         })
     }
 
     /// Same as [`Module::load`] but uses `fatCubin` format.
-    pub fn load_fatcubin(handle: &Rc<Handle<'a>>, module: &[u8]) -> CudaResult<Self> {
+    pub fn load_fatcubin(handle: &Arc<Handle<'a>>, module: &[u8]) -> CudaResult<Self> {
+        let _current = handle.current()?;
         let mut inner = null_mut();
         cuda_error(unsafe {
-            sys::cuModuleLoadFatBinary(&mut inner as *mut _, module.as_ptr() as *const _)
+            backend::cuModuleLoadFatBinary(&mut inner as *mut _, module.as_ptr() as *const _)
         })?;
         Ok(Module {
             inner,
             handle: handle.clone(),
+            functions: module_functions(module),
         })
     }
 
+    /// Loads the best image of `fatbin` for a device of compute capability `compute_capability`, see [`Fatbin::select`].
+    /// Unlike [`Module::load_fatcubin`], a missing image is reported with the available architectures, rather than as [`ErrorCode::NoBinaryForGpu`].
+    pub fn load_fatbin(
+        handle: &Arc<Handle<'a>>,
+        compute_capability: CudaVersion,
+        fatbin: &[u8],
+    ) -> Result<Self, FatbinError> {
+        let fatbin = Fatbin::parse(fatbin)?;
+        let entry = fatbin.select(compute_capability)?;
+        let mut image = entry.data()?.into_owned();
+        if entry.kind == FatbinEntryKind::Ptx && image.last() != Some(&0) {
+            image.push(0);
+        }
+        Ok(Module::load(handle, &image)?)
+    }
+
     /// Retrieve a reference to a define CUDA kernel within the module.
     pub fn get_function<'b>(&'b self, name: &str) -> CudaResult<Function<'a, 'b>> {
         let mut inner = null_mut();
+        let info = self.functions.iter().find(|x| x.signature.name == name);
         let name = CString::new(name).unwrap();
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuModuleGetFunction(&mut inner as *mut _, self.inner, name.as_ptr())
+            backend::cuModuleGetFunction(&mut inner as *mut _, self.inner, name.as_ptr())
         })?;
         Ok(Function {
             module: self,
             inner,
+            info,
         })
     }
 
+    /// Retrieve a kernel within the module, typed by its parameters `Args`, i.e. `module.kernel::<(DeviceSlice<f32>, u32)>("fill")`.
+    /// The layout of `Args` is checked against the kernel signature, see [`Function::signature`], so that [`Kernel::launch`] only needs valid device pointers.
+    pub fn kernel<'b, Args: KernelArgs>(
+        &'b self,
+        name: &str,
+    ) -> Result<Kernel<'a, 'b, Args>, LaunchError> {
+        Kernel::new(self.get_function(name)?)
+    }
+
+    /// Gets the kernel entry points of this module, as read from its cubin or PTX image.
+    /// Kernels of images that could not be read are unknown.
+    pub fn functions(&self) -> &[FunctionInfo] {
+        &self.functions
+    }
+
     /// Get a pointer to a global variable defined by a CUDA module.
     pub fn get_global<'b: 'a>(&'b self, name: &str) -> CudaResult<DevicePtr<'b>> {
         let mut out = DevicePtr {
             handle: self.handle.clone(),
             inner: 0,
             len: 0,
+            _p: PhantomData,
         };
         let name = CString::new(name).unwrap();
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuModuleGetGlobal_v2(
+            backend::cuModuleGetGlobal_v2(
                 &mut out.inner,
                 &mut out.len as *mut u64 as *mut _,
                 self.inner,
@@ -294,7 +397,8 @@ impl<'a> Module<'a> {
 
 impl<'a> Drop for Module<'a> {
     fn drop(&mut self) {
-        if let Err(e) = cuda_error(unsafe { sys::cuModuleUnload(self.inner) }) {
+        let _current = self.handle.current();
+        if let Err(e) = cuda_error(unsafe { backend::cuModuleUnload(self.inner) }) {
             eprintln!("CUDA: failed to destroy cuda module: {:?}", e);
         }
     }
@@ -0,0 +1,123 @@
+use std::{ffi::c_void, ptr::null_mut};
+
+use crate::*;
+
+/// A pool of device memory used for stream-ordered allocations, see [`DeviceBox::alloc_async`].
+/// Memory freed back to a pool is cached for reuse by later allocations, up to the release threshold.
+pub struct MemPool {
+    pub(crate) inner: *mut sys::CUmemPoolHandle_st,
+    owned: bool,
+}
+
+impl MemPool {
+    /// Creates a new [`MemPool`] allocating memory on `device`.
+    pub fn new(device: &Device) -> CudaResult<Self> {
+        let props = sys::CUmemPoolProps {
+            allocType: sys::CUmemAllocationType_enum_CU_MEM_ALLOCATION_TYPE_PINNED,
+            handleTypes: sys::CUmemAllocationHandleType_enum_CU_MEM_HANDLE_TYPE_NONE,
+            location: sys::CUmemLocation {
+                type_: sys::CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_DEVICE,
+                id: device.handle,
+            },
+            win32SecurityAttributes: null_mut(),
+            reserved: [0; 64],
+        };
+        let mut inner = null_mut();
+        cuda_error(unsafe { backend::cuMemPoolCreate(&mut inner as *mut _, &props) })?;
+        Ok(MemPool { inner, owned: true })
+    }
+
+    fn get_attribute_u64(&self, attribute: u32) -> CudaResult<u64> {
+        let mut out = 0u64;
+        cuda_error(unsafe {
+            backend::cuMemPoolGetAttribute(
+                self.inner,
+                attribute,
+                &mut out as *mut u64 as *mut c_void,
+            )
+        })?;
+        Ok(out)
+    }
+
+    fn set_attribute_u64(&mut self, attribute: u32, mut value: u64) -> CudaResult<()> {
+        cuda_error(unsafe {
+            backend::cuMemPoolSetAttribute(
+                self.inner,
+                attribute,
+                &mut value as *mut u64 as *mut c_void,
+            )
+        })
+    }
+
+    /// Gets the amount of reserved memory in bytes the pool holds onto before releasing it back to the OS when a [`Stream`] syncs.
+    pub fn release_threshold(&self) -> CudaResult<u64> {
+        self.get_attribute_u64(sys::CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_RELEASE_THRESHOLD)
+    }
+
+    /// Sets the amount of reserved memory in bytes the pool holds onto before releasing it back to the OS when a [`Stream`] syncs.
+    /// Defaults to 0, use [`u64::MAX`] to never release memory.
+    pub fn set_release_threshold(&mut self, threshold: u64) -> CudaResult<()> {
+        self.set_attribute_u64(
+            sys::CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_RELEASE_THRESHOLD,
+            threshold,
+        )
+    }
+
+    /// Gets the amount of backing memory in bytes currently reserved by the pool
+    pub fn reserved_memory(&self) -> CudaResult<u64> {
+        self.get_attribute_u64(sys::CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_RESERVED_MEM_CURRENT)
+    }
+
+    /// Gets the amount of memory in bytes from the pool that is currently in use by allocations
+    pub fn used_memory(&self) -> CudaResult<u64> {
+        self.get_attribute_u64(sys::CUmemPool_attribute_enum_CU_MEMPOOL_ATTR_USED_MEM_CURRENT)
+    }
+
+    /// Releases reserved memory back to the OS until the pool holds fewer than `min_bytes_to_keep` reserved bytes, or there is no more unused memory to release.
+    pub fn trim_to(&mut self, min_bytes_to_keep: u64) -> CudaResult<()> {
+        cuda_error(unsafe {
+            backend::cuMemPoolTrimTo(self.inner, min_bytes_to_keep as sys::size_t)
+        })
+    }
+}
+
+impl Drop for MemPool {
+    fn drop(&mut self) {
+        if !self.owned {
+            return;
+        }
+        if let Err(e) = cuda_error(unsafe { backend::cuMemPoolDestroy(self.inner) }) {
+            eprintln!("CUDA: failed to destroy memory pool: {:?}", e);
+        }
+    }
+}
+
+impl Device {
+    /// Gets the default [`MemPool`] of the device
+    pub fn default_mem_pool(&self) -> CudaResult<MemPool> {
+        let mut inner = null_mut();
+        cuda_error(unsafe {
+            backend::cuDeviceGetDefaultMemPool(&mut inner as *mut _, self.handle)
+        })?;
+        Ok(MemPool {
+            inner,
+            owned: false,
+        })
+    }
+
+    /// Gets the current [`MemPool`] of the device, used by [`DeviceBox::alloc_async`]
+    pub fn mem_pool(&self) -> CudaResult<MemPool> {
+        let mut inner = null_mut();
+        cuda_error(unsafe { backend::cuDeviceGetMemPool(&mut inner as *mut _, self.handle) })?;
+        Ok(MemPool {
+            inner,
+            owned: false,
+        })
+    }
+
+    /// Sets the current [`MemPool`] of the device, used by [`DeviceBox::alloc_async`].
+    /// If `pool` is dropped while current, the default pool becomes current again.
+    pub fn set_mem_pool(&self, pool: &MemPool) -> CudaResult<()> {
+        cuda_error(unsafe { backend::cuDeviceSetMemPool(self.handle, pool.inner) })
+    }
+}
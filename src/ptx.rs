@@ -0,0 +1,540 @@
+use std::{error::Error, fmt};
+
+use crate::{KernelParameterBuffer, LaunchError};
+
+/// A single parameter of a kernel entry point, as declared in PTX
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KernelParam {
+    /// The name of the parameter, i.e. `matrixMul_param_0`
+    pub name: String,
+    /// The byte offset of the parameter in the kernel's parameter space
+    pub offset: usize,
+    /// The size of the parameter in bytes
+    pub size: usize,
+    /// The alignment of the parameter in bytes
+    pub align: usize,
+    /// The PTX type of the parameter, or of its elements if it is an array. Unknown for parameters read from cubins.
+    pub ty: Option<PtxType>,
+    /// The number of elements if the parameter is an array, i.e. a struct passed by value
+    pub array_len: Option<usize>,
+}
+
+/// A PTX fundamental type, as used by kernel parameters
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PtxType {
+    B8,
+    B16,
+    B32,
+    B64,
+    U8,
+    U16,
+    U32,
+    U64,
+    S8,
+    S16,
+    S32,
+    S64,
+    F16,
+    F16x2,
+    BF16,
+    BF16x2,
+    F32,
+    F64,
+}
+
+impl PtxType {
+    /// Parses a type directive, i.e. `.u32`
+    pub fn from_directive(directive: &str) -> Option<Self> {
+        Some(match directive {
+            ".b8" => PtxType::B8,
+            ".b16" => PtxType::B16,
+            ".b32" => PtxType::B32,
+            ".b64" => PtxType::B64,
+            ".u8" => PtxType::U8,
+            ".u16" => PtxType::U16,
+            ".u32" => PtxType::U32,
+            ".u64" => PtxType::U64,
+            ".s8" => PtxType::S8,
+            ".s16" => PtxType::S16,
+            ".s32" => PtxType::S32,
+            ".s64" => PtxType::S64,
+            ".f16" => PtxType::F16,
+            ".f16x2" => PtxType::F16x2,
+            ".bf16" => PtxType::BF16,
+            ".bf16x2" => PtxType::BF16x2,
+            ".f32" => PtxType::F32,
+            ".f64" => PtxType::F64,
+            _ => return None,
+        })
+    }
+
+    /// Gets the size of the type in bytes
+    pub fn size(self) -> usize {
+        match self {
+            PtxType::B8 | PtxType::U8 | PtxType::S8 => 1,
+            PtxType::B16 | PtxType::U16 | PtxType::S16 | PtxType::F16 | PtxType::BF16 => 2,
+            PtxType::B32
+            | PtxType::U32
+            | PtxType::S32
+            | PtxType::F16x2
+            | PtxType::BF16x2
+            | PtxType::F32 => 4,
+            PtxType::B64 | PtxType::U64 | PtxType::S64 | PtxType::F64 => 8,
+        }
+    }
+}
+
+/// The parameter list of a kernel entry point, see [`Function::signature`](crate::Function::signature)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KernelSignature {
+    /// The name of the kernel, i.e. `matrixMul`
+    pub name: String,
+    /// The parameters of the kernel, in order
+    pub params: Vec<KernelParam>,
+}
+
+impl KernelSignature {
+    /// Checks that `params` matches this signature in parameter count, and in the size and offset of each parameter.
+    /// Offsets can only differ when a parameter is packed at a different alignment than the kernel declares.
+    pub fn check(&self, params: &KernelParameterBuffer) -> Result<(), LaunchError> {
+        if params.count() != self.params.len() {
+            return Err(LaunchError::ParameterCount {
+                expected: self.params.len(),
+                found: params.count(),
+            });
+        }
+        for (index, param) in self.params.iter().enumerate() {
+            if params.sizes()[index] != param.size {
+                return Err(LaunchError::ParameterSize {
+                    index,
+                    expected: param.size,
+                    found: params.sizes()[index],
+                });
+            }
+            if params.offsets()[index] != param.offset {
+                return Err(LaunchError::ParameterAlignment {
+                    index,
+                    expected: param.align,
+                    offset: params.offsets()[index],
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses the `.entry` declarations of a PTX module. Only the declarations are parsed, kernel bodies are skipped.
+    pub fn parse_ptx(ptx: &str) -> Result<Vec<KernelSignature>, PtxParseError> {
+        let tokens = tokenize(ptx)?;
+        let mut tokens = tokens.iter().peekable();
+        let mut out = vec![];
+        while let Some(token) = tokens.next() {
+            if *token != ".entry" {
+                continue;
+            }
+            let name = match tokens.next() {
+                Some(name) if is_identifier(name) => name.to_string(),
+                _ => return Err(PtxParseError::new("expected kernel name after .entry")),
+            };
+            let mut params: Vec<KernelParam> = vec![];
+            if tokens.peek().map(|x| *x == "(").unwrap_or(false) {
+                tokens.next();
+                loop {
+                    match tokens.next().map(|x| &**x) {
+                        Some(")") => break,
+                        Some(",") => continue,
+                        Some(".param") => {
+                            // offsets of earlier parameters are checked not to overflow
+                            let offset = params.last().map(|x| x.offset + x.size).unwrap_or(0);
+                            params.push(parse_param(&name, offset, &mut tokens)?)
+                        }
+                        Some(other) => {
+                            return Err(PtxParseError::new(format!(
+                                "unexpected token '{}' in parameters of kernel '{}'",
+                                other, name
+                            )))
+                        }
+                        None => {
+                            return Err(PtxParseError::new(format!(
+                                "unterminated parameter list of kernel '{}'",
+                                name
+                            )))
+                        }
+                    }
+                }
+            }
+            out.push(KernelSignature { name, params });
+        }
+        Ok(out)
+    }
+}
+
+/// Parses a single `.param` declaration, placing it at the next multiple of its alignment from `offset`
+fn parse_param<'a, I: Iterator<Item = &'a String>>(
+    kernel: &str,
+    offset: usize,
+    tokens: &mut std::iter::Peekable<I>,
+) -> Result<KernelParam, PtxParseError> {
+    let mut ty = None;
+    let mut align = None;
+    let mut is_ptr = false;
+    loop {
+        let token = match tokens.next() {
+            Some(token) => token,
+            None => {
+                return Err(PtxParseError::new(format!(
+                    "unterminated parameter of kernel '{}'",
+                    kernel
+                )))
+            }
+        };
+        if token == ".align" {
+            let value = match tokens.next().and_then(|x| x.parse::<usize>().ok()) {
+                Some(x) if x.is_power_of_two() => x,
+                _ => {
+                    return Err(PtxParseError::new(format!(
+                        "invalid .align in parameters of kernel '{}'",
+                        kernel
+                    )))
+                }
+            };
+            // after `.ptr`, `.align` is the alignment of the pointee rather than the parameter
+            if !is_ptr {
+                align = Some(value);
+            }
+        } else if token == ".ptr" {
+            is_ptr = true;
+        } else if let Some(x) = PtxType::from_directive(token) {
+            ty = Some(x);
+        } else if token.starts_with('.') {
+            // state spaces of pointer parameters do not affect layout
+        } else if is_identifier(token) {
+            let ty = ty.ok_or_else(|| {
+                PtxParseError::new(format!(
+                    "missing type of parameter '{}' of kernel '{}'",
+                    token, kernel
+                ))
+            })?;
+            let mut array_len = None;
+            if tokens.peek().map(|x| *x == "[").unwrap_or(false) {
+                tokens.next();
+                array_len = Some(
+                    tokens
+                        .next()
+                        .and_then(|x| x.parse::<usize>().ok())
+                        .ok_or_else(|| {
+                            PtxParseError::new(format!(
+                                "invalid array length of parameter '{}' of kernel '{}'",
+                                token, kernel
+                            ))
+                        })?,
+                );
+                if tokens.next().map(|x| &**x) != Some("]") {
+                    return Err(PtxParseError::new(format!(
+                        "unterminated array length of parameter '{}' of kernel '{}'",
+                        token, kernel
+                    )));
+                }
+            }
+            let align = align.unwrap_or(ty.size());
+            let size = ty.size().checked_mul(array_len.unwrap_or(1));
+            let offset = offset.checked_next_multiple_of(align);
+            let (size, offset) = match (size, offset) {
+                (Some(size), Some(offset)) if offset.checked_add(size).is_some() => (size, offset),
+                _ => {
+                    return Err(PtxParseError::new(format!(
+                        "parameter '{}' of kernel '{}' is too large",
+                        token, kernel
+                    )))
+                }
+            };
+            return Ok(KernelParam {
+                name: token.to_string(),
+                offset,
+                size,
+                align,
+                ty: Some(ty),
+                array_len,
+            });
+        } else {
+            return Err(PtxParseError::new(format!(
+                "unexpected token '{}' in parameters of kernel '{}'",
+                token, kernel
+            )));
+        }
+    }
+}
+
+pub(crate) fn is_identifier(token: &str) -> bool {
+    let mut chars = token.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' || c == '%' => (),
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Splits PTX source into tokens, dropping comments and string literals
+pub(crate) fn tokenize(ptx: &str) -> Result<Vec<String>, PtxParseError> {
+    let mut out = vec![];
+    let mut chars = ptx.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut last = ' ';
+                loop {
+                    match chars.next() {
+                        Some('/') if last == '*' => break,
+                        Some(c) => last = c,
+                        None => return Err(PtxParseError::new("unterminated block comment")),
+                    }
+                }
+            }
+            '"' => loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => {
+                        chars.next();
+                    }
+                    Some(_) => (),
+                    None => return Err(PtxParseError::new("unterminated string literal")),
+                }
+            },
+            c if c.is_whitespace() => (),
+            c if c.is_ascii_alphanumeric() || c == '.' || c == '_' || c == '$' || c == '%' => {
+                let mut token = c.to_string();
+                while let Some(c) = chars.peek() {
+                    if c.is_ascii_alphanumeric() || *c == '_' || *c == '$' || *c == '.' {
+                        token.push(*c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                out.push(token);
+            }
+            c => out.push(c.to_string()),
+        }
+    }
+    Ok(out)
+}
+
+/// An error from [`KernelSignature::parse_ptx`]
+#[derive(Clone, Debug)]
+pub struct PtxParseError {
+    message: String,
+}
+
+impl PtxParseError {
+    pub(crate) fn new(message: impl Into<String>) -> Self {
+        PtxParseError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for PtxParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse PTX: {}", self.message)
+    }
+}
+
+impl Error for PtxParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(ptx: &str) -> Vec<KernelSignature> {
+        KernelSignature::parse_ptx(ptx).unwrap()
+    }
+
+    fn parse_err(ptx: &str) -> String {
+        KernelSignature::parse_ptx(ptx).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn scalar_params() {
+        let kernels = parse(
+            ".visible .entry add(
+                .param .u64 add_param_0,
+                .param .u32 add_param_1,
+                .param .f64 add_param_2
+            )
+            {
+                ret;
+            }",
+        );
+        assert_eq!(kernels.len(), 1);
+        assert_eq!(kernels[0].name, "add");
+        let params = &kernels[0].params;
+        assert_eq!(
+            params
+                .iter()
+                .map(|x| (&*x.name, x.offset, x.size, x.align, x.ty))
+                .collect::<Vec<_>>(),
+            vec![
+                ("add_param_0", 0, 8, 8, Some(PtxType::U64)),
+                ("add_param_1", 8, 4, 4, Some(PtxType::U32)),
+                ("add_param_2", 16, 8, 8, Some(PtxType::F64)),
+            ]
+        );
+        assert!(params.iter().all(|x| x.array_len.is_none()));
+    }
+
+    #[test]
+    fn align_and_array_params() {
+        let kernels = parse(
+            ".entry by_value(.param .u8 k_param_0, .param .align 16 .b8 k_param_1[24], .param .b32 k_param_2[3])
+            {
+                ret;
+            }",
+        );
+        let params = &kernels[0].params;
+        assert_eq!(params[1].offset, 16);
+        assert_eq!(params[1].size, 24);
+        assert_eq!(params[1].align, 16);
+        assert_eq!(params[1].ty, Some(PtxType::B8));
+        assert_eq!(params[1].array_len, Some(24));
+        assert_eq!(params[2].offset, 40);
+        assert_eq!(params[2].size, 12);
+        assert_eq!(params[2].align, 4);
+        assert_eq!(params[2].array_len, Some(3));
+    }
+
+    #[test]
+    fn ptr_params() {
+        let kernels = parse(
+            ".entry ptrs(
+                .param .u32 ptrs_param_0,
+                .param .u64 .ptr .global .align 4 ptrs_param_1,
+                .param .u64 .ptr .align 16 ptrs_param_2
+            )
+            {
+                ret;
+            }",
+        );
+        let params = &kernels[0].params;
+        // the alignment after `.ptr` is of the pointee, so the parameters keep the alignment of `.u64`
+        assert_eq!(
+            (params[1].offset, params[1].size, params[1].align),
+            (8, 8, 8)
+        );
+        assert_eq!(
+            (params[2].offset, params[2].size, params[2].align),
+            (16, 8, 8)
+        );
+        assert_eq!(params[1].ty, Some(PtxType::U64));
+    }
+
+    #[test]
+    fn multiple_entries() {
+        let kernels = parse(
+            "// a comment mentioning .entry fake(.param .u8 x)
+            .version 7.0
+            .target sm_80
+            .address_size 64
+
+            .func (.param .b32 func_retval0) helper(.param .b32 helper_param_0)
+            {
+                ret;
+            }
+
+            /* .entry also_fake() */
+            .visible .entry first()
+            {
+                call.uni (retval0), helper, (param0);
+                ret;
+            }
+
+            .visible .entry second(.param .s32 second_param_0)
+            {
+                ret;
+            }",
+        );
+        assert_eq!(
+            kernels.iter().map(|x| &*x.name).collect::<Vec<_>>(),
+            vec!["first", "second"]
+        );
+        assert!(kernels[0].params.is_empty());
+        assert_eq!(kernels[1].params[0].ty, Some(PtxType::S32));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(parse_err(".entry").contains("expected kernel name"));
+        assert!(parse_err(".entry k(.param .u32 k_param_0").contains("unterminated"));
+        assert!(parse_err(".entry k(.param .u32").contains("unterminated parameter"));
+        assert!(parse_err(".entry k(.param k_param_0)").contains("missing type"));
+        assert!(parse_err(".entry k(.param .align 3 .b8 k_param_0[4])").contains("invalid .align"));
+        assert!(parse_err(".entry k(.param .align").contains("invalid .align"));
+        assert!(parse_err(".entry k(.param .b8 k_param_0[x])").contains("invalid array length"));
+        assert!(parse_err(".entry k(.param .b8 k_param_0[4)").contains("unterminated array"));
+        assert!(parse_err(".entry k(.param .u32 k_param_0; )").contains("unexpected token"));
+        assert!(parse_err(".entry k() { /* ").contains("unterminated block comment"));
+        assert!(parse_err(".entry k() { \"").contains("unterminated string"));
+        assert!(
+            parse_err(&format!(".entry k(.param .u64 k_param_0[{}])", usize::MAX))
+                .contains("too large")
+        );
+        assert!(parse_err(&format!(
+            ".entry k(.param .b8 k_param_0[{}], .param .u64 k_param_1)",
+            usize::MAX
+        ))
+        .contains("too large"));
+    }
+
+    #[test]
+    fn check() {
+        let kernels = parse(".entry k(.param .u64 k_param_0, .param .u32 k_param_1)");
+        let signature = &kernels[0];
+
+        let mut params = KernelParameterBuffer::new();
+        params.push(&0u64);
+        params.push(&0u32);
+        assert!(signature.check(&params).is_ok());
+
+        params.clear();
+        params.push(&0u64);
+        assert!(matches!(
+            signature.check(&params),
+            Err(LaunchError::ParameterCount {
+                expected: 2,
+                found: 1
+            })
+        ));
+
+        params.clear();
+        params.push(&0u64);
+        params.push(&0u64);
+        assert!(matches!(
+            signature.check(&params),
+            Err(LaunchError::ParameterSize {
+                index: 1,
+                expected: 4,
+                found: 8
+            })
+        ));
+
+        // parameters packed at a lower alignment than the kernel declares land at the wrong offset
+        let kernels = parse(".entry k(.param .u32 k_param_0, .param .u64 k_param_1)");
+        params.clear();
+        params.push(&0u32);
+        params.push_bytes(&[0; 8], 1);
+        assert!(matches!(
+            kernels[0].check(&params),
+            Err(LaunchError::ParameterAlignment {
+                index: 1,
+                expected: 8,
+                offset: 4
+            })
+        ));
+    }
+}
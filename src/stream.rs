@@ -1,15 +1,47 @@
 use num_enum::TryFromPrimitive;
-use std::{ffi::c_void, marker::PhantomData, pin::Pin, ptr::null_mut, rc::Rc};
+use std::{
+    any::Any,
+    error::Error,
+    ffi::c_void,
+    fmt,
+    ops::{Deref, DerefMut},
+    ptr::null_mut,
+    rc::Rc,
+    sync::Arc,
+};
 
 use crate::*;
 
+/// Owns a raw `libcuda` stream, destroying it once the last reference is dropped.
+/// Shared with stream-ordered allocations that are freed on their allocating stream.
+pub(crate) struct RawStream<'a>(pub(crate) *mut sys::CUstream_st, Arc<Handle<'a>>);
+
+// libcuda streams can be used from any thread
+unsafe impl<'a> Send for RawStream<'a> {}
+unsafe impl<'a> Sync for RawStream<'a> {}
+
+impl<'a> Drop for RawStream<'a> {
+    fn drop(&mut self) {
+        let _current = self.1.current();
+        if let Err(e) = cuda_error(unsafe { backend::cuStreamDestroy_v2(self.0) }) {
+            eprintln!("CUDA: failed to drop stream: {:?}", e);
+        }
+    }
+}
+
 /// A stream of asynchronous operations operating in a [`Context`]
 pub struct Stream<'a> {
     pub(crate) inner: *mut sys::CUstream_st,
-    pub(crate) pending_stores: Vec<Pin<Box<[u8]>>>,
-    _p: PhantomData<&'a ()>,
+    pub(crate) raw: Arc<RawStream<'a>>,
+    pub(crate) pending_stores: Vec<Box<dyn Any + Send>>,
+    pub(crate) handle: Arc<Handle<'a>>,
+    pub(crate) capturing: bool,
+    params: KernelParameterBuffer,
 }
 
+// a stream can be moved to another thread, where operations make its context current
+unsafe impl<'a> Send for Stream<'a> {}
+
 /// Wait comparison type for waiting on some condition in [`Stream::wait_32`]/etc
 #[derive(Debug, Copy, Clone, TryFromPrimitive)]
 #[repr(u32)]
@@ -31,31 +63,40 @@ unsafe extern "C" fn host_callback(arg: *mut std::ffi::c_void) {
 
 impl<'a> Stream<'a> {
     /// Creates a new stream for a handle
-    pub fn new(_handle: &Rc<Handle<'a>>) -> CudaResult<Self> {
+    pub fn new(handle: &Arc<Handle<'a>>) -> CudaResult<Self> {
+        let _current = handle.current()?;
         let mut out = null_mut();
         cuda_error(unsafe {
-            sys::cuStreamCreate(
+            backend::cuStreamCreate(
                 &mut out as *mut _,
                 sys::CUstream_flags_enum_CU_STREAM_NON_BLOCKING,
             )
         })?;
         Ok(Self {
             inner: out,
+            raw: Arc::new(RawStream(out, handle.clone())),
             pending_stores: vec![],
-            _p: PhantomData,
+            handle: handle.clone(),
+            capturing: false,
+            params: KernelParameterBuffer::new(),
         })
     }
 
     /// Drives all pending tasks on the stream to completion
     pub fn sync(&mut self) -> CudaResult<()> {
-        cuda_error(unsafe { sys::cuStreamSynchronize(self.inner) })?;
+        if self.capturing {
+            return Err(ErrorCode::StreamCaptureUnsupported);
+        }
+        let _current = self.handle.current()?;
+        cuda_error(unsafe { backend::cuStreamSynchronize(self.inner) })?;
         self.pending_stores.clear();
         Ok(())
     }
 
     /// Returns `Ok(true)` if the stream has finished processing all queued tasks.
     pub fn is_synced(&self) -> CudaResult<bool> {
-        match cuda_error(unsafe { sys::cuStreamQuery(self.inner) }) {
+        let _current = self.handle.current()?;
+        match cuda_error(unsafe { backend::cuStreamQuery(self.inner) }) {
             Ok(()) => Ok(true),
             Err(ErrorCode::NotReady) => Ok(false),
             Err(e) => Err(e),
@@ -74,8 +115,9 @@ impl<'a> Stream<'a> {
             panic!("overflow in Stream::wait_32");
         }
         let flush = if flush { 1u32 << 30 } else { 0 };
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuStreamWaitValue32(self.inner, addr.inner, value, mode as u32 | flush)
+            backend::cuStreamWaitValue32(self.inner, addr.inner, value, mode as u32 | flush)
         })
     }
 
@@ -91,8 +133,9 @@ impl<'a> Stream<'a> {
             panic!("overflow in Stream::wait_64");
         }
         let flush = if flush { 1u32 << 30 } else { 0 };
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuStreamWaitValue64(self.inner, addr.inner, value, mode as u32 | flush)
+            backend::cuStreamWaitValue64(self.inner, addr.inner, value, mode as u32 | flush)
         })
     }
 
@@ -107,8 +150,9 @@ impl<'a> Stream<'a> {
             panic!("overflow in Stream::write_32");
         }
         let no_memory_barrier = if no_memory_barrier { 1u32 } else { 0 };
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuStreamWriteValue32(self.inner, addr.inner, value, no_memory_barrier)
+            backend::cuStreamWriteValue32(self.inner, addr.inner, value, no_memory_barrier)
         })
     }
 
@@ -123,8 +167,44 @@ impl<'a> Stream<'a> {
             panic!("overflow in Stream::write_64");
         }
         let no_memory_barrier = if no_memory_barrier { 1u32 } else { 0 };
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuStreamWriteValue64(self.inner, addr.inner, value, no_memory_barrier)
+        })
+    }
+
+    /// Records `event` at the current end of this [`Stream`]. The event completes once all prior tasks in the stream have completed.
+    /// Recording an already-recorded event moves it to the new point.
+    pub fn record(&mut self, event: &Event<'a>) -> CudaResult<()> {
+        let _current = self.handle.current()?;
+        cuda_error(unsafe { backend::cuEventRecord(event.inner, self.inner) })
+    }
+
+    /// Makes all future tasks in this [`Stream`] wait until `event` has completed. `event` may have been recorded on a different [`Stream`].
+    /// This does not block the calling thread.
+    pub fn wait_event(&mut self, event: &Event<'a>) -> CudaResult<()> {
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuStreamWriteValue64(self.inner, addr.inner, value, no_memory_barrier)
+            backend::cuStreamWaitEvent(
+                self.inner,
+                event.inner,
+                sys::CUevent_wait_flags_enum_CU_EVENT_WAIT_DEFAULT,
+            )
+        })
+    }
+
+    /// Asynchronously changes which streams may access `mem`. With [`MemAttach::Single`], the host may access `mem` while kernels on other streams run, once prior work on this stream completes.
+    pub fn attach_mem<T: DeviceCopy>(
+        &mut self,
+        mem: &ManagedBox<'a, T>,
+        attach: MemAttach,
+    ) -> CudaResult<()> {
+        if mem.byte_len() == 0 {
+            return Ok(());
+        }
+        let _current = self.handle.current()?;
+        cuda_error(unsafe {
+            backend::cuStreamAttachMemAsync(self.inner, mem.inner.as_ptr() as u64, 0, attach as u32)
         })
     }
 
@@ -133,10 +213,15 @@ impl<'a> Stream<'a> {
     /// The callback is not guaranteed to be called if the stream errors out.
     /// Also note that it is erroneous in `libcuda` to make any calls to `libcuda` from this callback.
     /// The callback is called from a CUDA internal thread, however this is an implementation detail of `libcuda` and not guaranteed.
+    /// Returns [`ErrorCode::StreamCaptureUnsupported`] while capturing, as a captured callback may run more than once. Use [`Graph::add_host`] instead.
     pub fn callback<F: FnOnce() + Send + Sync>(&mut self, callback: F) -> CudaResult<()> {
+        if self.capturing {
+            return Err(ErrorCode::StreamCaptureUnsupported);
+        }
         let callback: Box<Box<dyn FnOnce()>> = Box::new(Box::new(callback));
+        let _current = self.handle.current()?;
         cuda_error(unsafe {
-            sys::cuLaunchHostFunc(
+            backend::cuLaunchHostFunc(
                 self.inner,
                 Some(host_callback),
                 Box::leak(callback) as *mut _ as *mut _,
@@ -144,9 +229,15 @@ impl<'a> Stream<'a> {
         })
     }
 
+    /// Gets a [`CudaFuture`] completing once all work currently queued on this [`Stream`] completes.
+    /// Returns [`ErrorCode::StreamCaptureUnsupported`] while capturing.
+    pub fn future(&mut self) -> CudaResult<CudaFuture<'a, ()>> {
+        CudaFuture::new(self, ())
+    }
+
     /// Launch a CUDA kernel on this [`Stream`] with the given `grid_dim` grid dimensions, `block_dim` block dimensions, `shared_mem_size` allocated shared memory pool, and `parameters` kernel parameters.
     /// It is undefined behavior to pass in `parameters` that do not conform to the passes CUDA kernel. If the argument count is wrong, CUDA will generally throw an error.
-    /// If your `parameters` is accurate to the kernel definition, then this function is otherwise safe.
+    /// If your `parameters` is accurate to the kernel definition, then this function is otherwise safe. See [`Stream::launch_checked`] for a checked alternative.
     pub unsafe fn launch<'b, D1: Into<Dim3>, D2: Into<Dim3>, K: KernelParameters>(
         &mut self,
         f: &Function<'a, 'b>,
@@ -154,16 +245,83 @@ impl<'a> Stream<'a> {
         block_dim: D2,
         shared_mem_size: u32,
         parameters: K,
+    ) -> CudaResult<()> {
+        self.params.clear();
+        parameters.params(&mut self.params);
+        self.launch_params(f, grid_dim.into(), block_dim.into(), shared_mem_size)
+    }
+
+    /// Like [`Stream::launch`], but first checks `parameters` against the signature of `f`, see [`Function::signature`].
+    /// Returns an error instead of launching if the parameter count, or the size or alignment of any parameter, does not match the kernel.
+    /// Returns [`LaunchError::UnknownSignature`] if `f` has no known signature.
+    /// Only the layout of `parameters` is checked: a PTX `.u64` parameter does not tell device pointers from integers, so any `u64` is accepted in place of a pointer.
+    /// It is undefined behavior to pass in values the kernel uses as pointers which do not point to device memory it can access.
+    pub unsafe fn launch_checked<'b, D1: Into<Dim3>, D2: Into<Dim3>, K: KernelParameters>(
+        &mut self,
+        f: &Function<'a, 'b>,
+        grid_dim: D1,
+        block_dim: D2,
+        shared_mem_size: u32,
+        parameters: K,
+    ) -> Result<(), LaunchError> {
+        let signature = f.signature().ok_or(LaunchError::UnknownSignature)?;
+        self.params.clear();
+        parameters.params(&mut self.params);
+        signature.check(&self.params)?;
+        self.launch_params(f, grid_dim.into(), block_dim.into(), shared_mem_size)?;
+        Ok(())
+    }
+
+    /// Launches `f` with the parameters currently in `self.params`
+    unsafe fn launch_params(
+        &mut self,
+        f: &Function<'a, '_>,
+        grid_dim: Dim3,
+        block_dim: Dim3,
+        shared_mem_size: u32,
+    ) -> CudaResult<()> {
+        let grid_dim = grid_dim.0;
+        let block_dim = block_dim.0;
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuLaunchKernel(
+            f.inner,
+            grid_dim.0,
+            grid_dim.1,
+            grid_dim.2,
+            block_dim.0,
+            block_dim.1,
+            block_dim.2,
+            shared_mem_size,
+            self.inner,
+            self.params.pointers(),
+            null_mut(),
+        ))
+    }
+
+    /// Like [`Stream::launch`], but passes `parameters` to `libcuda` as a single packed buffer (`CU_LAUNCH_PARAM_BUFFER_POINTER`) instead of an array of pointers.
+    /// Each parameter must be at the offset the kernel expects, which holds for parameters at their natural alignment. Note that `&[u8]` and `Vec<u8>` parameters are packed unaligned.
+    pub unsafe fn launch_packed<'b, D1: Into<Dim3>, D2: Into<Dim3>, K: KernelParameters>(
+        &mut self,
+        f: &Function<'a, 'b>,
+        grid_dim: D1,
+        block_dim: D2,
+        shared_mem_size: u32,
+        parameters: K,
     ) -> CudaResult<()> {
         let grid_dim = grid_dim.into().0;
         let block_dim = block_dim.into().0;
-        let mut kernel_params = vec![];
-        parameters.params(&mut kernel_params);
-        let mut new_kernel_params = Vec::with_capacity(kernel_params.len());
-        for param in &kernel_params {
-            new_kernel_params.push(param.as_ptr() as *mut c_void);
-        }
-        cuda_error(sys::cuLaunchKernel(
+        self.params.clear();
+        parameters.params(&mut self.params);
+        let mut size = self.params.len() as sys::size_t;
+        let mut extra = [
+            CU_LAUNCH_PARAM_BUFFER_POINTER,
+            self.params.as_mut_ptr() as *mut c_void,
+            CU_LAUNCH_PARAM_BUFFER_SIZE,
+            &mut size as *mut sys::size_t as *mut c_void,
+            CU_LAUNCH_PARAM_END,
+        ];
+        let _current = self.handle.current()?;
+        cuda_error(backend::cuLaunchKernel(
             f.inner,
             grid_dim.0,
             grid_dim.1,
@@ -173,16 +331,205 @@ impl<'a> Stream<'a> {
             block_dim.2,
             shared_mem_size,
             self.inner,
-            new_kernel_params.as_mut_ptr(),
             null_mut(),
+            extra.as_mut_ptr(),
         ))
     }
+
+    /// Gets the capture status of this [`Stream`]
+    pub fn capture_status(&self) -> CudaResult<StreamCaptureStatus> {
+        let _current = self.handle.current()?;
+        let mut out = 0u32;
+        cuda_error(unsafe { backend::cuStreamIsCapturing(self.inner, &mut out as *mut u32) })?;
+        Ok(
+            StreamCaptureStatus::try_from_primitive(out)
+                .unwrap_or(StreamCaptureStatus::Invalidated),
+        )
+    }
+
+    /// Begins capturing all work queued on this [`Stream`] into a [`Graph`] instead of executing it.
+    /// Capture ends with [`StreamCapture::end_capture`], or is discarded when the [`StreamCapture`] is dropped.
+    /// Host data borrowed by captured operations (i.e. [`DevicePtr::store_stream`]) must outlive every launch of the resulting [`Graph`].
+    /// Data given to [`DevicePtr::store_stream_buf`] is kept alive by the resulting [`Graph`].
+    pub fn begin_capture<'b>(
+        &'b mut self,
+        mode: StreamCaptureMode,
+    ) -> CudaResult<StreamCapture<'b, 'a>> {
+        let _current = self.handle.current()?;
+        cuda_error(unsafe { backend::cuStreamBeginCapture_v2(self.inner, mode as u32) })?;
+        self.capturing = true;
+        Ok(StreamCapture {
+            pending_stores_start: self.pending_stores.len(),
+            stream: self,
+            ended: false,
+        })
+    }
+}
+
+/// An error from [`Stream::launch_checked`]
+#[derive(Debug, Copy, Clone)]
+pub enum LaunchError {
+    /// The [`Function`] has no known signature to check against, see [`Module::functions`]
+    UnknownSignature,
+    /// The number of parameters does not match the kernel
+    ParameterCount { expected: usize, found: usize },
+    /// The size in bytes of parameter `index` does not match the kernel
+    ParameterSize {
+        index: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// Parameter `index` was packed at `offset`, which does not match the kernel's `expected` alignment
+    ParameterAlignment {
+        index: usize,
+        expected: usize,
+        offset: usize,
+    },
+    /// Any other error
+    Cuda(ErrorCode),
+}
+
+impl fmt::Display for LaunchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LaunchError::UnknownSignature => write!(f, "kernel signature is unknown"),
+            LaunchError::ParameterCount { expected, found } => {
+                write!(f, "kernel expects {} parameters, found {}", expected, found)
+            }
+            LaunchError::ParameterSize {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "kernel parameter {} is {} bytes, found {} bytes",
+                index, expected, found
+            ),
+            LaunchError::ParameterAlignment {
+                index,
+                expected,
+                offset,
+            } => write!(
+                f,
+                "kernel parameter {} is {} byte aligned, found at offset {}",
+                index, expected, offset
+            ),
+            LaunchError::Cuda(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for LaunchError {}
+
+impl From<ErrorCode> for LaunchError {
+    fn from(e: ErrorCode) -> Self {
+        LaunchError::Cuda(e)
+    }
+}
+
+/// The mode of a stream capture, controlling which potentially unsafe `libcuda` calls are prohibited while capturing
+#[derive(Debug, Copy, Clone, TryFromPrimitive)]
+#[repr(u32)]
+pub enum StreamCaptureMode {
+    /// Prohibit unsafe calls on any thread while any thread has a global or thread local capture ongoing
+    Global = 0x0,
+    /// Prohibit unsafe calls on this thread while this thread has a capture ongoing
+    ThreadLocal = 0x1,
+    /// Do not prohibit any calls. Unsafe calls made during capture may fail or corrupt the capture.
+    Relaxed = 0x2,
+}
+
+/// The capture status of a [`Stream`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u32)]
+pub enum StreamCaptureStatus {
+    /// Stream is not capturing
+    None = 0x0,
+    /// Stream is actively capturing
+    Active = 0x1,
+    /// Stream is part of a capture sequence that has been invalidated, but not terminated
+    Invalidated = 0x2,
+}
+
+/// An ongoing capture of a [`Stream`] into a [`Graph`], see [`Stream::begin_capture`].
+/// Dereferences to the capturing [`Stream`], so work can be queued as usual.
+/// If dropped without calling [`StreamCapture::end_capture`], the capture is ended and discarded.
+pub struct StreamCapture<'b, 'a> {
+    stream: &'b mut Stream<'a>,
+    pending_stores_start: usize,
+    ended: bool,
+}
+
+impl<'b, 'a> StreamCapture<'b, 'a> {
+    fn end(&mut self) -> CudaResult<*mut sys::CUgraph_st> {
+        let _current = self.stream.handle.current()?;
+        self.ended = true;
+        self.stream.capturing = false;
+        let mut graph = null_mut();
+        let out = cuda_error(unsafe { backend::cuStreamEndCapture(self.stream.inner, &mut graph) });
+        if let Err(e) = out {
+            if !graph.is_null() {
+                unsafe { backend::cuGraphDestroy(graph) };
+            }
+            self.stream
+                .pending_stores
+                .truncate(self.pending_stores_start);
+            return Err(e);
+        }
+        Ok(graph)
+    }
+
+    /// Ends the capture, returning a [`Graph`] of all captured work.
+    pub fn end_capture(mut self) -> CudaResult<Graph<'a>> {
+        let inner = self.end()?;
+        let host_buffers = self
+            .stream
+            .pending_stores
+            .split_off(self.pending_stores_start)
+            .into_iter()
+            .map(|x| Rc::from(x as Box<dyn Any>))
+            .collect();
+        Ok(Graph {
+            inner,
+            handle: self.stream.handle.clone(),
+            resources: GraphResources {
+                host_buffers,
+                ..Default::default()
+            },
+        })
+    }
 }
 
-impl<'a> Drop for Stream<'a> {
+impl<'b, 'a> Deref for StreamCapture<'b, 'a> {
+    type Target = Stream<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.stream
+    }
+}
+
+impl<'b, 'a> DerefMut for StreamCapture<'b, 'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.stream
+    }
+}
+
+impl<'b, 'a> Drop for StreamCapture<'b, 'a> {
     fn drop(&mut self) {
-        if let Err(e) = cuda_error(unsafe { sys::cuStreamDestroy_v2(self.inner) }) {
-            eprintln!("CUDA: failed to drop stream: {:?}", e);
+        if self.ended {
+            return;
+        }
+        match self.end() {
+            Ok(graph) => {
+                let _current = self.stream.handle.current();
+                if let Err(e) = cuda_error(unsafe { backend::cuGraphDestroy(graph) }) {
+                    eprintln!("CUDA: failed to destroy discarded capture graph: {:?}", e);
+                }
+                self.stream
+                    .pending_stores
+                    .truncate(self.pending_stores_start);
+            }
+            Err(e) => eprintln!("CUDA: failed to end stream capture: {:?}", e),
         }
     }
 }
@@ -228,7 +228,7 @@ fn bindgen_test_layout_div_t() {
         concat!("Alignment of ", stringify!(div_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<div_t>())).quot as *const _ as usize },
+        ::std::mem::offset_of!(div_t, quot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -238,7 +238,7 @@ fn bindgen_test_layout_div_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<div_t>())).rem as *const _ as usize },
+        ::std::mem::offset_of!(div_t, rem),
         4usize,
         concat!(
             "Offset of field: ",
@@ -267,7 +267,7 @@ fn bindgen_test_layout_ldiv_t() {
         concat!("Alignment of ", stringify!(ldiv_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<ldiv_t>())).quot as *const _ as usize },
+        ::std::mem::offset_of!(ldiv_t, quot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -277,7 +277,7 @@ fn bindgen_test_layout_ldiv_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<ldiv_t>())).rem as *const _ as usize },
+        ::std::mem::offset_of!(ldiv_t, rem),
         8usize,
         concat!(
             "Offset of field: ",
@@ -306,7 +306,7 @@ fn bindgen_test_layout_lldiv_t() {
         concat!("Alignment of ", stringify!(lldiv_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<lldiv_t>())).quot as *const _ as usize },
+        ::std::mem::offset_of!(lldiv_t, quot),
         0usize,
         concat!(
             "Offset of field: ",
@@ -316,7 +316,7 @@ fn bindgen_test_layout_lldiv_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<lldiv_t>())).rem as *const _ as usize },
+        ::std::mem::offset_of!(lldiv_t, rem),
         8usize,
         concat!(
             "Offset of field: ",
@@ -459,7 +459,7 @@ fn bindgen_test_layout___fsid_t() {
         concat!("Alignment of ", stringify!(__fsid_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__fsid_t>())).__val as *const _ as usize },
+        ::std::mem::offset_of!(__fsid_t, __val),
         0usize,
         concat!(
             "Offset of field: ",
@@ -548,7 +548,7 @@ fn bindgen_test_layout___sigset_t() {
         concat!("Alignment of ", stringify!(__sigset_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__sigset_t>())).__val as *const _ as usize },
+        ::std::mem::offset_of!(__sigset_t, __val),
         0usize,
         concat!(
             "Offset of field: ",
@@ -578,7 +578,7 @@ fn bindgen_test_layout_timeval() {
         concat!("Alignment of ", stringify!(timeval))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<timeval>())).tv_sec as *const _ as usize },
+        ::std::mem::offset_of!(timeval, tv_sec),
         0usize,
         concat!(
             "Offset of field: ",
@@ -588,7 +588,7 @@ fn bindgen_test_layout_timeval() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<timeval>())).tv_usec as *const _ as usize },
+        ::std::mem::offset_of!(timeval, tv_usec),
         8usize,
         concat!(
             "Offset of field: ",
@@ -617,7 +617,7 @@ fn bindgen_test_layout_timespec() {
         concat!("Alignment of ", stringify!(timespec))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<timespec>())).tv_sec as *const _ as usize },
+        ::std::mem::offset_of!(timespec, tv_sec),
         0usize,
         concat!(
             "Offset of field: ",
@@ -627,7 +627,7 @@ fn bindgen_test_layout_timespec() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<timespec>())).tv_nsec as *const _ as usize },
+        ::std::mem::offset_of!(timespec, tv_nsec),
         8usize,
         concat!(
             "Offset of field: ",
@@ -657,7 +657,7 @@ fn bindgen_test_layout_fd_set() {
         concat!("Alignment of ", stringify!(fd_set))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<fd_set>())).__fds_bits as *const _ as usize },
+        ::std::mem::offset_of!(fd_set, __fds_bits),
         0usize,
         concat!(
             "Offset of field: ",
@@ -710,7 +710,7 @@ fn bindgen_test_layout___pthread_internal_list() {
         concat!("Alignment of ", stringify!(__pthread_internal_list))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_internal_list>())).__prev as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_internal_list, __prev),
         0usize,
         concat!(
             "Offset of field: ",
@@ -720,7 +720,7 @@ fn bindgen_test_layout___pthread_internal_list() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_internal_list>())).__next as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_internal_list, __next),
         8usize,
         concat!(
             "Offset of field: ",
@@ -749,7 +749,7 @@ fn bindgen_test_layout___pthread_internal_slist() {
         concat!("Alignment of ", stringify!(__pthread_internal_slist))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_internal_slist>())).__next as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_internal_slist, __next),
         0usize,
         concat!(
             "Offset of field: ",
@@ -785,7 +785,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
         concat!("Alignment of ", stringify!(__pthread_mutex_s))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__lock as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_mutex_s, __lock),
         0usize,
         concat!(
             "Offset of field: ",
@@ -795,7 +795,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__count as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_mutex_s, __count),
         4usize,
         concat!(
             "Offset of field: ",
@@ -805,7 +805,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__owner as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_mutex_s, __owner),
         8usize,
         concat!(
             "Offset of field: ",
@@ -815,7 +815,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__nusers as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_mutex_s, __nusers),
         12usize,
         concat!(
             "Offset of field: ",
@@ -825,7 +825,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__kind as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_mutex_s, __kind),
         16usize,
         concat!(
             "Offset of field: ",
@@ -835,7 +835,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__spins as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_mutex_s, __spins),
         20usize,
         concat!(
             "Offset of field: ",
@@ -845,7 +845,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__elision as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_mutex_s, __elision),
         22usize,
         concat!(
             "Offset of field: ",
@@ -855,7 +855,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__list as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_mutex_s, __list),
         24usize,
         concat!(
             "Offset of field: ",
@@ -894,9 +894,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         concat!("Alignment of ", stringify!(__pthread_rwlock_arch_t))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__readers as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __readers),
         0usize,
         concat!(
             "Offset of field: ",
@@ -906,9 +904,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__writers as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __writers),
         4usize,
         concat!(
             "Offset of field: ",
@@ -918,9 +914,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__wrphase_futex as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __wrphase_futex),
         8usize,
         concat!(
             "Offset of field: ",
@@ -930,9 +924,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__writers_futex as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __writers_futex),
         12usize,
         concat!(
             "Offset of field: ",
@@ -942,7 +934,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__pad3 as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad3),
         16usize,
         concat!(
             "Offset of field: ",
@@ -952,7 +944,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__pad4 as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad4),
         20usize,
         concat!(
             "Offset of field: ",
@@ -962,9 +954,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__cur_writer as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __cur_writer),
         24usize,
         concat!(
             "Offset of field: ",
@@ -974,9 +964,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__shared as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __shared),
         28usize,
         concat!(
             "Offset of field: ",
@@ -986,9 +974,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__rwelision as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __rwelision),
         32usize,
         concat!(
             "Offset of field: ",
@@ -998,7 +984,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__pad1 as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad1),
         33usize,
         concat!(
             "Offset of field: ",
@@ -1008,7 +994,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__pad2 as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad2),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1018,7 +1004,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__flags as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_rwlock_arch_t, __flags),
         48usize,
         concat!(
             "Offset of field: ",
@@ -1070,10 +1056,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_1__bindgen_ty_1>())).__low
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_1__bindgen_ty_1, __low),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1083,10 +1066,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_1__bindgen_ty_1>())).__high
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_1__bindgen_ty_1, __high),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1109,9 +1089,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(__pthread_cond_s__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_1>())).__wseq as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_1, __wseq),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1121,9 +1099,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_1>())).__wseq32 as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_1, __wseq32),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1164,10 +1140,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_2__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_2__bindgen_ty_1>())).__low
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_2__bindgen_ty_1, __low),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1177,10 +1150,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_2__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_2__bindgen_ty_1>())).__high
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_2__bindgen_ty_1, __high),
         4usize,
         concat!(
             "Offset of field: ",
@@ -1203,10 +1173,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_2() {
         concat!("Alignment of ", stringify!(__pthread_cond_s__bindgen_ty_2))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_2>())).__g1_start as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_2, __g1_start),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1216,10 +1183,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_2>())).__g1_start32 as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_2, __g1_start32),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1242,7 +1206,7 @@ fn bindgen_test_layout___pthread_cond_s() {
         concat!("Alignment of ", stringify!(__pthread_cond_s))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_cond_s>())).__g_refs as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_cond_s, __g_refs),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1252,7 +1216,7 @@ fn bindgen_test_layout___pthread_cond_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_cond_s>())).__g_size as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_cond_s, __g_size),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1262,7 +1226,7 @@ fn bindgen_test_layout___pthread_cond_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_cond_s>())).__g1_orig_size as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_cond_s, __g1_orig_size),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1272,7 +1236,7 @@ fn bindgen_test_layout___pthread_cond_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_cond_s>())).__wrefs as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_cond_s, __wrefs),
         36usize,
         concat!(
             "Offset of field: ",
@@ -1282,7 +1246,7 @@ fn bindgen_test_layout___pthread_cond_s() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__pthread_cond_s>())).__g_signals as *const _ as usize },
+        ::std::mem::offset_of!(__pthread_cond_s, __g_signals),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1312,7 +1276,7 @@ fn bindgen_test_layout___once_flag() {
         concat!("Alignment of ", stringify!(__once_flag))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<__once_flag>())).__data as *const _ as usize },
+        ::std::mem::offset_of!(__once_flag, __data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1342,7 +1306,7 @@ fn bindgen_test_layout_pthread_mutexattr_t() {
         concat!("Alignment of ", stringify!(pthread_mutexattr_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutexattr_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutexattr_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1352,7 +1316,7 @@ fn bindgen_test_layout_pthread_mutexattr_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutexattr_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutexattr_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1381,7 +1345,7 @@ fn bindgen_test_layout_pthread_condattr_t() {
         concat!("Alignment of ", stringify!(pthread_condattr_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_condattr_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_condattr_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1391,7 +1355,7 @@ fn bindgen_test_layout_pthread_condattr_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_condattr_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_condattr_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1422,7 +1386,7 @@ fn bindgen_test_layout_pthread_attr_t() {
         concat!("Alignment of ", stringify!(pthread_attr_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_attr_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_attr_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1432,7 +1396,7 @@ fn bindgen_test_layout_pthread_attr_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_attr_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_attr_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1462,7 +1426,7 @@ fn bindgen_test_layout_pthread_mutex_t() {
         concat!("Alignment of ", stringify!(pthread_mutex_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t>())).__data as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t, __data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1472,7 +1436,7 @@ fn bindgen_test_layout_pthread_mutex_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1482,7 +1446,7 @@ fn bindgen_test_layout_pthread_mutex_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_mutex_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_mutex_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1512,7 +1476,7 @@ fn bindgen_test_layout_pthread_cond_t() {
         concat!("Alignment of ", stringify!(pthread_cond_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t>())).__data as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t, __data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1522,7 +1486,7 @@ fn bindgen_test_layout_pthread_cond_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1532,7 +1496,7 @@ fn bindgen_test_layout_pthread_cond_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_cond_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_cond_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1562,7 +1526,7 @@ fn bindgen_test_layout_pthread_rwlock_t() {
         concat!("Alignment of ", stringify!(pthread_rwlock_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t>())).__data as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t, __data),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1572,7 +1536,7 @@ fn bindgen_test_layout_pthread_rwlock_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1582,7 +1546,7 @@ fn bindgen_test_layout_pthread_rwlock_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlock_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlock_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1611,7 +1575,7 @@ fn bindgen_test_layout_pthread_rwlockattr_t() {
         concat!("Alignment of ", stringify!(pthread_rwlockattr_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlockattr_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlockattr_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1621,7 +1585,7 @@ fn bindgen_test_layout_pthread_rwlockattr_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_rwlockattr_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_rwlockattr_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1651,7 +1615,7 @@ fn bindgen_test_layout_pthread_barrier_t() {
         concat!("Alignment of ", stringify!(pthread_barrier_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_barrier_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_barrier_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1661,7 +1625,7 @@ fn bindgen_test_layout_pthread_barrier_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_barrier_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_barrier_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1690,7 +1654,7 @@ fn bindgen_test_layout_pthread_barrierattr_t() {
         concat!("Alignment of ", stringify!(pthread_barrierattr_t))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_barrierattr_t>())).__size as *const _ as usize },
+        ::std::mem::offset_of!(pthread_barrierattr_t, __size),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1700,7 +1664,7 @@ fn bindgen_test_layout_pthread_barrierattr_t() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<pthread_barrierattr_t>())).__align as *const _ as usize },
+        ::std::mem::offset_of!(pthread_barrierattr_t, __align),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1750,7 +1714,7 @@ fn bindgen_test_layout_random_data() {
         concat!("Alignment of ", stringify!(random_data))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).fptr as *const _ as usize },
+        ::std::mem::offset_of!(random_data, fptr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1760,7 +1724,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).rptr as *const _ as usize },
+        ::std::mem::offset_of!(random_data, rptr),
         8usize,
         concat!(
             "Offset of field: ",
@@ -1770,7 +1734,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).state as *const _ as usize },
+        ::std::mem::offset_of!(random_data, state),
         16usize,
         concat!(
             "Offset of field: ",
@@ -1780,7 +1744,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).rand_type as *const _ as usize },
+        ::std::mem::offset_of!(random_data, rand_type),
         24usize,
         concat!(
             "Offset of field: ",
@@ -1790,7 +1754,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).rand_deg as *const _ as usize },
+        ::std::mem::offset_of!(random_data, rand_deg),
         28usize,
         concat!(
             "Offset of field: ",
@@ -1800,7 +1764,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).rand_sep as *const _ as usize },
+        ::std::mem::offset_of!(random_data, rand_sep),
         32usize,
         concat!(
             "Offset of field: ",
@@ -1810,7 +1774,7 @@ fn bindgen_test_layout_random_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<random_data>())).end_ptr as *const _ as usize },
+        ::std::mem::offset_of!(random_data, end_ptr),
         40usize,
         concat!(
             "Offset of field: ",
@@ -1901,7 +1865,7 @@ fn bindgen_test_layout_drand48_data() {
         concat!("Alignment of ", stringify!(drand48_data))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<drand48_data>())).__x as *const _ as usize },
+        ::std::mem::offset_of!(drand48_data, __x),
         0usize,
         concat!(
             "Offset of field: ",
@@ -1911,7 +1875,7 @@ fn bindgen_test_layout_drand48_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<drand48_data>())).__old_x as *const _ as usize },
+        ::std::mem::offset_of!(drand48_data, __old_x),
         6usize,
         concat!(
             "Offset of field: ",
@@ -1921,7 +1885,7 @@ fn bindgen_test_layout_drand48_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<drand48_data>())).__c as *const _ as usize },
+        ::std::mem::offset_of!(drand48_data, __c),
         12usize,
         concat!(
             "Offset of field: ",
@@ -1931,7 +1895,7 @@ fn bindgen_test_layout_drand48_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<drand48_data>())).__init as *const _ as usize },
+        ::std::mem::offset_of!(drand48_data, __init),
         14usize,
         concat!(
             "Offset of field: ",
@@ -1941,7 +1905,7 @@ fn bindgen_test_layout_drand48_data() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<drand48_data>())).__a as *const _ as usize },
+        ::std::mem::offset_of!(drand48_data, __a),
         16usize,
         concat!(
             "Offset of field: ",
@@ -2439,7 +2403,7 @@ fn bindgen_test_layout_CUuuid_st() {
         concat!("Alignment of ", stringify!(CUuuid_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUuuid_st>())).bytes as *const _ as usize },
+        ::std::mem::offset_of!(CUuuid_st, bytes),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2469,7 +2433,7 @@ fn bindgen_test_layout_CUipcEventHandle_st() {
         concat!("Alignment of ", stringify!(CUipcEventHandle_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUipcEventHandle_st>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(CUipcEventHandle_st, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2501,7 +2465,7 @@ fn bindgen_test_layout_CUipcMemHandle_st() {
         concat!("Alignment of ", stringify!(CUipcMemHandle_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUipcMemHandle_st>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(CUipcMemHandle_st, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2711,12 +2675,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValuePara
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1,
-            >()))
-            .value as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1,
+            value
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2728,12 +2690,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValuePara
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1,
-            >()))
-            .value64 as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1,
+            value64
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2764,11 +2724,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValuePara
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st>(
-            )))
-            .operation as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st,
+            operation
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2778,11 +2737,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValuePara
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st>(
-            )))
-            .address as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st,
+            address
+        ),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2792,11 +2750,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValuePara
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st>(
-            )))
-            .flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st,
+            flags
+        ),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2806,11 +2763,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValuePara
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st>(
-            )))
-            .alias as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st,
+            alias
+        ),
         32usize,
         concat!(
             "Offset of field: ",
@@ -2865,12 +2821,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValuePar
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1,
-            >()))
-            .value as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1,
+            value
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2882,12 +2836,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValuePar
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1,
-            >()))
-            .value64 as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1,
+            value64
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2918,11 +2870,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValuePar
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st>(
-            )))
-            .operation as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st,
+            operation
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -2932,11 +2883,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValuePar
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st>(
-            )))
-            .address as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st,
+            address
+        ),
         8usize,
         concat!(
             "Offset of field: ",
@@ -2946,11 +2896,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValuePar
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st>(
-            )))
-            .flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st,
+            flags
+        ),
         24usize,
         concat!(
             "Offset of field: ",
@@ -2960,11 +2909,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValuePar
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st>(
-            )))
-            .alias as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st,
+            alias
+        ),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3002,12 +2950,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWr
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st,
-            >()))
-            .operation as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st,
+            operation
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3017,12 +2963,10 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWr
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st,
-            >()))
-            .flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st,
+            flags
+        ),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3045,10 +2989,7 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union() {
         concat!("Alignment of ", stringify!(CUstreamBatchMemOpParams_union))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union>())).operation as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUstreamBatchMemOpParams_union, operation),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3058,10 +2999,7 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union>())).waitValue as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUstreamBatchMemOpParams_union, waitValue),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3071,10 +3009,7 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union>())).writeValue as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUstreamBatchMemOpParams_union, writeValue),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3084,10 +3019,7 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union>())).flushRemoteWrites as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUstreamBatchMemOpParams_union, flushRemoteWrites),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3097,9 +3029,7 @@ fn bindgen_test_layout_CUstreamBatchMemOpParams_union() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamBatchMemOpParams_union>())).pad as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUstreamBatchMemOpParams_union, pad),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3568,7 +3498,7 @@ fn bindgen_test_layout_CUdevprop_st() {
         concat!("Alignment of ", stringify!(CUdevprop_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUdevprop_st>())).maxThreadsPerBlock as *const _ as usize },
+        ::std::mem::offset_of!(CUdevprop_st, maxThreadsPerBlock),
         0usize,
         concat!(
             "Offset of field: ",
@@ -3578,7 +3508,7 @@ fn bindgen_test_layout_CUdevprop_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUdevprop_st>())).maxThreadsDim as *const _ as usize },
+        ::std::mem::offset_of!(CUdevprop_st, maxThreadsDim),
         4usize,
         concat!(
             "Offset of field: ",
@@ -3588,7 +3518,7 @@ fn bindgen_test_layout_CUdevprop_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUdevprop_st>())).maxGridSize as *const _ as usize },
+        ::std::mem::offset_of!(CUdevprop_st, maxGridSize),
         16usize,
         concat!(
             "Offset of field: ",
@@ -3598,7 +3528,7 @@ fn bindgen_test_layout_CUdevprop_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUdevprop_st>())).sharedMemPerBlock as *const _ as usize },
+        ::std::mem::offset_of!(CUdevprop_st, sharedMemPerBlock),
         28usize,
         concat!(
             "Offset of field: ",
@@ -3608,9 +3538,7 @@ fn bindgen_test_layout_CUdevprop_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUdevprop_st>())).totalConstantMemory as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUdevprop_st, totalConstantMemory),
         32usize,
         concat!(
             "Offset of field: ",
@@ -3620,7 +3548,7 @@ fn bindgen_test_layout_CUdevprop_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUdevprop_st>())).SIMDWidth as *const _ as usize },
+        ::std::mem::offset_of!(CUdevprop_st, SIMDWidth),
         36usize,
         concat!(
             "Offset of field: ",
@@ -3630,7 +3558,7 @@ fn bindgen_test_layout_CUdevprop_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUdevprop_st>())).memPitch as *const _ as usize },
+        ::std::mem::offset_of!(CUdevprop_st, memPitch),
         40usize,
         concat!(
             "Offset of field: ",
@@ -3640,7 +3568,7 @@ fn bindgen_test_layout_CUdevprop_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUdevprop_st>())).regsPerBlock as *const _ as usize },
+        ::std::mem::offset_of!(CUdevprop_st, regsPerBlock),
         44usize,
         concat!(
             "Offset of field: ",
@@ -3650,7 +3578,7 @@ fn bindgen_test_layout_CUdevprop_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUdevprop_st>())).clockRate as *const _ as usize },
+        ::std::mem::offset_of!(CUdevprop_st, clockRate),
         48usize,
         concat!(
             "Offset of field: ",
@@ -3660,7 +3588,7 @@ fn bindgen_test_layout_CUdevprop_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUdevprop_st>())).textureAlign as *const _ as usize },
+        ::std::mem::offset_of!(CUdevprop_st, textureAlign),
         52usize,
         concat!(
             "Offset of field: ",
@@ -4184,9 +4112,7 @@ fn bindgen_test_layout_CUaccessPolicyWindow_st() {
         concat!("Alignment of ", stringify!(CUaccessPolicyWindow_st))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUaccessPolicyWindow_st>())).base_ptr as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUaccessPolicyWindow_st, base_ptr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4196,9 +4122,7 @@ fn bindgen_test_layout_CUaccessPolicyWindow_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUaccessPolicyWindow_st>())).num_bytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUaccessPolicyWindow_st, num_bytes),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4208,9 +4132,7 @@ fn bindgen_test_layout_CUaccessPolicyWindow_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUaccessPolicyWindow_st>())).hitRatio as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUaccessPolicyWindow_st, hitRatio),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4220,7 +4142,7 @@ fn bindgen_test_layout_CUaccessPolicyWindow_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUaccessPolicyWindow_st>())).hitProp as *const _ as usize },
+        ::std::mem::offset_of!(CUaccessPolicyWindow_st, hitProp),
         20usize,
         concat!(
             "Offset of field: ",
@@ -4230,9 +4152,7 @@ fn bindgen_test_layout_CUaccessPolicyWindow_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUaccessPolicyWindow_st>())).missProp as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUaccessPolicyWindow_st, missProp),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4292,7 +4212,7 @@ fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_st() {
         concat!("Alignment of ", stringify!(CUDA_KERNEL_NODE_PARAMS_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_KERNEL_NODE_PARAMS_st>())).func as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, func),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4302,9 +4222,7 @@ fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_KERNEL_NODE_PARAMS_st>())).gridDimX as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, gridDimX),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4314,9 +4232,7 @@ fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_KERNEL_NODE_PARAMS_st>())).gridDimY as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, gridDimY),
         12usize,
         concat!(
             "Offset of field: ",
@@ -4326,9 +4242,7 @@ fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_KERNEL_NODE_PARAMS_st>())).gridDimZ as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, gridDimZ),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4338,9 +4252,7 @@ fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_KERNEL_NODE_PARAMS_st>())).blockDimX as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, blockDimX),
         20usize,
         concat!(
             "Offset of field: ",
@@ -4350,9 +4262,7 @@ fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_KERNEL_NODE_PARAMS_st>())).blockDimY as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, blockDimY),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4362,9 +4272,7 @@ fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_KERNEL_NODE_PARAMS_st>())).blockDimZ as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, blockDimZ),
         28usize,
         concat!(
             "Offset of field: ",
@@ -4374,10 +4282,7 @@ fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_KERNEL_NODE_PARAMS_st>())).sharedMemBytes as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, sharedMemBytes),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4387,9 +4292,7 @@ fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_KERNEL_NODE_PARAMS_st>())).kernelParams as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, kernelParams),
         40usize,
         concat!(
             "Offset of field: ",
@@ -4399,9 +4302,7 @@ fn bindgen_test_layout_CUDA_KERNEL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_KERNEL_NODE_PARAMS_st>())).extra as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, extra),
         48usize,
         concat!(
             "Offset of field: ",
@@ -4444,7 +4345,7 @@ fn bindgen_test_layout_CUDA_MEMSET_NODE_PARAMS_st() {
         concat!("Alignment of ", stringify!(CUDA_MEMSET_NODE_PARAMS_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMSET_NODE_PARAMS_st>())).dst as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, dst),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4454,9 +4355,7 @@ fn bindgen_test_layout_CUDA_MEMSET_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMSET_NODE_PARAMS_st>())).pitch as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, pitch),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4466,9 +4365,7 @@ fn bindgen_test_layout_CUDA_MEMSET_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMSET_NODE_PARAMS_st>())).value as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, value),
         16usize,
         concat!(
             "Offset of field: ",
@@ -4478,9 +4375,7 @@ fn bindgen_test_layout_CUDA_MEMSET_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMSET_NODE_PARAMS_st>())).elementSize as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, elementSize),
         20usize,
         concat!(
             "Offset of field: ",
@@ -4490,9 +4385,7 @@ fn bindgen_test_layout_CUDA_MEMSET_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMSET_NODE_PARAMS_st>())).width as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, width),
         24usize,
         concat!(
             "Offset of field: ",
@@ -4502,9 +4395,7 @@ fn bindgen_test_layout_CUDA_MEMSET_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMSET_NODE_PARAMS_st>())).height as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, height),
         32usize,
         concat!(
             "Offset of field: ",
@@ -4539,7 +4430,7 @@ fn bindgen_test_layout_CUDA_HOST_NODE_PARAMS_st() {
         concat!("Alignment of ", stringify!(CUDA_HOST_NODE_PARAMS_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_HOST_NODE_PARAMS_st>())).fn_ as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_HOST_NODE_PARAMS_st, fn_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4549,9 +4440,7 @@ fn bindgen_test_layout_CUDA_HOST_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_HOST_NODE_PARAMS_st>())).userData as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_HOST_NODE_PARAMS_st, userData),
         8usize,
         concat!(
             "Offset of field: ",
@@ -4626,10 +4515,7 @@ fn bindgen_test_layout_CUkernelNodeAttrValue_union() {
         concat!("Alignment of ", stringify!(CUkernelNodeAttrValue_union))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUkernelNodeAttrValue_union>())).accessPolicyWindow as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUkernelNodeAttrValue_union, accessPolicyWindow),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4639,9 +4525,7 @@ fn bindgen_test_layout_CUkernelNodeAttrValue_union() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUkernelNodeAttrValue_union>())).cooperative as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUkernelNodeAttrValue_union, cooperative),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4707,10 +4591,7 @@ fn bindgen_test_layout_CUstreamAttrValue_union() {
         concat!("Alignment of ", stringify!(CUstreamAttrValue_union))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamAttrValue_union>())).accessPolicyWindow as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUstreamAttrValue_union, accessPolicyWindow),
         0usize,
         concat!(
             "Offset of field: ",
@@ -4720,9 +4601,7 @@ fn bindgen_test_layout_CUstreamAttrValue_union() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUstreamAttrValue_union>())).syncPolicy as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUstreamAttrValue_union, syncPolicy),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5127,7 +5006,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         concat!("Alignment of ", stringify!(CUDA_MEMCPY2D_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).srcXInBytes as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcXInBytes),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5137,7 +5016,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).srcY as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcY),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5147,7 +5026,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).srcMemoryType as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcMemoryType),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5157,7 +5036,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).srcHost as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcHost),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5167,7 +5046,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).srcDevice as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcDevice),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5177,7 +5056,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).srcArray as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcArray),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5187,7 +5066,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).srcPitch as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcPitch),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5197,7 +5076,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).dstXInBytes as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstXInBytes),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5207,7 +5086,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).dstY as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstY),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5217,7 +5096,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).dstMemoryType as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstMemoryType),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5227,7 +5106,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).dstHost as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstHost),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5237,7 +5116,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).dstDevice as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstDevice),
         88usize,
         concat!(
             "Offset of field: ",
@@ -5247,7 +5126,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).dstArray as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstArray),
         96usize,
         concat!(
             "Offset of field: ",
@@ -5257,7 +5136,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).dstPitch as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstPitch),
         104usize,
         concat!(
             "Offset of field: ",
@@ -5267,7 +5146,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).WidthInBytes as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, WidthInBytes),
         112usize,
         concat!(
             "Offset of field: ",
@@ -5277,7 +5156,7 @@ fn bindgen_test_layout_CUDA_MEMCPY2D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY2D_st>())).Height as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY2D_st, Height),
         120usize,
         concat!(
             "Offset of field: ",
@@ -5358,7 +5237,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         concat!("Alignment of ", stringify!(CUDA_MEMCPY3D_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).srcXInBytes as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcXInBytes),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5368,7 +5247,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).srcY as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcY),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5378,7 +5257,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).srcZ as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcZ),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5388,7 +5267,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).srcLOD as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcLOD),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5398,7 +5277,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).srcMemoryType as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcMemoryType),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5408,7 +5287,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).srcHost as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcHost),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5418,7 +5297,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).srcDevice as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcDevice),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5428,7 +5307,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).srcArray as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcArray),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5438,7 +5317,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).reserved0 as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, reserved0),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5448,7 +5327,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).srcPitch as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcPitch),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5458,7 +5337,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).srcHeight as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcHeight),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5468,7 +5347,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).dstXInBytes as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstXInBytes),
         88usize,
         concat!(
             "Offset of field: ",
@@ -5478,7 +5357,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).dstY as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstY),
         96usize,
         concat!(
             "Offset of field: ",
@@ -5488,7 +5367,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).dstZ as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstZ),
         104usize,
         concat!(
             "Offset of field: ",
@@ -5498,7 +5377,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).dstLOD as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstLOD),
         112usize,
         concat!(
             "Offset of field: ",
@@ -5508,7 +5387,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).dstMemoryType as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstMemoryType),
         120usize,
         concat!(
             "Offset of field: ",
@@ -5518,7 +5397,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).dstHost as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstHost),
         128usize,
         concat!(
             "Offset of field: ",
@@ -5528,7 +5407,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).dstDevice as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstDevice),
         136usize,
         concat!(
             "Offset of field: ",
@@ -5538,7 +5417,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).dstArray as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstArray),
         144usize,
         concat!(
             "Offset of field: ",
@@ -5548,7 +5427,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).reserved1 as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, reserved1),
         152usize,
         concat!(
             "Offset of field: ",
@@ -5558,7 +5437,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).dstPitch as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstPitch),
         160usize,
         concat!(
             "Offset of field: ",
@@ -5568,7 +5447,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).dstHeight as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstHeight),
         168usize,
         concat!(
             "Offset of field: ",
@@ -5578,7 +5457,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).WidthInBytes as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, WidthInBytes),
         176usize,
         concat!(
             "Offset of field: ",
@@ -5588,7 +5467,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).Height as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, Height),
         184usize,
         concat!(
             "Offset of field: ",
@@ -5598,7 +5477,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_st>())).Depth as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_st, Depth),
         192usize,
         concat!(
             "Offset of field: ",
@@ -5679,9 +5558,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         concat!("Alignment of ", stringify!(CUDA_MEMCPY3D_PEER_st))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).srcXInBytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcXInBytes),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5691,7 +5568,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).srcY as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcY),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5701,7 +5578,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).srcZ as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcZ),
         16usize,
         concat!(
             "Offset of field: ",
@@ -5711,7 +5588,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).srcLOD as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcLOD),
         24usize,
         concat!(
             "Offset of field: ",
@@ -5721,9 +5598,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).srcMemoryType as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcMemoryType),
         32usize,
         concat!(
             "Offset of field: ",
@@ -5733,7 +5608,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).srcHost as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcHost),
         40usize,
         concat!(
             "Offset of field: ",
@@ -5743,7 +5618,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).srcDevice as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcDevice),
         48usize,
         concat!(
             "Offset of field: ",
@@ -5753,7 +5628,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).srcArray as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcArray),
         56usize,
         concat!(
             "Offset of field: ",
@@ -5763,9 +5638,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).srcContext as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcContext),
         64usize,
         concat!(
             "Offset of field: ",
@@ -5775,7 +5648,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).srcPitch as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcPitch),
         72usize,
         concat!(
             "Offset of field: ",
@@ -5785,7 +5658,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).srcHeight as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcHeight),
         80usize,
         concat!(
             "Offset of field: ",
@@ -5795,9 +5668,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).dstXInBytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstXInBytes),
         88usize,
         concat!(
             "Offset of field: ",
@@ -5807,7 +5678,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).dstY as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstY),
         96usize,
         concat!(
             "Offset of field: ",
@@ -5817,7 +5688,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).dstZ as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstZ),
         104usize,
         concat!(
             "Offset of field: ",
@@ -5827,7 +5698,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).dstLOD as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstLOD),
         112usize,
         concat!(
             "Offset of field: ",
@@ -5837,9 +5708,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).dstMemoryType as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstMemoryType),
         120usize,
         concat!(
             "Offset of field: ",
@@ -5849,7 +5718,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).dstHost as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstHost),
         128usize,
         concat!(
             "Offset of field: ",
@@ -5859,7 +5728,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).dstDevice as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstDevice),
         136usize,
         concat!(
             "Offset of field: ",
@@ -5869,7 +5738,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).dstArray as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstArray),
         144usize,
         concat!(
             "Offset of field: ",
@@ -5879,9 +5748,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).dstContext as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstContext),
         152usize,
         concat!(
             "Offset of field: ",
@@ -5891,7 +5758,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).dstPitch as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstPitch),
         160usize,
         concat!(
             "Offset of field: ",
@@ -5901,7 +5768,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).dstHeight as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstHeight),
         168usize,
         concat!(
             "Offset of field: ",
@@ -5911,9 +5778,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).WidthInBytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, WidthInBytes),
         176usize,
         concat!(
             "Offset of field: ",
@@ -5923,7 +5788,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).Height as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, Height),
         184usize,
         concat!(
             "Offset of field: ",
@@ -5933,7 +5798,7 @@ fn bindgen_test_layout_CUDA_MEMCPY3D_PEER_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_MEMCPY3D_PEER_st>())).Depth as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, Depth),
         192usize,
         concat!(
             "Offset of field: ",
@@ -5972,7 +5837,7 @@ fn bindgen_test_layout_CUDA_ARRAY_DESCRIPTOR_st() {
         concat!("Alignment of ", stringify!(CUDA_ARRAY_DESCRIPTOR_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_ARRAY_DESCRIPTOR_st>())).Width as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, Width),
         0usize,
         concat!(
             "Offset of field: ",
@@ -5982,7 +5847,7 @@ fn bindgen_test_layout_CUDA_ARRAY_DESCRIPTOR_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_ARRAY_DESCRIPTOR_st>())).Height as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, Height),
         8usize,
         concat!(
             "Offset of field: ",
@@ -5992,7 +5857,7 @@ fn bindgen_test_layout_CUDA_ARRAY_DESCRIPTOR_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_ARRAY_DESCRIPTOR_st>())).Format as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, Format),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6002,9 +5867,7 @@ fn bindgen_test_layout_CUDA_ARRAY_DESCRIPTOR_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY_DESCRIPTOR_st>())).NumChannels as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, NumChannels),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6047,9 +5910,7 @@ fn bindgen_test_layout_CUDA_ARRAY3D_DESCRIPTOR_st() {
         concat!("Alignment of ", stringify!(CUDA_ARRAY3D_DESCRIPTOR_st))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY3D_DESCRIPTOR_st>())).Width as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Width),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6059,9 +5920,7 @@ fn bindgen_test_layout_CUDA_ARRAY3D_DESCRIPTOR_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY3D_DESCRIPTOR_st>())).Height as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Height),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6071,9 +5930,7 @@ fn bindgen_test_layout_CUDA_ARRAY3D_DESCRIPTOR_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY3D_DESCRIPTOR_st>())).Depth as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Depth),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6083,9 +5940,7 @@ fn bindgen_test_layout_CUDA_ARRAY3D_DESCRIPTOR_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY3D_DESCRIPTOR_st>())).Format as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Format),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6095,9 +5950,7 @@ fn bindgen_test_layout_CUDA_ARRAY3D_DESCRIPTOR_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY3D_DESCRIPTOR_st>())).NumChannels as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, NumChannels),
         28usize,
         concat!(
             "Offset of field: ",
@@ -6107,9 +5960,7 @@ fn bindgen_test_layout_CUDA_ARRAY3D_DESCRIPTOR_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY3D_DESCRIPTOR_st>())).Flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Flags),
         32usize,
         concat!(
             "Offset of field: ",
@@ -6164,10 +6015,7 @@ fn bindgen_test_layout_CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1>())).width
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1, width),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6177,10 +6025,7 @@ fn bindgen_test_layout_CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1>())).height
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1, height),
         4usize,
         concat!(
             "Offset of field: ",
@@ -6190,10 +6035,7 @@ fn bindgen_test_layout_CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1>())).depth
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1, depth),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6216,10 +6058,7 @@ fn bindgen_test_layout_CUDA_ARRAY_SPARSE_PROPERTIES_st() {
         concat!("Alignment of ", stringify!(CUDA_ARRAY_SPARSE_PROPERTIES_st))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY_SPARSE_PROPERTIES_st>())).tileExtent as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, tileExtent),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6229,10 +6068,7 @@ fn bindgen_test_layout_CUDA_ARRAY_SPARSE_PROPERTIES_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY_SPARSE_PROPERTIES_st>())).miptailFirstLevel
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, miptailFirstLevel),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6242,10 +6078,7 @@ fn bindgen_test_layout_CUDA_ARRAY_SPARSE_PROPERTIES_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY_SPARSE_PROPERTIES_st>())).miptailSize as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, miptailSize),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6255,9 +6088,7 @@ fn bindgen_test_layout_CUDA_ARRAY_SPARSE_PROPERTIES_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY_SPARSE_PROPERTIES_st>())).flags as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6267,10 +6098,7 @@ fn bindgen_test_layout_CUDA_ARRAY_SPARSE_PROPERTIES_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_ARRAY_SPARSE_PROPERTIES_st>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, reserved),
         28usize,
         concat!(
             "Offset of field: ",
@@ -6327,10 +6155,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1>())).hArray
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1, hArray),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6365,10 +6190,10 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2>()))
-                .hMipmappedArray as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2,
+            hMipmappedArray
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6409,10 +6234,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3>())).devPtr
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3, devPtr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6422,10 +6244,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3>())).format
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3, format),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6435,10 +6254,10 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3>()))
-                .numChannels as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3,
+            numChannels
+        ),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6448,10 +6267,10 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3>()))
-                .sizeInBytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3,
+            sizeInBytes
+        ),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6496,10 +6315,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4>())).devPtr
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4, devPtr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6509,10 +6325,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4>())).format
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4, format),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6522,10 +6335,10 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4>()))
-                .numChannels as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4,
+            numChannels
+        ),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6535,10 +6348,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4>())).width
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4, width),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6548,10 +6358,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4>())).height
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4, height),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6561,10 +6368,10 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4>()))
-                .pitchInBytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4,
+            pitchInBytes
+        ),
         32usize,
         concat!(
             "Offset of field: ",
@@ -6598,10 +6405,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5>())).reserved
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6627,10 +6431,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1>())).array as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, array),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6640,10 +6441,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1>())).mipmap as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, mipmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6653,10 +6451,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1>())).linear as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, linear),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6666,10 +6461,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1>())).pitch2D as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, pitch2D),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6679,10 +6471,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st__bindgen_ty_1>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6705,7 +6494,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st() {
         concat!("Alignment of ", stringify!(CUDA_RESOURCE_DESC_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st>())).resType as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st, resType),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6715,7 +6504,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st>())).res as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st, res),
         8usize,
         concat!(
             "Offset of field: ",
@@ -6725,7 +6514,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_RESOURCE_DESC_st>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_RESOURCE_DESC_st, flags),
         136usize,
         concat!(
             "Offset of field: ",
@@ -6775,9 +6564,7 @@ fn bindgen_test_layout_CUDA_TEXTURE_DESC_st() {
         concat!("Alignment of ", stringify!(CUDA_TEXTURE_DESC_st))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_TEXTURE_DESC_st>())).addressMode as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, addressMode),
         0usize,
         concat!(
             "Offset of field: ",
@@ -6787,7 +6574,7 @@ fn bindgen_test_layout_CUDA_TEXTURE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_TEXTURE_DESC_st>())).filterMode as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, filterMode),
         12usize,
         concat!(
             "Offset of field: ",
@@ -6797,7 +6584,7 @@ fn bindgen_test_layout_CUDA_TEXTURE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_TEXTURE_DESC_st>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, flags),
         16usize,
         concat!(
             "Offset of field: ",
@@ -6807,9 +6594,7 @@ fn bindgen_test_layout_CUDA_TEXTURE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_TEXTURE_DESC_st>())).maxAnisotropy as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, maxAnisotropy),
         20usize,
         concat!(
             "Offset of field: ",
@@ -6819,9 +6604,7 @@ fn bindgen_test_layout_CUDA_TEXTURE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_TEXTURE_DESC_st>())).mipmapFilterMode as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, mipmapFilterMode),
         24usize,
         concat!(
             "Offset of field: ",
@@ -6831,9 +6614,7 @@ fn bindgen_test_layout_CUDA_TEXTURE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_TEXTURE_DESC_st>())).mipmapLevelBias as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, mipmapLevelBias),
         28usize,
         concat!(
             "Offset of field: ",
@@ -6843,10 +6624,7 @@ fn bindgen_test_layout_CUDA_TEXTURE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_TEXTURE_DESC_st>())).minMipmapLevelClamp as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, minMipmapLevelClamp),
         32usize,
         concat!(
             "Offset of field: ",
@@ -6856,10 +6634,7 @@ fn bindgen_test_layout_CUDA_TEXTURE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_TEXTURE_DESC_st>())).maxMipmapLevelClamp as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, maxMipmapLevelClamp),
         36usize,
         concat!(
             "Offset of field: ",
@@ -6869,9 +6644,7 @@ fn bindgen_test_layout_CUDA_TEXTURE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_TEXTURE_DESC_st>())).borderColor as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, borderColor),
         40usize,
         concat!(
             "Offset of field: ",
@@ -6881,7 +6654,7 @@ fn bindgen_test_layout_CUDA_TEXTURE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_TEXTURE_DESC_st>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, reserved),
         56usize,
         concat!(
             "Offset of field: ",
@@ -7004,9 +6777,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_VIEW_DESC_st() {
         concat!("Alignment of ", stringify!(CUDA_RESOURCE_VIEW_DESC_st))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_VIEW_DESC_st>())).format as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, format),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7016,9 +6787,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_VIEW_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_VIEW_DESC_st>())).width as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, width),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7028,9 +6797,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_VIEW_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_VIEW_DESC_st>())).height as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, height),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7040,9 +6807,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_VIEW_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_VIEW_DESC_st>())).depth as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, depth),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7052,10 +6817,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_VIEW_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_VIEW_DESC_st>())).firstMipmapLevel as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, firstMipmapLevel),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7065,10 +6827,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_VIEW_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_VIEW_DESC_st>())).lastMipmapLevel as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, lastMipmapLevel),
         36usize,
         concat!(
             "Offset of field: ",
@@ -7078,9 +6837,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_VIEW_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_VIEW_DESC_st>())).firstLayer as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, firstLayer),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7090,9 +6847,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_VIEW_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_VIEW_DESC_st>())).lastLayer as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, lastLayer),
         44usize,
         concat!(
             "Offset of field: ",
@@ -7102,9 +6857,7 @@ fn bindgen_test_layout_CUDA_RESOURCE_VIEW_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_RESOURCE_VIEW_DESC_st>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, reserved),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7143,10 +6896,7 @@ fn bindgen_test_layout_CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st>())).p2pToken as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st, p2pToken),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7156,10 +6906,7 @@ fn bindgen_test_layout_CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st>())).vaSpaceToken
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st, vaSpaceToken),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7225,7 +6972,7 @@ fn bindgen_test_layout_CUDA_LAUNCH_PARAMS_st() {
         concat!("Alignment of ", stringify!(CUDA_LAUNCH_PARAMS_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_LAUNCH_PARAMS_st>())).function as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, function),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7235,7 +6982,7 @@ fn bindgen_test_layout_CUDA_LAUNCH_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_LAUNCH_PARAMS_st>())).gridDimX as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, gridDimX),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7245,7 +6992,7 @@ fn bindgen_test_layout_CUDA_LAUNCH_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_LAUNCH_PARAMS_st>())).gridDimY as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, gridDimY),
         12usize,
         concat!(
             "Offset of field: ",
@@ -7255,7 +7002,7 @@ fn bindgen_test_layout_CUDA_LAUNCH_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_LAUNCH_PARAMS_st>())).gridDimZ as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, gridDimZ),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7265,7 +7012,7 @@ fn bindgen_test_layout_CUDA_LAUNCH_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_LAUNCH_PARAMS_st>())).blockDimX as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, blockDimX),
         20usize,
         concat!(
             "Offset of field: ",
@@ -7275,7 +7022,7 @@ fn bindgen_test_layout_CUDA_LAUNCH_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_LAUNCH_PARAMS_st>())).blockDimY as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, blockDimY),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7285,7 +7032,7 @@ fn bindgen_test_layout_CUDA_LAUNCH_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_LAUNCH_PARAMS_st>())).blockDimZ as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, blockDimZ),
         28usize,
         concat!(
             "Offset of field: ",
@@ -7295,9 +7042,7 @@ fn bindgen_test_layout_CUDA_LAUNCH_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_LAUNCH_PARAMS_st>())).sharedMemBytes as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, sharedMemBytes),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7307,7 +7052,7 @@ fn bindgen_test_layout_CUDA_LAUNCH_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUDA_LAUNCH_PARAMS_st>())).hStream as *const _ as usize },
+        ::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, hStream),
         40usize,
         concat!(
             "Offset of field: ",
@@ -7317,9 +7062,7 @@ fn bindgen_test_layout_CUDA_LAUNCH_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_LAUNCH_PARAMS_st>())).kernelParams as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, kernelParams),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7426,11 +7169,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindge
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1>(
-            )))
-            .handle as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
+            handle
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7440,11 +7182,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindge
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1>(
-            )))
-            .name as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
+            name
+        ),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7473,10 +7214,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1>())).fd
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1, fd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7486,10 +7224,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1>())).win32
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1, win32),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7499,10 +7234,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1>()))
-                .nvSciBufObject as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1,
+            nvSciBufObject
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7528,10 +7263,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st>())).type_ as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7541,10 +7273,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st>())).handle as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, handle),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7554,10 +7283,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st>())).size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, size),
         24usize,
         concat!(
             "Offset of field: ",
@@ -7567,10 +7293,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, flags),
         32usize,
         concat!(
             "Offset of field: ",
@@ -7580,10 +7303,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, reserved),
         36usize,
         concat!(
             "Offset of field: ",
@@ -7624,10 +7344,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st>())).offset as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, offset),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7637,10 +7354,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st>())).size as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, size),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7650,10 +7364,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, flags),
         16usize,
         concat!(
             "Offset of field: ",
@@ -7663,10 +7374,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, reserved),
         20usize,
         concat!(
             "Offset of field: ",
@@ -7711,10 +7419,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st>())).offset
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, offset),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7724,10 +7429,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st>())).arrayDesc
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, arrayDesc),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7737,10 +7439,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st>())).numLevels
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, numLevels),
         48usize,
         concat!(
             "Offset of field: ",
@@ -7750,10 +7449,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st>())).reserved
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, reserved),
         52usize,
         concat!(
             "Offset of field: ",
@@ -7863,12 +7559,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bin
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
-            >()))
-            .handle as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
+            handle
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7878,12 +7572,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bin
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
-            >()))
-            .name as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
+            name
+        ),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7912,10 +7604,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1>())).fd
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1, fd),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7925,10 +7614,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1>())).win32
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1, win32),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7938,10 +7624,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1>()))
-                .nvSciSyncObj as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1,
+            nvSciSyncObj
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7970,10 +7656,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st>())).type_ as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -7983,10 +7666,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st>())).handle as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, handle),
         8usize,
         concat!(
             "Offset of field: ",
@@ -7996,10 +7676,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, flags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8009,10 +7686,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, reserved),
         28usize,
         concat!(
             "Offset of field: ",
@@ -8077,12 +7751,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__b
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1,
-            >()))
-            .value as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1,
+            value
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8121,12 +7793,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__b
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
-            >()))
-            .fence as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
+            fence
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8136,12 +7806,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__b
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
-            >()))
-            .reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
+            reserved
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8179,12 +7847,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__b
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
-            >()))
-            .key as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
+            key
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8213,10 +7879,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1()
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1>())).fence
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1,
+            fence
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8226,10 +7892,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1()
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1>()))
-                .nvSciSync as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1,
+            nvSciSync
+        ),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8239,10 +7905,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1()
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1>()))
-                .keyedMutex as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1,
+            keyedMutex
+        ),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8252,10 +7918,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1()
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1>()))
-                .reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1,
+            reserved
+        ),
         24usize,
         concat!(
             "Offset of field: ",
@@ -8284,10 +7950,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st>())).params as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st, params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8297,10 +7960,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st, flags),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8310,10 +7970,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st>())).reserved
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st, reserved),
         76usize,
         concat!(
             "Offset of field: ",
@@ -8376,12 +8033,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bin
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1,
-            >()))
-            .value as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1,
+            value
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8419,12 +8074,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bin
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
-            >()))
-            .fence as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
+            fence
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8434,12 +8087,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bin
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
-            >()))
-            .reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
+            reserved
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8478,12 +8129,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bin
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
-            >()))
-            .key as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
+            key
+        ),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8493,12 +8142,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bin
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<
-                CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
-            >()))
-            .timeoutMs as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
+            timeoutMs
+        ),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8527,10 +8174,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1>())).fence
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1, fence),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8540,10 +8184,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1>()))
-                .nvSciSync as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1,
+            nvSciSync
+        ),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8553,10 +8197,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1>()))
-                .keyedMutex as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1,
+            keyedMutex
+        ),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8566,10 +8210,10 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1>()))
-                .reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(
+            CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1,
+            reserved
+        ),
         32usize,
         concat!(
             "Offset of field: ",
@@ -8598,10 +8242,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st>())).params as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st, params),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8611,10 +8252,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st>())).flags as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st, flags),
         72usize,
         concat!(
             "Offset of field: ",
@@ -8624,10 +8262,7 @@ fn bindgen_test_layout_CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st, reserved),
         76usize,
         concat!(
             "Offset of field: ",
@@ -8667,10 +8302,7 @@ fn bindgen_test_layout_CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st>())).extSemArray as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st, extSemArray),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8680,10 +8312,7 @@ fn bindgen_test_layout_CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st>())).paramsArray as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st, paramsArray),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8693,10 +8322,7 @@ fn bindgen_test_layout_CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st>())).numExtSems as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st, numExtSems),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8736,10 +8362,7 @@ fn bindgen_test_layout_CUDA_EXT_SEM_WAIT_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXT_SEM_WAIT_NODE_PARAMS_st>())).extSemArray as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st, extSemArray),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8749,10 +8372,7 @@ fn bindgen_test_layout_CUDA_EXT_SEM_WAIT_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXT_SEM_WAIT_NODE_PARAMS_st>())).paramsArray as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st, paramsArray),
         8usize,
         concat!(
             "Offset of field: ",
@@ -8762,10 +8382,7 @@ fn bindgen_test_layout_CUDA_EXT_SEM_WAIT_NODE_PARAMS_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUDA_EXT_SEM_WAIT_NODE_PARAMS_st>())).numExtSems as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st, numExtSems),
         16usize,
         concat!(
             "Offset of field: ",
@@ -8901,9 +8518,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_1() {
         concat!("Alignment of ", stringify!(CUarrayMapInfo_st__bindgen_ty_1))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_1>())).mipmap as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_1, mipmap),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8913,9 +8528,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_1>())).array as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_1, array),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8970,10 +8583,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>())).level
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, level),
         0usize,
         concat!(
             "Offset of field: ",
@@ -8983,10 +8593,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>())).layer
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, layer),
         4usize,
         concat!(
             "Offset of field: ",
@@ -8996,10 +8603,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>())).offsetX
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, offsetX),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9009,10 +8613,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>())).offsetY
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, offsetY),
         12usize,
         concat!(
             "Offset of field: ",
@@ -9022,10 +8623,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>())).offsetZ
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, offsetZ),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9035,10 +8633,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>())).extentWidth
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, extentWidth),
         20usize,
         concat!(
             "Offset of field: ",
@@ -9048,10 +8643,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>())).extentHeight
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, extentHeight),
         24usize,
         concat!(
             "Offset of field: ",
@@ -9061,10 +8653,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>())).extentDepth
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, extentDepth),
         28usize,
         concat!(
             "Offset of field: ",
@@ -9103,10 +8692,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2>())).layer
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2, layer),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9116,10 +8702,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2>())).offset
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2, offset),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9129,10 +8712,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2>())).size
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2, size),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9155,10 +8735,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2() {
         concat!("Alignment of ", stringify!(CUarrayMapInfo_st__bindgen_ty_2))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2>())).sparseLevel as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2, sparseLevel),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9168,9 +8745,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_2() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_2>())).miptail as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2, miptail),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9198,10 +8773,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st__bindgen_ty_3() {
         concat!("Alignment of ", stringify!(CUarrayMapInfo_st__bindgen_ty_3))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st__bindgen_ty_3>())).memHandle as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_3, memHandle),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9224,7 +8796,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st() {
         concat!("Alignment of ", stringify!(CUarrayMapInfo_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUarrayMapInfo_st>())).resourceType as *const _ as usize },
+        ::std::mem::offset_of!(CUarrayMapInfo_st, resourceType),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9234,7 +8806,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUarrayMapInfo_st>())).resource as *const _ as usize },
+        ::std::mem::offset_of!(CUarrayMapInfo_st, resource),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9244,9 +8816,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st>())).subresourceType as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st, subresourceType),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9256,7 +8826,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUarrayMapInfo_st>())).subresource as *const _ as usize },
+        ::std::mem::offset_of!(CUarrayMapInfo_st, subresource),
         24usize,
         concat!(
             "Offset of field: ",
@@ -9266,9 +8836,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUarrayMapInfo_st>())).memOperationType as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUarrayMapInfo_st, memOperationType),
         56usize,
         concat!(
             "Offset of field: ",
@@ -9278,7 +8846,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUarrayMapInfo_st>())).memHandleType as *const _ as usize },
+        ::std::mem::offset_of!(CUarrayMapInfo_st, memHandleType),
         60usize,
         concat!(
             "Offset of field: ",
@@ -9288,7 +8856,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUarrayMapInfo_st>())).memHandle as *const _ as usize },
+        ::std::mem::offset_of!(CUarrayMapInfo_st, memHandle),
         64usize,
         concat!(
             "Offset of field: ",
@@ -9298,7 +8866,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUarrayMapInfo_st>())).offset as *const _ as usize },
+        ::std::mem::offset_of!(CUarrayMapInfo_st, offset),
         72usize,
         concat!(
             "Offset of field: ",
@@ -9308,7 +8876,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUarrayMapInfo_st>())).deviceBitMask as *const _ as usize },
+        ::std::mem::offset_of!(CUarrayMapInfo_st, deviceBitMask),
         80usize,
         concat!(
             "Offset of field: ",
@@ -9318,7 +8886,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUarrayMapInfo_st>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(CUarrayMapInfo_st, flags),
         84usize,
         concat!(
             "Offset of field: ",
@@ -9328,7 +8896,7 @@ fn bindgen_test_layout_CUarrayMapInfo_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUarrayMapInfo_st>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(CUarrayMapInfo_st, reserved),
         88usize,
         concat!(
             "Offset of field: ",
@@ -9363,7 +8931,7 @@ fn bindgen_test_layout_CUmemLocation_st() {
         concat!("Alignment of ", stringify!(CUmemLocation_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUmemLocation_st>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(CUmemLocation_st, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9373,7 +8941,7 @@ fn bindgen_test_layout_CUmemLocation_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUmemLocation_st>())).id as *const _ as usize },
+        ::std::mem::offset_of!(CUmemLocation_st, id),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9450,10 +9018,7 @@ fn bindgen_test_layout_CUmemAllocationProp_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUmemAllocationProp_st__bindgen_ty_1>())).compressionType
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUmemAllocationProp_st__bindgen_ty_1, compressionType),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9463,10 +9028,7 @@ fn bindgen_test_layout_CUmemAllocationProp_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUmemAllocationProp_st__bindgen_ty_1>())).gpuDirectRDMACapable
-                as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUmemAllocationProp_st__bindgen_ty_1, gpuDirectRDMACapable),
         1usize,
         concat!(
             "Offset of field: ",
@@ -9476,10 +9038,7 @@ fn bindgen_test_layout_CUmemAllocationProp_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUmemAllocationProp_st__bindgen_ty_1>())).usage as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUmemAllocationProp_st__bindgen_ty_1, usage),
         2usize,
         concat!(
             "Offset of field: ",
@@ -9489,10 +9048,7 @@ fn bindgen_test_layout_CUmemAllocationProp_st__bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUmemAllocationProp_st__bindgen_ty_1>())).reserved as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUmemAllocationProp_st__bindgen_ty_1, reserved),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9515,7 +9071,7 @@ fn bindgen_test_layout_CUmemAllocationProp_st() {
         concat!("Alignment of ", stringify!(CUmemAllocationProp_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUmemAllocationProp_st>())).type_ as *const _ as usize },
+        ::std::mem::offset_of!(CUmemAllocationProp_st, type_),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9525,10 +9081,7 @@ fn bindgen_test_layout_CUmemAllocationProp_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUmemAllocationProp_st>())).requestedHandleTypes as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUmemAllocationProp_st, requestedHandleTypes),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9538,7 +9091,7 @@ fn bindgen_test_layout_CUmemAllocationProp_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUmemAllocationProp_st>())).location as *const _ as usize },
+        ::std::mem::offset_of!(CUmemAllocationProp_st, location),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9548,10 +9101,7 @@ fn bindgen_test_layout_CUmemAllocationProp_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUmemAllocationProp_st>())).win32HandleMetaData as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUmemAllocationProp_st, win32HandleMetaData),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9561,9 +9111,7 @@ fn bindgen_test_layout_CUmemAllocationProp_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUmemAllocationProp_st>())).allocFlags as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUmemAllocationProp_st, allocFlags),
         24usize,
         concat!(
             "Offset of field: ",
@@ -9598,7 +9146,7 @@ fn bindgen_test_layout_CUmemAccessDesc_st() {
         concat!("Alignment of ", stringify!(CUmemAccessDesc_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUmemAccessDesc_st>())).location as *const _ as usize },
+        ::std::mem::offset_of!(CUmemAccessDesc_st, location),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9608,7 +9156,7 @@ fn bindgen_test_layout_CUmemAccessDesc_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUmemAccessDesc_st>())).flags as *const _ as usize },
+        ::std::mem::offset_of!(CUmemAccessDesc_st, flags),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9691,7 +9239,7 @@ fn bindgen_test_layout_CUmemPoolProps_st() {
         concat!("Alignment of ", stringify!(CUmemPoolProps_st))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUmemPoolProps_st>())).allocType as *const _ as usize },
+        ::std::mem::offset_of!(CUmemPoolProps_st, allocType),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9701,7 +9249,7 @@ fn bindgen_test_layout_CUmemPoolProps_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUmemPoolProps_st>())).handleTypes as *const _ as usize },
+        ::std::mem::offset_of!(CUmemPoolProps_st, handleTypes),
         4usize,
         concat!(
             "Offset of field: ",
@@ -9711,7 +9259,7 @@ fn bindgen_test_layout_CUmemPoolProps_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUmemPoolProps_st>())).location as *const _ as usize },
+        ::std::mem::offset_of!(CUmemPoolProps_st, location),
         8usize,
         concat!(
             "Offset of field: ",
@@ -9721,10 +9269,7 @@ fn bindgen_test_layout_CUmemPoolProps_st() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUmemPoolProps_st>())).win32SecurityAttributes as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(CUmemPoolProps_st, win32SecurityAttributes),
         16usize,
         concat!(
             "Offset of field: ",
@@ -9734,7 +9279,7 @@ fn bindgen_test_layout_CUmemPoolProps_st() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<CUmemPoolProps_st>())).reserved as *const _ as usize },
+        ::std::mem::offset_of!(CUmemPoolProps_st, reserved),
         24usize,
         concat!(
             "Offset of field: ",
@@ -9766,9 +9311,7 @@ fn bindgen_test_layout_CUmemPoolPtrExportData_st() {
         concat!("Alignment of ", stringify!(CUmemPoolPtrExportData_st))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<CUmemPoolPtrExportData_st>())).reserved as *const _ as usize
-        },
+        ::std::mem::offset_of!(CUmemPoolPtrExportData_st, reserved),
         0usize,
         concat!(
             "Offset of field: ",
@@ -9869,7 +9412,7 @@ pub const CUuserObjectRetain_flags_enum_CU_GRAPH_USER_OBJECT_MOVE: CUuserObjectR
 pub type CUuserObjectRetain_flags_enum = ::std::os::raw::c_uint;
 #[doc = " Flags for retaining user object references for graphs"]
 pub use self::CUuserObjectRetain_flags_enum as CUuserObjectRetain_flags;
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the string description of an error code"]
     #[doc = ""]
     #[doc = " Sets \\p *pStr to the address of a NULL-terminated string description"]
@@ -9889,7 +9432,7 @@ extern "C" {
     #[doc = " ::cudaGetErrorString"]
     pub fn cuGetErrorString(error: CUresult, pStr: *mut *const ::std::os::raw::c_char) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the string representation of an error code enum name"]
     #[doc = ""]
     #[doc = " Sets \\p *pStr to the address of a NULL-terminated string representation"]
@@ -9909,7 +9452,7 @@ extern "C" {
     #[doc = " ::cudaGetErrorName"]
     pub fn cuGetErrorName(error: CUresult, pStr: *mut *const ::std::os::raw::c_char) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Initialize the CUDA driver API"]
     #[doc = ""]
     #[doc = " Initializes the driver API and must be called before any other function from"]
@@ -9928,7 +9471,7 @@ extern "C" {
     #[doc = " \\notefnerr"]
     pub fn cuInit(Flags: ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the latest CUDA version supported by driver"]
     #[doc = ""]
     #[doc = " Returns in \\p *driverVersion the version of CUDA supported by"]
@@ -9951,7 +9494,7 @@ extern "C" {
     #[doc = " ::cudaRuntimeGetVersion"]
     pub fn cuDriverGetVersion(driverVersion: *mut ::std::os::raw::c_int) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a handle to a compute device"]
     #[doc = ""]
     #[doc = " Returns in \\p *device a device handle given an ordinal in the range <b>[0,"]
@@ -9978,7 +9521,7 @@ extern "C" {
     #[doc = " ::cuDeviceTotalMem"]
     pub fn cuDeviceGet(device: *mut CUdevice, ordinal: ::std::os::raw::c_int) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the number of compute-capable devices"]
     #[doc = ""]
     #[doc = " Returns in \\p *count the number of devices with compute capability greater"]
@@ -10005,7 +9548,7 @@ extern "C" {
     #[doc = " ::cudaGetDeviceCount"]
     pub fn cuDeviceGetCount(count: *mut ::std::os::raw::c_int) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns an identifer string for the device"]
     #[doc = ""]
     #[doc = " Returns an ASCII string identifying the device \\p dev in the NULL-terminated"]
@@ -10039,7 +9582,7 @@ extern "C" {
         dev: CUdevice,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Return an UUID for the device"]
     #[doc = ""]
     #[doc = " Returns 16-octets identifing the device \\p dev in the structure"]
@@ -10066,7 +9609,7 @@ extern "C" {
     #[doc = " ::cudaGetDeviceProperties"]
     pub fn cuDeviceGetUuid(uuid: *mut CUuuid, dev: CUdevice) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Return an LUID and device node mask for the device"]
     #[doc = ""]
     #[doc = " Return identifying information (\\p luid and \\p deviceNodeMask) to allow"]
@@ -10097,10 +9640,10 @@ extern "C" {
         dev: CUdevice,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuDeviceTotalMem_v2(bytes: *mut size_t, dev: CUdevice) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the maximum number of elements allocatable in a 1D linear texture for a given texture element size."]
     #[doc = ""]
     #[doc = " Returns in \\p maxWidthInElements the maximum number of texture elements allocatable in a 1D linear texture"]
@@ -10135,7 +9678,7 @@ extern "C" {
         dev: CUdevice,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns information about the device"]
     #[doc = ""]
     #[doc = " Returns in \\p *pi the integer value of the attribute \\p attrib on device"]
@@ -10353,7 +9896,7 @@ extern "C" {
         dev: CUdevice,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Return NvSciSync attributes that this device can support."]
     #[doc = ""]
     #[doc = " Returns in \\p nvSciSyncAttrList, the properties of NvSciSync that"]
@@ -10405,7 +9948,7 @@ extern "C" {
         flags: ::std::os::raw::c_int,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the current memory pool of a device"]
     #[doc = ""]
     #[doc = " The memory pool must be local to the specified device."]
@@ -10422,7 +9965,7 @@ extern "C" {
     #[doc = " \\sa ::cuDeviceGetDefaultMemPool, ::cuDeviceGetMemPool, ::cuMemPoolCreate, ::cuMemPoolDestroy, ::cuMemAllocFromPoolAsync"]
     pub fn cuDeviceSetMemPool(dev: CUdevice, pool: CUmemoryPool) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the current mempool for a device"]
     #[doc = ""]
     #[doc = " Returns the last pool provided to ::cuDeviceSetMemPool for this device"]
@@ -10437,7 +9980,7 @@ extern "C" {
     #[doc = " \\sa ::cuDeviceGetDefaultMemPool, ::cuMemPoolCreate, ::cuDeviceSetMemPool"]
     pub fn cuDeviceGetMemPool(pool: *mut CUmemoryPool, dev: CUdevice) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the default mempool of a device"]
     #[doc = ""]
     #[doc = " The default mempool of a device contains device memory from that device."]
@@ -10454,7 +9997,7 @@ extern "C" {
     #[doc = " \\sa ::cuMemAllocAsync, ::cuMemPoolTrimTo, ::cuMemPoolGetAttribute, ::cuMemPoolSetAttribute, cuMemPoolSetAccess, ::cuDeviceGetMemPool, ::cuMemPoolCreate"]
     pub fn cuDeviceGetDefaultMemPool(pool_out: *mut CUmemoryPool, dev: CUdevice) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns properties for a selected device"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -10517,7 +10060,7 @@ extern "C" {
     #[doc = " ::cuDeviceTotalMem"]
     pub fn cuDeviceGetProperties(prop: *mut CUdevprop, dev: CUdevice) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the compute capability of the device"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -10554,7 +10097,7 @@ extern "C" {
         dev: CUdevice,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Retain the primary context on the GPU"]
     #[doc = ""]
     #[doc = " Retains the primary context on the device."]
@@ -10603,13 +10146,13 @@ extern "C" {
     #[doc = " ::cuCtxSynchronize"]
     pub fn cuDevicePrimaryCtxRetain(pctx: *mut CUcontext, dev: CUdevice) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuDevicePrimaryCtxRelease_v2(dev: CUdevice) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuDevicePrimaryCtxSetFlags_v2(dev: CUdevice, flags: ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Get the state of the primary context"]
     #[doc = ""]
     #[doc = " Returns in \\p *flags the flags for the primary context of \\p dev, and in"]
@@ -10638,26 +10181,26 @@ extern "C" {
         active: *mut ::std::os::raw::c_int,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuDevicePrimaryCtxReset_v2(dev: CUdevice) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuCtxCreate_v2(
         pctx: *mut CUcontext,
         flags: ::std::os::raw::c_uint,
         dev: CUdevice,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuCtxDestroy_v2(ctx: CUcontext) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuCtxPushCurrent_v2(ctx: CUcontext) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuCtxPopCurrent_v2(pctx: *mut CUcontext) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Binds the specified CUDA context to the calling CPU thread"]
     #[doc = ""]
     #[doc = " Binds the specified CUDA context to the calling CPU thread."]
@@ -10686,7 +10229,7 @@ extern "C" {
     #[doc = " ::cudaSetDevice"]
     pub fn cuCtxSetCurrent(ctx: CUcontext) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the CUDA context bound to the calling CPU thread."]
     #[doc = ""]
     #[doc = " Returns in \\p *pctx the CUDA context bound to the calling CPU thread."]
@@ -10708,7 +10251,7 @@ extern "C" {
     #[doc = " ::cudaGetDevice"]
     pub fn cuCtxGetCurrent(pctx: *mut CUcontext) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the device ID for the current context"]
     #[doc = ""]
     #[doc = " Returns in \\p *device the ordinal of the current context's device."]
@@ -10737,7 +10280,7 @@ extern "C" {
     #[doc = " ::cudaGetDevice"]
     pub fn cuCtxGetDevice(device: *mut CUdevice) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the flags for the current context"]
     #[doc = ""]
     #[doc = " Returns in \\p *flags the flags of the current context. See ::cuCtxCreate"]
@@ -10764,7 +10307,7 @@ extern "C" {
     #[doc = " ::cudaGetDeviceFlags"]
     pub fn cuCtxGetFlags(flags: *mut ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Block for a context's tasks to complete"]
     #[doc = ""]
     #[doc = " Blocks until the device has completed all preceding requested tasks."]
@@ -10793,7 +10336,7 @@ extern "C" {
     #[doc = " ::cudaDeviceSynchronize"]
     pub fn cuCtxSynchronize() -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Set resource limits"]
     #[doc = ""]
     #[doc = " Setting \\p limit to \\p value is a request by the application to update"]
@@ -10891,7 +10434,7 @@ extern "C" {
     #[doc = " ::cudaDeviceSetLimit"]
     pub fn cuCtxSetLimit(limit: CUlimit, value: size_t) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns resource limits"]
     #[doc = ""]
     #[doc = " Returns in \\p *pvalue the current size of \\p limit.  The supported"]
@@ -10932,7 +10475,7 @@ extern "C" {
     #[doc = " ::cudaDeviceGetLimit"]
     pub fn cuCtxGetLimit(pvalue: *mut size_t, limit: CUlimit) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the preferred cache configuration for the current context."]
     #[doc = ""]
     #[doc = " On devices where the L1 cache and shared memory use the same hardware"]
@@ -10975,7 +10518,7 @@ extern "C" {
     #[doc = " ::cudaDeviceGetCacheConfig"]
     pub fn cuCtxGetCacheConfig(pconfig: *mut CUfunc_cache) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the preferred cache configuration for the current context."]
     #[doc = ""]
     #[doc = " On devices where the L1 cache and shared memory use the same hardware"]
@@ -11025,7 +10568,7 @@ extern "C" {
     #[doc = " ::cudaDeviceSetCacheConfig"]
     pub fn cuCtxSetCacheConfig(config: CUfunc_cache) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the current shared memory configuration for the current context."]
     #[doc = ""]
     #[doc = " This function will return in \\p pConfig the current size of shared memory banks"]
@@ -11066,7 +10609,7 @@ extern "C" {
     #[doc = " ::cudaDeviceGetSharedMemConfig"]
     pub fn cuCtxGetSharedMemConfig(pConfig: *mut CUsharedconfig) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the shared memory configuration for the current context."]
     #[doc = ""]
     #[doc = " On devices with configurable shared memory banks, this function will set"]
@@ -11118,7 +10661,7 @@ extern "C" {
     #[doc = " ::cudaDeviceSetSharedMemConfig"]
     pub fn cuCtxSetSharedMemConfig(config: CUsharedconfig) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the context's API version."]
     #[doc = ""]
     #[doc = " Returns a version number in \\p version corresponding to the capabilities of"]
@@ -11155,7 +10698,7 @@ extern "C" {
     #[doc = " ::cuCtxSynchronize"]
     pub fn cuCtxGetApiVersion(ctx: CUcontext, version: *mut ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns numerical values that correspond to the least and"]
     #[doc = " greatest stream priorities."]
     #[doc = ""]
@@ -11197,7 +10740,7 @@ extern "C" {
         greatestPriority: *mut ::std::os::raw::c_int,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Resets all persisting lines in cache to normal status."]
     #[doc = ""]
     #[doc = " ::cuCtxResetPersistingL2Cache Resets all persisting lines in cache to normal"]
@@ -11212,7 +10755,7 @@ extern "C" {
     #[doc = " ::CUaccessPolicyWindow"]
     pub fn cuCtxResetPersistingL2Cache() -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Increment a context's usage-count"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -11252,7 +10795,7 @@ extern "C" {
     #[doc = " ::cuCtxSynchronize"]
     pub fn cuCtxAttach(pctx: *mut CUcontext, flags: ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Decrement a context's usage-count"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -11287,7 +10830,7 @@ extern "C" {
     #[doc = " ::cuCtxSynchronize"]
     pub fn cuCtxDetach(ctx: CUcontext) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Loads a compute module"]
     #[doc = ""]
     #[doc = " Takes a filename \\p fname and loads the corresponding module \\p module into"]
@@ -11327,7 +10870,7 @@ extern "C" {
     #[doc = " ::cuModuleUnload"]
     pub fn cuModuleLoad(module: *mut CUmodule, fname: *const ::std::os::raw::c_char) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Load a module's data"]
     #[doc = ""]
     #[doc = " Takes a pointer \\p image and loads the corresponding module \\p module into"]
@@ -11367,7 +10910,7 @@ extern "C" {
         image: *const ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Load a module's data with options"]
     #[doc = ""]
     #[doc = " Takes a pointer \\p image and loads the corresponding module \\p module into"]
@@ -11416,7 +10959,7 @@ extern "C" {
         optionValues: *mut *mut ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Load a module's data"]
     #[doc = ""]
     #[doc = " Takes a pointer \\p fatCubin and loads the corresponding module \\p module"]
@@ -11461,7 +11004,7 @@ extern "C" {
         fatCubin: *const ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Unloads a module"]
     #[doc = ""]
     #[doc = " Unloads a module \\p hmod from the current context."]
@@ -11485,7 +11028,7 @@ extern "C" {
     #[doc = " ::cuModuleLoadFatBinary"]
     pub fn cuModuleUnload(hmod: CUmodule) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a function handle"]
     #[doc = ""]
     #[doc = " Returns in \\p *hfunc the handle of the function of name \\p name located in"]
@@ -11518,7 +11061,7 @@ extern "C" {
         name: *const ::std::os::raw::c_char,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuModuleGetGlobal_v2(
         dptr: *mut CUdeviceptr,
         bytes: *mut size_t,
@@ -11526,7 +11069,7 @@ extern "C" {
         name: *const ::std::os::raw::c_char,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a handle to a texture reference"]
     #[doc = ""]
     #[doc = " Returns in \\p *pTexRef the handle of the texture reference of name \\p name"]
@@ -11563,7 +11106,7 @@ extern "C" {
         name: *const ::std::os::raw::c_char,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a handle to a surface reference"]
     #[doc = ""]
     #[doc = " Returns in \\p *pSurfRef the handle of the surface reference of name \\p name"]
@@ -11598,7 +11141,7 @@ extern "C" {
         name: *const ::std::os::raw::c_char,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuLinkCreate_v2(
         numOptions: ::std::os::raw::c_uint,
         options: *mut CUjit_option,
@@ -11606,7 +11149,7 @@ extern "C" {
         stateOut: *mut CUlinkState,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuLinkAddData_v2(
         state: CUlinkState,
         type_: CUjitInputType,
@@ -11618,7 +11161,7 @@ extern "C" {
         optionValues: *mut *mut ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuLinkAddFile_v2(
         state: CUlinkState,
         type_: CUjitInputType,
@@ -11628,7 +11171,7 @@ extern "C" {
         optionValues: *mut *mut ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Complete a pending linker invocation"]
     #[doc = ""]
     #[doc = " Completes the pending linker action and returns the cubin image for the linked"]
@@ -11656,7 +11199,7 @@ extern "C" {
         sizeOut: *mut size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Destroys state for a JIT linker invocation."]
     #[doc = ""]
     #[doc = " \\param state State object for the linker invocation"]
@@ -11668,13 +11211,13 @@ extern "C" {
     #[doc = " \\sa ::cuLinkCreate"]
     pub fn cuLinkDestroy(state: CUlinkState) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemGetInfo_v2(free: *mut size_t, total: *mut size_t) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemAlloc_v2(dptr: *mut CUdeviceptr, bytesize: size_t) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemAllocPitch_v2(
         dptr: *mut CUdeviceptr,
         pPitch: *mut size_t,
@@ -11683,20 +11226,20 @@ extern "C" {
         ElementSizeBytes: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemFree_v2(dptr: CUdeviceptr) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemGetAddressRange_v2(
         pbase: *mut CUdeviceptr,
         psize: *mut size_t,
         dptr: CUdeviceptr,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemAllocHost_v2(pp: *mut *mut ::std::os::raw::c_void, bytesize: size_t) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Frees page-locked host memory"]
     #[doc = ""]
     #[doc = " Frees the memory space pointed to by \\p p, which must have been returned by"]
@@ -11725,7 +11268,7 @@ extern "C" {
     #[doc = " ::cudaFreeHost"]
     pub fn cuMemFreeHost(p: *mut ::std::os::raw::c_void) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Allocates page-locked host memory"]
     #[doc = ""]
     #[doc = " Allocates \\p bytesize bytes of host memory that is page-locked and accessible"]
@@ -11807,14 +11350,14 @@ extern "C" {
         Flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemHostGetDevicePointer_v2(
         pdptr: *mut CUdeviceptr,
         p: *mut ::std::os::raw::c_void,
         Flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Passes back flags that were used for a pinned allocation"]
     #[doc = ""]
     #[doc = " Passes back the flags \\p pFlags that were specified when allocating"]
@@ -11843,7 +11386,7 @@ extern "C" {
         p: *mut ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Allocates memory that will be automatically managed by the Unified Memory system"]
     #[doc = ""]
     #[doc = " Allocates \\p bytesize bytes of managed memory on the device and returns in"]
@@ -11956,7 +11499,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a handle to a compute device"]
     #[doc = ""]
     #[doc = " Returns in \\p *device a device handle given a PCI bus ID string."]
@@ -11987,7 +11530,7 @@ extern "C" {
         pciBusId: *const ::std::os::raw::c_char,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a PCI Bus Id string for the device"]
     #[doc = ""]
     #[doc = " Returns an ASCII string identifying the device \\p dev in the NULL-terminated"]
@@ -12022,7 +11565,7 @@ extern "C" {
         dev: CUdevice,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets an interprocess handle for a previously allocated event"]
     #[doc = ""]
     #[doc = " Takes as input a previously allocated event. This event must have been"]
@@ -12066,7 +11609,7 @@ extern "C" {
     #[doc = " ::cudaIpcGetEventHandle"]
     pub fn cuIpcGetEventHandle(pHandle: *mut CUipcEventHandle, event: CUevent) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Opens an interprocess event handle for use in the current process"]
     #[doc = ""]
     #[doc = " Opens an interprocess event handle exported from another process with"]
@@ -12105,7 +11648,7 @@ extern "C" {
     #[doc = " ::cudaIpcOpenEventHandle"]
     pub fn cuIpcOpenEventHandle(phEvent: *mut CUevent, handle: CUipcEventHandle) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets an interprocess memory handle for an existing device memory"]
     #[doc = " allocation"]
     #[doc = ""]
@@ -12145,14 +11688,14 @@ extern "C" {
     #[doc = " ::cudaIpcGetMemHandle"]
     pub fn cuIpcGetMemHandle(pHandle: *mut CUipcMemHandle, dptr: CUdeviceptr) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuIpcOpenMemHandle_v2(
         pdptr: *mut CUdeviceptr,
         handle: CUipcMemHandle,
         Flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Attempts to close memory mapped with ::cuIpcOpenMemHandle"]
     #[doc = ""]
     #[doc = " Decrements the reference count of the memory returned by ::cuIpcOpenMemHandle by 1."]
@@ -12185,14 +11728,14 @@ extern "C" {
     #[doc = " ::cudaIpcCloseMemHandle"]
     pub fn cuIpcCloseMemHandle(dptr: CUdeviceptr) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemHostRegister_v2(
         p: *mut ::std::os::raw::c_void,
         bytesize: size_t,
         Flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Unregisters a memory range that was registered with cuMemHostRegister."]
     #[doc = ""]
     #[doc = " Unmaps the memory range whose base address is specified by \\p p, and makes"]
@@ -12217,7 +11760,7 @@ extern "C" {
     #[doc = " ::cudaHostUnregister"]
     pub fn cuMemHostUnregister(p: *mut ::std::os::raw::c_void) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Copies memory"]
     #[doc = ""]
     #[doc = " Copies data between two pointers."]
@@ -12256,7 +11799,7 @@ extern "C" {
     #[doc = " ::cudaMemcpyFromSymbol"]
     pub fn cuMemcpy(dst: CUdeviceptr, src: CUdeviceptr, ByteCount: size_t) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Copies device memory between two contexts"]
     #[doc = ""]
     #[doc = " Copies from device memory in one context to device memory in another"]
@@ -12291,28 +11834,28 @@ extern "C" {
         ByteCount: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyHtoD_v2(
         dstDevice: CUdeviceptr,
         srcHost: *const ::std::os::raw::c_void,
         ByteCount: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyDtoH_v2(
         dstHost: *mut ::std::os::raw::c_void,
         srcDevice: CUdeviceptr,
         ByteCount: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyDtoD_v2(
         dstDevice: CUdeviceptr,
         srcDevice: CUdeviceptr,
         ByteCount: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyDtoA_v2(
         dstArray: CUarray,
         dstOffset: size_t,
@@ -12320,7 +11863,7 @@ extern "C" {
         ByteCount: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyAtoD_v2(
         dstDevice: CUdeviceptr,
         srcArray: CUarray,
@@ -12328,7 +11871,7 @@ extern "C" {
         ByteCount: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyHtoA_v2(
         dstArray: CUarray,
         dstOffset: size_t,
@@ -12336,7 +11879,7 @@ extern "C" {
         ByteCount: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyAtoH_v2(
         dstHost: *mut ::std::os::raw::c_void,
         srcArray: CUarray,
@@ -12344,7 +11887,7 @@ extern "C" {
         ByteCount: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyAtoA_v2(
         dstArray: CUarray,
         dstOffset: size_t,
@@ -12353,16 +11896,16 @@ extern "C" {
         ByteCount: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpy2D_v2(pCopy: *const CUDA_MEMCPY2D) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpy2DUnaligned_v2(pCopy: *const CUDA_MEMCPY2D) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpy3D_v2(pCopy: *const CUDA_MEMCPY3D) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Copies memory between contexts"]
     #[doc = ""]
     #[doc = " Perform a 3D memory copy according to the parameters specified in"]
@@ -12385,7 +11928,7 @@ extern "C" {
     #[doc = " ::cudaMemcpy3DPeer"]
     pub fn cuMemcpy3DPeer(pCopy: *const CUDA_MEMCPY3D_PEER) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Copies memory asynchronously"]
     #[doc = ""]
     #[doc = " Copies data between two pointers."]
@@ -12434,7 +11977,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Copies device memory between two contexts asynchronously."]
     #[doc = ""]
     #[doc = " Copies from device memory in one context to device memory in another"]
@@ -12473,7 +12016,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyHtoDAsync_v2(
         dstDevice: CUdeviceptr,
         srcHost: *const ::std::os::raw::c_void,
@@ -12481,7 +12024,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyDtoHAsync_v2(
         dstHost: *mut ::std::os::raw::c_void,
         srcDevice: CUdeviceptr,
@@ -12489,7 +12032,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyDtoDAsync_v2(
         dstDevice: CUdeviceptr,
         srcDevice: CUdeviceptr,
@@ -12497,7 +12040,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyHtoAAsync_v2(
         dstArray: CUarray,
         dstOffset: size_t,
@@ -12506,7 +12049,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpyAtoHAsync_v2(
         dstHost: *mut ::std::os::raw::c_void,
         srcArray: CUarray,
@@ -12515,13 +12058,13 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpy2DAsync_v2(pCopy: *const CUDA_MEMCPY2D, hStream: CUstream) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemcpy3DAsync_v2(pCopy: *const CUDA_MEMCPY3D, hStream: CUstream) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Copies memory between contexts asynchronously."]
     #[doc = ""]
     #[doc = " Perform a 3D memory copy according to the parameters specified in"]
@@ -12546,28 +12089,28 @@ extern "C" {
     #[doc = " ::cudaMemcpy3DPeerAsync"]
     pub fn cuMemcpy3DPeerAsync(pCopy: *const CUDA_MEMCPY3D_PEER, hStream: CUstream) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemsetD8_v2(
         dstDevice: CUdeviceptr,
         uc: ::std::os::raw::c_uchar,
         N: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemsetD16_v2(
         dstDevice: CUdeviceptr,
         us: ::std::os::raw::c_ushort,
         N: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemsetD32_v2(
         dstDevice: CUdeviceptr,
         ui: ::std::os::raw::c_uint,
         N: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemsetD2D8_v2(
         dstDevice: CUdeviceptr,
         dstPitch: size_t,
@@ -12576,7 +12119,7 @@ extern "C" {
         Height: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemsetD2D16_v2(
         dstDevice: CUdeviceptr,
         dstPitch: size_t,
@@ -12585,7 +12128,7 @@ extern "C" {
         Height: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuMemsetD2D32_v2(
         dstDevice: CUdeviceptr,
         dstPitch: size_t,
@@ -12594,7 +12137,7 @@ extern "C" {
         Height: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets device memory"]
     #[doc = ""]
     #[doc = " Sets the memory range of \\p N 8-bit values to the specified value"]
@@ -12635,7 +12178,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets device memory"]
     #[doc = ""]
     #[doc = " Sets the memory range of \\p N 16-bit values to the specified value"]
@@ -12676,7 +12219,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets device memory"]
     #[doc = ""]
     #[doc = " Sets the memory range of \\p N 32-bit values to the specified value"]
@@ -12716,7 +12259,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets device memory"]
     #[doc = ""]
     #[doc = " Sets the 2D memory range of \\p Width 8-bit values to the specified value"]
@@ -12764,7 +12307,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets device memory"]
     #[doc = ""]
     #[doc = " Sets the 2D memory range of \\p Width 16-bit values to the specified value"]
@@ -12813,7 +12356,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets device memory"]
     #[doc = ""]
     #[doc = " Sets the 2D memory range of \\p Width 32-bit values to the specified value"]
@@ -12862,19 +12405,19 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuArrayCreate_v2(
         pHandle: *mut CUarray,
         pAllocateArray: *const CUDA_ARRAY_DESCRIPTOR,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuArrayGetDescriptor_v2(
         pArrayDescriptor: *mut CUDA_ARRAY_DESCRIPTOR,
         hArray: CUarray,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the layout properties of a sparse CUDA array"]
     #[doc = ""]
     #[doc = " Returns the layout properties of a sparse CUDA array in \\p sparseProperties"]
@@ -12900,7 +12443,7 @@ extern "C" {
         array: CUarray,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the layout properties of a sparse CUDA mipmapped array"]
     #[doc = ""]
     #[doc = " Returns the sparse array layout properties in \\p sparseProperties"]
@@ -12927,7 +12470,7 @@ extern "C" {
         mipmap: CUmipmappedArray,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets a CUDA array plane from a CUDA array"]
     #[doc = ""]
     #[doc = " Returns in \\p pPlaneArray a CUDA array that represents a single format plane"]
@@ -12963,7 +12506,7 @@ extern "C" {
         planeIdx: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Destroys a CUDA array"]
     #[doc = ""]
     #[doc = " Destroys the CUDA array \\p hArray."]
@@ -12993,19 +12536,19 @@ extern "C" {
     #[doc = " ::cudaFreeArray"]
     pub fn cuArrayDestroy(hArray: CUarray) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuArray3DCreate_v2(
         pHandle: *mut CUarray,
         pAllocateArray: *const CUDA_ARRAY3D_DESCRIPTOR,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuArray3DGetDescriptor_v2(
         pArrayDescriptor: *mut CUDA_ARRAY3D_DESCRIPTOR,
         hArray: CUarray,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates a CUDA mipmapped array"]
     #[doc = ""]
     #[doc = " Creates a CUDA mipmapped array according to the ::CUDA_ARRAY3D_DESCRIPTOR structure"]
@@ -13150,7 +12693,7 @@ extern "C" {
         numMipmapLevels: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets a mipmap level of a CUDA mipmapped array"]
     #[doc = ""]
     #[doc = " Returns in \\p *pLevelArray a CUDA array that represents a single mipmap level"]
@@ -13183,7 +12726,7 @@ extern "C" {
         level: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Destroys a CUDA mipmapped array"]
     #[doc = ""]
     #[doc = " Destroys the CUDA mipmapped array \\p hMipmappedArray."]
@@ -13207,7 +12750,7 @@ extern "C" {
     #[doc = " ::cudaFreeMipmappedArray"]
     pub fn cuMipmappedArrayDestroy(hMipmappedArray: CUmipmappedArray) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Allocate an address range reservation."]
     #[doc = ""]
     #[doc = " Reserves a virtual address range based on the given parameters, giving"]
@@ -13239,7 +12782,7 @@ extern "C" {
         flags: ::std::os::raw::c_ulonglong,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Free an address range reservation."]
     #[doc = ""]
     #[doc = " Frees a virtual address range reserved by cuMemAddressReserve.  The size"]
@@ -13259,7 +12802,7 @@ extern "C" {
     #[doc = " \\sa ::cuMemAddressReserve"]
     pub fn cuMemAddressFree(ptr: CUdeviceptr, size: size_t) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Create a CUDA memory handle representing a memory allocation of a given size described by the given properties"]
     #[doc = ""]
     #[doc = " This creates a memory allocation on the target device specified through the"]
@@ -13302,7 +12845,7 @@ extern "C" {
         flags: ::std::os::raw::c_ulonglong,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Release a memory handle representing a memory allocation which was previously allocated through cuMemCreate."]
     #[doc = ""]
     #[doc = " Frees the memory that was allocated on a device through cuMemCreate."]
@@ -13328,7 +12871,7 @@ extern "C" {
     #[doc = " \\sa ::cuMemCreate"]
     pub fn cuMemRelease(handle: CUmemGenericAllocationHandle) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Maps an allocation handle to a reserved virtual address range."]
     #[doc = ""]
     #[doc = " Maps bytes of memory represented by \\p handle starting from byte \\p offset to"]
@@ -13377,7 +12920,7 @@ extern "C" {
         flags: ::std::os::raw::c_ulonglong,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Maps or unmaps subregions of sparse CUDA arrays and sparse CUDA mipmapped arrays"]
     #[doc = ""]
     #[doc = " Performs map or unmap operations on subregions of sparse CUDA arrays and sparse CUDA mipmapped arrays."]
@@ -13510,7 +13053,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Unmap the backing memory of a given address range."]
     #[doc = ""]
     #[doc = " The range must be the entire contiguous address range that was mapped to.  In"]
@@ -13538,7 +13081,7 @@ extern "C" {
     #[doc = " \\sa ::cuMemCreate, ::cuMemAddressReserve"]
     pub fn cuMemUnmap(ptr: CUdeviceptr, size: size_t) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Set the access flags for each location specified in \\p desc for the given virtual address range"]
     #[doc = ""]
     #[doc = " Given the virtual address range via \\p ptr and \\p size, and the locations"]
@@ -13567,7 +13110,7 @@ extern "C" {
         count: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Get the access \\p flags set for the given \\p location and \\p ptr"]
     #[doc = ""]
     #[doc = " \\param[out] flags   - Flags set for this location"]
@@ -13589,7 +13132,7 @@ extern "C" {
         ptr: CUdeviceptr,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Exports an allocation to a requested shareable handle type"]
     #[doc = ""]
     #[doc = " Given a CUDA memory handle, create a shareable memory"]
@@ -13626,7 +13169,7 @@ extern "C" {
         flags: ::std::os::raw::c_ulonglong,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Imports an allocation from a requested shareable handle type."]
     #[doc = ""]
     #[doc = " If the current process cannot support the memory described by this shareable"]
@@ -13656,7 +13199,7 @@ extern "C" {
         shHandleType: CUmemAllocationHandleType,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Calculates either the minimal or recommended granularity"]
     #[doc = ""]
     #[doc = " Calculates either the minimal or recommended granularity"]
@@ -13681,7 +13224,7 @@ extern "C" {
         option: CUmemAllocationGranularity_flags,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Retrieve the contents of the property structure defining properties for this handle"]
     #[doc = ""]
     #[doc = " \\param[out] prop  - Pointer to a properties structure which will hold the information about this handle"]
@@ -13700,7 +13243,7 @@ extern "C" {
         handle: CUmemGenericAllocationHandle,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Given an address \\p addr, returns the allocation handle of the backing memory allocation."]
     #[doc = ""]
     #[doc = " The handle is guaranteed to be the same handle value used to map the memory. If the address"]
@@ -13726,7 +13269,7 @@ extern "C" {
         addr: *mut ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Frees memory with stream ordered semantics"]
     #[doc = ""]
     #[doc = " Inserts a free operation into \\p hStream."]
@@ -13744,7 +13287,7 @@ extern "C" {
     #[doc = " ::CUDA_ERROR_NOT_SUPPORTED"]
     pub fn cuMemFreeAsync(dptr: CUdeviceptr, hStream: CUstream) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Allocates memory with stream ordered semantics"]
     #[doc = ""]
     #[doc = " Inserts an allocation operation into \\p hStream."]
@@ -13774,7 +13317,7 @@ extern "C" {
     pub fn cuMemAllocAsync(dptr: *mut CUdeviceptr, bytesize: size_t, hStream: CUstream)
         -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Tries to release memory back to the OS"]
     #[doc = ""]
     #[doc = " Releases memory back to the OS until the pool contains fewer than minBytesToKeep"]
@@ -13799,7 +13342,7 @@ extern "C" {
     #[doc = "     ::cuDeviceGetMemPool, ::cuMemPoolCreate"]
     pub fn cuMemPoolTrimTo(pool: CUmemoryPool, minBytesToKeep: size_t) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets attributes of a memory pool"]
     #[doc = ""]
     #[doc = " Supported attributes are:"]
@@ -13845,7 +13388,7 @@ extern "C" {
         value: *mut ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets attributes of a memory pool"]
     #[doc = ""]
     #[doc = " Supported attributes are:"]
@@ -13895,7 +13438,7 @@ extern "C" {
         value: *mut ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Controls visibility of pools between devices"]
     #[doc = ""]
     #[doc = " \\param[in] pool  - The pool being modified"]
@@ -13915,7 +13458,7 @@ extern "C" {
         count: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the accessibility of a pool from a device"]
     #[doc = ""]
     #[doc = " Returns the accessibility of the pool's memory from the specified location."]
@@ -13932,7 +13475,7 @@ extern "C" {
         location: *mut CUmemLocation,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates a memory pool"]
     #[doc = ""]
     #[doc = " Creates a CUDA memory pool and returns the handle in \\p pool.  The \\p poolProps determines"]
@@ -13953,7 +13496,7 @@ extern "C" {
     #[doc = "     ::cuMemAllocFromPoolAsync, ::cuMemPoolExportToShareableHandle"]
     pub fn cuMemPoolCreate(pool: *mut CUmemoryPool, poolProps: *const CUmemPoolProps) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Destroys the specified memory pool"]
     #[doc = ""]
     #[doc = " If any pointers obtained from this pool haven't been freed or"]
@@ -13975,7 +13518,7 @@ extern "C" {
     #[doc = "     ::cuDeviceGetDefaultMemPool, ::cuMemPoolCreate"]
     pub fn cuMemPoolDestroy(pool: CUmemoryPool) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Allocates memory from a specified pool with stream ordered semantics."]
     #[doc = ""]
     #[doc = " Inserts an allocation operation into \\p hStream."]
@@ -14013,7 +13556,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Exports a memory pool to the requested handle type."]
     #[doc = ""]
     #[doc = " Given an IPC capable mempool, create an OS handle to share the pool with another process."]
@@ -14046,7 +13589,7 @@ extern "C" {
         flags: ::std::os::raw::c_ulonglong,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief imports a memory pool from a shared handle."]
     #[doc = ""]
     #[doc = " Specific allocations can be imported from the imported pool with cuMemPoolImportPointer."]
@@ -14074,7 +13617,7 @@ extern "C" {
         flags: ::std::os::raw::c_ulonglong,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Export data to share a memory pool allocation between processes."]
     #[doc = ""]
     #[doc = " Constructs \\p shareData_out for sharing a specific allocation from an already shared memory pool."]
@@ -14096,7 +13639,7 @@ extern "C" {
         ptr: CUdeviceptr,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Import a memory pool allocation from another process."]
     #[doc = ""]
     #[doc = " Returns in \\p ptr_out a pointer to the imported memory."]
@@ -14128,7 +13671,7 @@ extern "C" {
         shareData: *mut CUmemPoolPtrExportData,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns information about a pointer"]
     #[doc = ""]
     #[doc = " The supported attributes are:"]
@@ -14321,7 +13864,7 @@ extern "C" {
         ptr: CUdeviceptr,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Prefetches memory to the specified destination device"]
     #[doc = ""]
     #[doc = " Prefetches memory to the specified destination device.  \\p devPtr is the"]
@@ -14393,7 +13936,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Advise about the usage of a given memory range"]
     #[doc = ""]
     #[doc = " Advise the Unified Memory subsystem about the usage pattern for the memory range"]
@@ -14511,7 +14054,7 @@ extern "C" {
         device: CUdevice,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Query an attribute of a given memory range"]
     #[doc = ""]
     #[doc = " Query an attribute about the memory range starting at \\p devPtr with a size of \\p count bytes. The"]
@@ -14574,7 +14117,7 @@ extern "C" {
         count: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Query attributes of a given memory range."]
     #[doc = ""]
     #[doc = " Query attributes of the memory range starting at \\p devPtr with a size of \\p count bytes. The"]
@@ -14620,7 +14163,7 @@ extern "C" {
         count: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Set attributes on a previously allocated memory region"]
     #[doc = ""]
     #[doc = " The supported attributes are:"]
@@ -14665,7 +14208,7 @@ extern "C" {
         ptr: CUdeviceptr,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns information about a pointer."]
     #[doc = ""]
     #[doc = " The supported attributes are (refer to ::cuPointerGetAttribute for attribute descriptions and restrictions):"]
@@ -14718,7 +14261,7 @@ extern "C" {
         ptr: CUdeviceptr,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Create a stream"]
     #[doc = ""]
     #[doc = " Creates a stream and returns a handle in \\p phStream.  The \\p Flags argument"]
@@ -14754,7 +14297,7 @@ extern "C" {
     #[doc = " ::cudaStreamCreateWithFlags"]
     pub fn cuStreamCreate(phStream: *mut CUstream, Flags: ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Create a stream with the given priority"]
     #[doc = ""]
     #[doc = " Creates a stream with the specified priority and returns a handle in \\p phStream."]
@@ -14806,7 +14349,7 @@ extern "C" {
         priority: ::std::os::raw::c_int,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Query the priority of a given stream"]
     #[doc = ""]
     #[doc = " Query the priority of a stream created using ::cuStreamCreate or ::cuStreamCreateWithPriority"]
@@ -14836,7 +14379,7 @@ extern "C" {
     pub fn cuStreamGetPriority(hStream: CUstream, priority: *mut ::std::os::raw::c_int)
         -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Query the flags of a given stream"]
     #[doc = ""]
     #[doc = " Query the flags of a stream created using ::cuStreamCreate or ::cuStreamCreateWithPriority"]
@@ -14863,7 +14406,7 @@ extern "C" {
     #[doc = " ::cudaStreamGetFlags"]
     pub fn cuStreamGetFlags(hStream: CUstream, flags: *mut ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Query the context associated with a stream"]
     #[doc = ""]
     #[doc = " Returns the CUDA context that the stream is associated with."]
@@ -14906,7 +14449,7 @@ extern "C" {
     #[doc = " ::cudaStreamCreateWithFlags"]
     pub fn cuStreamGetCtx(hStream: CUstream, pctx: *mut CUcontext) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Make a compute stream wait on an event"]
     #[doc = ""]
     #[doc = " Makes all future work submitted to \\p hStream wait for all work captured in"]
@@ -14946,7 +14489,7 @@ extern "C" {
         Flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Add a callback to a compute stream"]
     #[doc = ""]
     #[doc = " \\note This function is slated for eventual deprecation and removal. If"]
@@ -15025,10 +14568,10 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuStreamBeginCapture_v2(hStream: CUstream, mode: CUstreamCaptureMode) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Swaps the stream capture interaction mode for a thread"]
     #[doc = ""]
     #[doc = " Sets the calling thread's stream capture interaction mode to the value contained"]
@@ -15080,7 +14623,7 @@ extern "C" {
     #[doc = " ::cuStreamBeginCapture"]
     pub fn cuThreadExchangeStreamCaptureMode(mode: *mut CUstreamCaptureMode) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Ends capture on a stream, returning the captured graph"]
     #[doc = ""]
     #[doc = " End capture on \\p hStream, returning the captured graph via \\p phGraph."]
@@ -15109,7 +14652,7 @@ extern "C" {
     #[doc = " ::cuStreamIsCapturing"]
     pub fn cuStreamEndCapture(hStream: CUstream, phGraph: *mut CUgraph) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a stream's capture status"]
     #[doc = ""]
     #[doc = " Return the capture status of \\p hStream via \\p captureStatus. After a successful"]
@@ -15151,7 +14694,7 @@ extern "C" {
         captureStatus: *mut CUstreamCaptureStatus,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Query capture status of a stream"]
     #[doc = ""]
     #[doc = " Note there is a later version of this API, ::cuStreamGetCaptureInfo_v2. It will"]
@@ -15182,7 +14725,7 @@ extern "C" {
         id_out: *mut cuuint64_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Query a stream's capture state (11.3+)"]
     #[doc = ""]
     #[doc = " Query stream state related to stream capture."]
@@ -15242,7 +14785,7 @@ extern "C" {
         numDependencies_out: *mut size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Update the set of dependencies in a capturing stream (11.3+)"]
     #[doc = ""]
     #[doc = " Modifies the dependency set of a capturing stream. The dependency set is the set"]
@@ -15278,7 +14821,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Attach memory to a stream asynchronously"]
     #[doc = ""]
     #[doc = " Enqueues an operation in \\p hStream to specify stream association of"]
@@ -15370,7 +14913,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Determine status of a compute stream"]
     #[doc = ""]
     #[doc = " Returns ::CUDA_SUCCESS if all operations in the stream specified by"]
@@ -15399,7 +14942,7 @@ extern "C" {
     #[doc = " ::cudaStreamQuery"]
     pub fn cuStreamQuery(hStream: CUstream) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Wait until a stream's tasks are completed"]
     #[doc = ""]
     #[doc = " Waits until the device has completed all operations in the stream specified"]
@@ -15427,10 +14970,10 @@ extern "C" {
     #[doc = " ::cudaStreamSynchronize"]
     pub fn cuStreamSynchronize(hStream: CUstream) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuStreamDestroy_v2(hStream: CUstream) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Copies attributes from source stream to destination stream."]
     #[doc = ""]
     #[doc = " Copies attributes from source stream \\p src to destination stream \\p dst."]
@@ -15449,7 +14992,7 @@ extern "C" {
     #[doc = " ::CUaccessPolicyWindow"]
     pub fn cuStreamCopyAttributes(dst: CUstream, src: CUstream) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Queries stream attribute."]
     #[doc = ""]
     #[doc = " Queries attribute \\p attr from \\p hStream and stores it in corresponding"]
@@ -15473,7 +15016,7 @@ extern "C" {
         value_out: *mut CUstreamAttrValue,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets stream attribute."]
     #[doc = ""]
     #[doc = " Sets attribute \\p attr on \\p hStream from corresponding attribute of"]
@@ -15498,7 +15041,7 @@ extern "C" {
         value: *const CUstreamAttrValue,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates an event"]
     #[doc = ""]
     #[doc = " Creates an event *phEvent for the current context with the flags specified via"]
@@ -15538,7 +15081,7 @@ extern "C" {
     #[doc = " ::cudaEventCreateWithFlags"]
     pub fn cuEventCreate(phEvent: *mut CUevent, Flags: ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Records an event"]
     #[doc = ""]
     #[doc = " Captures in \\p hEvent the contents of \\p hStream at the time of this call."]
@@ -15579,7 +15122,7 @@ extern "C" {
     #[doc = " ::cuEventRecordWithFlags"]
     pub fn cuEventRecord(hEvent: CUevent, hStream: CUstream) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Records an event"]
     #[doc = ""]
     #[doc = " Captures in \\p hEvent the contents of \\p hStream at the time of this call."]
@@ -15631,7 +15174,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Queries an event's status"]
     #[doc = ""]
     #[doc = " Queries the status of all work currently captured by \\p hEvent. See"]
@@ -15662,7 +15205,7 @@ extern "C" {
     #[doc = " ::cudaEventQuery"]
     pub fn cuEventQuery(hEvent: CUevent) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Waits for an event to complete"]
     #[doc = ""]
     #[doc = " Waits until the completion of all work currently captured in \\p hEvent."]
@@ -15692,10 +15235,10 @@ extern "C" {
     #[doc = " ::cudaEventSynchronize"]
     pub fn cuEventSynchronize(hEvent: CUevent) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuEventDestroy_v2(hEvent: CUevent) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Computes the elapsed time between two events"]
     #[doc = ""]
     #[doc = " Computes the elapsed time between two events (in milliseconds with a"]
@@ -15738,7 +15281,7 @@ extern "C" {
     #[doc = " ::cudaEventElapsedTime"]
     pub fn cuEventElapsedTime(pMilliseconds: *mut f32, hStart: CUevent, hEnd: CUevent) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Imports an external memory object"]
     #[doc = ""]
     #[doc = " Imports an externally allocated memory object and returns"]
@@ -15900,7 +15443,7 @@ extern "C" {
         memHandleDesc: *const CUDA_EXTERNAL_MEMORY_HANDLE_DESC,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Maps a buffer onto an imported memory object"]
     #[doc = ""]
     #[doc = " Maps a buffer onto an imported memory object and returns a device"]
@@ -15956,7 +15499,7 @@ extern "C" {
         bufferDesc: *const CUDA_EXTERNAL_MEMORY_BUFFER_DESC,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Maps a CUDA mipmapped array onto an external memory object"]
     #[doc = ""]
     #[doc = " Maps a CUDA mipmapped array onto an external object and returns a"]
@@ -16011,7 +15554,7 @@ extern "C" {
         mipmapDesc: *const CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Destroys an external memory object."]
     #[doc = ""]
     #[doc = " Destroys the specified external memory object. Any existing buffers"]
@@ -16032,7 +15575,7 @@ extern "C" {
     #[doc = " ::cuExternalMemoryGetMappedMipmappedArray"]
     pub fn cuDestroyExternalMemory(extMem: CUexternalMemory) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Imports an external semaphore"]
     #[doc = ""]
     #[doc = " Imports an externally allocated synchronization object and returns"]
@@ -16191,7 +15734,7 @@ extern "C" {
         semHandleDesc: *const CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Signals a set of external semaphore objects"]
     #[doc = ""]
     #[doc = " Enqueues a signal operation on a set of externally allocated"]
@@ -16262,7 +15805,7 @@ extern "C" {
         stream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Waits on a set of external semaphore objects"]
     #[doc = ""]
     #[doc = " Enqueues a wait operation on a set of externally allocated"]
@@ -16342,7 +15885,7 @@ extern "C" {
         stream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Destroys an external semaphore"]
     #[doc = ""]
     #[doc = " Destroys an external semaphore object and releases any references"]
@@ -16362,7 +15905,7 @@ extern "C" {
     #[doc = " ::cuWaitExternalSemaphoresAsync"]
     pub fn cuDestroyExternalSemaphore(extSem: CUexternalSemaphore) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Wait on a memory location"]
     #[doc = ""]
     #[doc = " Enqueues a synchronization of the stream on the given memory location. Work"]
@@ -16405,7 +15948,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Wait on a memory location"]
     #[doc = ""]
     #[doc = " Enqueues a synchronization of the stream on the given memory location. Work"]
@@ -16444,7 +15987,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Write a value to memory"]
     #[doc = ""]
     #[doc = " Write a value to memory. Unless the ::CU_STREAM_WRITE_VALUE_NO_MEMORY_BARRIER"]
@@ -16483,7 +16026,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Write a value to memory"]
     #[doc = ""]
     #[doc = " Write a value to memory. Unless the ::CU_STREAM_WRITE_VALUE_NO_MEMORY_BARRIER"]
@@ -16521,7 +16064,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Batch operations to synchronize the stream via memory operations"]
     #[doc = ""]
     #[doc = " This is a batch version of ::cuStreamWaitValue32() and ::cuStreamWriteValue32()."]
@@ -16560,7 +16103,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns information about a function"]
     #[doc = ""]
     #[doc = " Returns in \\p *pi the integer value of the attribute \\p attrib on the kernel"]
@@ -16622,7 +16165,7 @@ extern "C" {
         hfunc: CUfunction,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets information about a function"]
     #[doc = ""]
     #[doc = " This call sets the value of a specified attribute \\p attrib on the kernel given"]
@@ -16671,7 +16214,7 @@ extern "C" {
         value: ::std::os::raw::c_int,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the preferred cache configuration for a device function"]
     #[doc = ""]
     #[doc = " On devices where the L1 cache and shared memory use the same hardware"]
@@ -16714,7 +16257,7 @@ extern "C" {
     #[doc = " ::cudaFuncSetCacheConfig"]
     pub fn cuFuncSetCacheConfig(hfunc: CUfunction, config: CUfunc_cache) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the shared memory configuration for a device function."]
     #[doc = ""]
     #[doc = " On devices with configurable shared memory banks, this function will"]
@@ -16765,7 +16308,7 @@ extern "C" {
     #[doc = " ::cudaFuncSetSharedMemConfig"]
     pub fn cuFuncSetSharedMemConfig(hfunc: CUfunction, config: CUsharedconfig) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a module handle"]
     #[doc = ""]
     #[doc = " Returns in \\p *hmod the handle of the module that function \\p hfunc"]
@@ -16790,7 +16333,7 @@ extern "C" {
     #[doc = ""]
     pub fn cuFuncGetModule(hmod: *mut CUmodule, hfunc: CUfunction) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Launches a CUDA function"]
     #[doc = ""]
     #[doc = " Invokes the kernel \\p f on a \\p gridDimX x \\p gridDimY x \\p gridDimZ"]
@@ -16909,7 +16452,7 @@ extern "C" {
         extra: *mut *mut ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Launches a CUDA function where thread blocks can cooperate and synchronize as they execute"]
     #[doc = ""]
     #[doc = " Invokes the kernel \\p f on a \\p gridDimX x \\p gridDimY x \\p gridDimZ"]
@@ -16997,7 +16540,7 @@ extern "C" {
         kernelParams: *mut *mut ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Launches CUDA functions on multiple devices where thread blocks can cooperate and synchronize as they execute"]
     #[doc = ""]
     #[doc = " \\deprecated This function is deprecated as of CUDA 11.3."]
@@ -17137,7 +16680,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Enqueues a host function call in a stream"]
     #[doc = ""]
     #[doc = " Enqueues a host function to run in a stream.  The function will be called"]
@@ -17205,7 +16748,7 @@ extern "C" {
         userData: *mut ::std::os::raw::c_void,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the block-dimensions for the function"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -17245,7 +16788,7 @@ extern "C" {
         z: ::std::os::raw::c_int,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the dynamic shared-memory size for the function"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -17278,7 +16821,7 @@ extern "C" {
     #[doc = " ::cuLaunchKernel"]
     pub fn cuFuncSetSharedSize(hfunc: CUfunction, bytes: ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the parameter size for the function"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -17309,7 +16852,7 @@ extern "C" {
     #[doc = " ::cuLaunchKernel"]
     pub fn cuParamSetSize(hfunc: CUfunction, numbytes: ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Adds an integer parameter to the function's argument list"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -17345,7 +16888,7 @@ extern "C" {
         value: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Adds a floating-point parameter to the function's argument list"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -17377,7 +16920,7 @@ extern "C" {
     #[doc = " ::cuLaunchKernel"]
     pub fn cuParamSetf(hfunc: CUfunction, offset: ::std::os::raw::c_int, value: f32) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Adds arbitrary data to the function's argument list"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -17416,7 +16959,7 @@ extern "C" {
         numbytes: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Launches a CUDA function"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -17467,7 +17010,7 @@ extern "C" {
     #[doc = " ::cuLaunchKernel"]
     pub fn cuLaunch(f: CUfunction) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Launches a CUDA function"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -17524,7 +17067,7 @@ extern "C" {
         grid_height: ::std::os::raw::c_int,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Launches a CUDA function"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -17590,7 +17133,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Adds a texture-reference to the function's argument list"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -17617,7 +17160,7 @@ extern "C" {
         hTexRef: CUtexref,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates a graph"]
     #[doc = ""]
     #[doc = " Creates an empty graph, which is returned via \\p phGraph."]
@@ -17649,7 +17192,7 @@ extern "C" {
     #[doc = " ::cuGraphClone"]
     pub fn cuGraphCreate(phGraph: *mut CUgraph, flags: ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates a kernel execution node and adds it to a graph"]
     #[doc = ""]
     #[doc = " Creates a new kernel execution node and adds it to \\p hGraph with \\p numDependencies"]
@@ -17754,7 +17297,7 @@ extern "C" {
         nodeParams: *const CUDA_KERNEL_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a kernel node's parameters"]
     #[doc = ""]
     #[doc = " Returns the parameters of kernel node \\p hNode in \\p nodeParams."]
@@ -17788,7 +17331,7 @@ extern "C" {
         nodeParams: *mut CUDA_KERNEL_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets a kernel node's parameters"]
     #[doc = ""]
     #[doc = " Sets the parameters of kernel node \\p hNode to \\p nodeParams."]
@@ -17813,7 +17356,7 @@ extern "C" {
         nodeParams: *const CUDA_KERNEL_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates a memcpy node and adds it to a graph"]
     #[doc = ""]
     #[doc = " Creates a new memcpy node and adds it to \\p hGraph with \\p numDependencies"]
@@ -17867,7 +17410,7 @@ extern "C" {
         ctx: CUcontext,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a memcpy node's parameters"]
     #[doc = ""]
     #[doc = " Returns the parameters of memcpy node \\p hNode in \\p nodeParams."]
@@ -17892,7 +17435,7 @@ extern "C" {
         nodeParams: *mut CUDA_MEMCPY3D,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets a memcpy node's parameters"]
     #[doc = ""]
     #[doc = " Sets the parameters of memcpy node \\p hNode to \\p nodeParams."]
@@ -17917,7 +17460,7 @@ extern "C" {
         nodeParams: *const CUDA_MEMCPY3D,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates a memset node and adds it to a graph"]
     #[doc = ""]
     #[doc = " Creates a new memset node and adds it to \\p hGraph with \\p numDependencies"]
@@ -17965,7 +17508,7 @@ extern "C" {
         ctx: CUcontext,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a memset node's parameters"]
     #[doc = ""]
     #[doc = " Returns the parameters of memset node \\p hNode in \\p nodeParams."]
@@ -17990,7 +17533,7 @@ extern "C" {
         nodeParams: *mut CUDA_MEMSET_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets a memset node's parameters"]
     #[doc = ""]
     #[doc = " Sets the parameters of memset node \\p hNode to \\p nodeParams."]
@@ -18015,7 +17558,7 @@ extern "C" {
         nodeParams: *const CUDA_MEMSET_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates a host execution node and adds it to a graph"]
     #[doc = ""]
     #[doc = " Creates a new CPU execution node and adds it to \\p hGraph with \\p numDependencies"]
@@ -18061,7 +17604,7 @@ extern "C" {
         nodeParams: *const CUDA_HOST_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a host node's parameters"]
     #[doc = ""]
     #[doc = " Returns the parameters of host node \\p hNode in \\p nodeParams."]
@@ -18086,7 +17629,7 @@ extern "C" {
         nodeParams: *mut CUDA_HOST_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets a host node's parameters"]
     #[doc = ""]
     #[doc = " Sets the parameters of host node \\p hNode to \\p nodeParams."]
@@ -18111,7 +17654,7 @@ extern "C" {
         nodeParams: *const CUDA_HOST_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates a child graph node and adds it to a graph"]
     #[doc = ""]
     #[doc = " Creates a new node which executes an embedded graph, and adds it to \\p hGraph with"]
@@ -18154,7 +17697,7 @@ extern "C" {
         childGraph: CUgraph,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets a handle to the embedded graph of a child graph node"]
     #[doc = ""]
     #[doc = " Gets a handle to the embedded graph in a child graph node. This call"]
@@ -18177,7 +17720,7 @@ extern "C" {
     #[doc = " ::cuGraphNodeFindInClone"]
     pub fn cuGraphChildGraphNodeGetGraph(hNode: CUgraphNode, phGraph: *mut CUgraph) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates an empty node and adds it to a graph"]
     #[doc = ""]
     #[doc = " Creates a new node which performs no operation, and adds it to \\p hGraph with"]
@@ -18219,7 +17762,7 @@ extern "C" {
         numDependencies: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates an event record node and adds it to a graph"]
     #[doc = ""]
     #[doc = " Creates a new event record node and adds it to \\p hGraph with \\p numDependencies"]
@@ -18265,7 +17808,7 @@ extern "C" {
         event: CUevent,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the event associated with an event record node"]
     #[doc = ""]
     #[doc = " Returns the event of event record node \\p hNode in \\p event_out."]
@@ -18289,7 +17832,7 @@ extern "C" {
     #[doc = " ::cuStreamWaitEvent"]
     pub fn cuGraphEventRecordNodeGetEvent(hNode: CUgraphNode, event_out: *mut CUevent) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets an event record node's event"]
     #[doc = ""]
     #[doc = " Sets the event of event record node \\p hNode to \\p event."]
@@ -18313,7 +17856,7 @@ extern "C" {
     #[doc = " ::cuStreamWaitEvent"]
     pub fn cuGraphEventRecordNodeSetEvent(hNode: CUgraphNode, event: CUevent) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates an event wait node and adds it to a graph"]
     #[doc = ""]
     #[doc = " Creates a new event wait node and adds it to \\p hGraph with \\p numDependencies"]
@@ -18360,7 +17903,7 @@ extern "C" {
         event: CUevent,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the event associated with an event wait node"]
     #[doc = ""]
     #[doc = " Returns the event of event wait node \\p hNode in \\p event_out."]
@@ -18384,7 +17927,7 @@ extern "C" {
     #[doc = " ::cuStreamWaitEvent"]
     pub fn cuGraphEventWaitNodeGetEvent(hNode: CUgraphNode, event_out: *mut CUevent) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets an event wait node's event"]
     #[doc = ""]
     #[doc = " Sets the event of event wait node \\p hNode to \\p event."]
@@ -18408,7 +17951,7 @@ extern "C" {
     #[doc = " ::cuStreamWaitEvent"]
     pub fn cuGraphEventWaitNodeSetEvent(hNode: CUgraphNode, event: CUevent) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates an external semaphore signal node and adds it to a graph"]
     #[doc = ""]
     #[doc = " Creates a new external semaphore signal node and adds it to \\p hGraph with \\p"]
@@ -18461,7 +18004,7 @@ extern "C" {
         nodeParams: *const CUDA_EXT_SEM_SIGNAL_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns an external semaphore signal node's parameters"]
     #[doc = ""]
     #[doc = " Returns the parameters of an external semaphore signal node \\p hNode in \\p params_out."]
@@ -18494,7 +18037,7 @@ extern "C" {
         params_out: *mut CUDA_EXT_SEM_SIGNAL_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets an external semaphore signal node's parameters"]
     #[doc = ""]
     #[doc = " Sets the parameters of an external semaphore signal node \\p hNode to \\p nodeParams."]
@@ -18521,7 +18064,7 @@ extern "C" {
         nodeParams: *const CUDA_EXT_SEM_SIGNAL_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates an external semaphore wait node and adds it to a graph"]
     #[doc = ""]
     #[doc = " Creates a new external semaphore wait node and adds it to \\p hGraph with \\p numDependencies"]
@@ -18574,7 +18117,7 @@ extern "C" {
         nodeParams: *const CUDA_EXT_SEM_WAIT_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns an external semaphore wait node's parameters"]
     #[doc = ""]
     #[doc = " Returns the parameters of an external semaphore wait node \\p hNode in \\p params_out."]
@@ -18607,7 +18150,7 @@ extern "C" {
         params_out: *mut CUDA_EXT_SEM_WAIT_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets an external semaphore wait node's parameters"]
     #[doc = ""]
     #[doc = " Sets the parameters of an external semaphore wait node \\p hNode to \\p nodeParams."]
@@ -18634,7 +18177,7 @@ extern "C" {
         nodeParams: *const CUDA_EXT_SEM_WAIT_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Clones a graph"]
     #[doc = ""]
     #[doc = " This function creates a copy of \\p originalGraph and returns it in \\p phGraphClone."]
@@ -18658,7 +18201,7 @@ extern "C" {
     #[doc = " ::cuGraphNodeFindInClone"]
     pub fn cuGraphClone(phGraphClone: *mut CUgraph, originalGraph: CUgraph) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Finds a cloned version of a node"]
     #[doc = ""]
     #[doc = " This function returns the node in \\p hClonedGraph corresponding to \\p hOriginalNode"]
@@ -18687,7 +18230,7 @@ extern "C" {
         hClonedGraph: CUgraph,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a node's type"]
     #[doc = ""]
     #[doc = " Returns the node type of \\p hNode in \\p type."]
@@ -18717,7 +18260,7 @@ extern "C" {
     #[doc = " ::cuGraphMemsetNodeSetParams"]
     pub fn cuGraphNodeGetType(hNode: CUgraphNode, type_: *mut CUgraphNodeType) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a graph's nodes"]
     #[doc = ""]
     #[doc = " Returns a list of \\p hGraph's nodes. \\p nodes may be NULL, in which case this"]
@@ -18751,7 +18294,7 @@ extern "C" {
         numNodes: *mut size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a graph's root nodes"]
     #[doc = ""]
     #[doc = " Returns a list of \\p hGraph's root nodes. \\p rootNodes may be NULL, in which case this"]
@@ -18785,7 +18328,7 @@ extern "C" {
         numRootNodes: *mut size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a graph's dependency edges"]
     #[doc = ""]
     #[doc = " Returns a list of \\p hGraph's dependency edges. Edges are returned via corresponding"]
@@ -18823,7 +18366,7 @@ extern "C" {
         numEdges: *mut size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a node's dependencies"]
     #[doc = ""]
     #[doc = " Returns a list of \\p node's dependencies. \\p dependencies may be NULL, in which case this"]
@@ -18857,7 +18400,7 @@ extern "C" {
         numDependencies: *mut size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a node's dependent nodes"]
     #[doc = ""]
     #[doc = " Returns a list of \\p node's dependent nodes. \\p dependentNodes may be NULL, in which"]
@@ -18892,7 +18435,7 @@ extern "C" {
         numDependentNodes: *mut size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Adds dependency edges to a graph"]
     #[doc = ""]
     #[doc = " The number of dependencies to be added is defined by \\p numDependencies"]
@@ -18925,7 +18468,7 @@ extern "C" {
         numDependencies: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Removes dependency edges from a graph"]
     #[doc = ""]
     #[doc = " The number of \\p dependencies to be removed is defined by \\p numDependencies."]
@@ -18958,7 +18501,7 @@ extern "C" {
         numDependencies: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Remove a node from the graph"]
     #[doc = ""]
     #[doc = " Removes \\p hNode from its graph. This operation also severs any dependencies of other nodes"]
@@ -18981,7 +18524,7 @@ extern "C" {
     #[doc = " ::cuGraphAddMemsetNode"]
     pub fn cuGraphDestroyNode(hNode: CUgraphNode) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuGraphInstantiate_v2(
         phGraphExec: *mut CUgraphExec,
         hGraph: CUgraph,
@@ -18990,7 +18533,7 @@ extern "C" {
         bufferSize: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the parameters for a kernel node in the given graphExec"]
     #[doc = ""]
     #[doc = " Sets the parameters of a kernel node in an executable graph \\p hGraphExec."]
@@ -19034,7 +18577,7 @@ extern "C" {
         nodeParams: *const CUDA_KERNEL_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the parameters for a memcpy node in the given graphExec."]
     #[doc = ""]
     #[doc = " Updates the work represented by \\p hNode in \\p hGraphExec as though \\p hNode had"]
@@ -19084,7 +18627,7 @@ extern "C" {
         ctx: CUcontext,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the parameters for a memset node in the given graphExec."]
     #[doc = ""]
     #[doc = " Updates the work represented by \\p hNode in \\p hGraphExec as though \\p hNode had"]
@@ -19134,7 +18677,7 @@ extern "C" {
         ctx: CUcontext,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the parameters for a host node in the given graphExec."]
     #[doc = ""]
     #[doc = " Updates the work represented by \\p hNode in \\p hGraphExec as though \\p hNode had"]
@@ -19174,7 +18717,7 @@ extern "C" {
         nodeParams: *const CUDA_HOST_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Updates node parameters in the child graph node in the given graphExec."]
     #[doc = ""]
     #[doc = " Updates the work represented by \\p hNode in \\p hGraphExec as though the nodes contained"]
@@ -19220,7 +18763,7 @@ extern "C" {
         childGraph: CUgraph,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the event for an event record node in the given graphExec"]
     #[doc = ""]
     #[doc = " Sets the event of an event record node in an executable graph \\p hGraphExec."]
@@ -19263,7 +18806,7 @@ extern "C" {
         event: CUevent,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the event for an event wait node in the given graphExec"]
     #[doc = ""]
     #[doc = " Sets the event of an event wait node in an executable graph \\p hGraphExec."]
@@ -19306,7 +18849,7 @@ extern "C" {
         event: CUevent,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the parameters for an external semaphore signal node in the given graphExec"]
     #[doc = ""]
     #[doc = " Sets the parameters of an external semaphore signal node in an executable graph \\p hGraphExec."]
@@ -19352,7 +18895,7 @@ extern "C" {
         nodeParams: *const CUDA_EXT_SEM_SIGNAL_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the parameters for an external semaphore wait node in the given graphExec"]
     #[doc = ""]
     #[doc = " Sets the parameters of an external semaphore wait node in an executable graph \\p hGraphExec."]
@@ -19398,7 +18941,7 @@ extern "C" {
         nodeParams: *const CUDA_EXT_SEM_WAIT_NODE_PARAMS,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Uploads an executable graph in a stream"]
     #[doc = ""]
     #[doc = " Uploads \\p hGraphExec to the device in \\p hStream without executing it. Uploads of"]
@@ -19422,7 +18965,7 @@ extern "C" {
     #[doc = " ::cuGraphExecDestroy"]
     pub fn cuGraphUpload(hGraphExec: CUgraphExec, hStream: CUstream) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Launches an executable graph in a stream"]
     #[doc = ""]
     #[doc = " Executes \\p hGraphExec in \\p hStream. Only one instance of \\p hGraphExec may be executing"]
@@ -19447,7 +18990,7 @@ extern "C" {
     #[doc = " ::cuGraphExecDestroy"]
     pub fn cuGraphLaunch(hGraphExec: CUgraphExec, hStream: CUstream) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Destroys an executable graph"]
     #[doc = ""]
     #[doc = " Destroys the executable graph specified by \\p hGraphExec, as well"]
@@ -19471,7 +19014,7 @@ extern "C" {
     #[doc = " ::cuGraphLaunch"]
     pub fn cuGraphExecDestroy(hGraphExec: CUgraphExec) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Destroys a graph"]
     #[doc = ""]
     #[doc = " Destroys the graph specified by \\p hGraph, as well as all of its nodes."]
@@ -19490,7 +19033,7 @@ extern "C" {
     #[doc = " ::cuGraphCreate"]
     pub fn cuGraphDestroy(hGraph: CUgraph) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Check whether an executable graph can be updated with a graph and perform the update if possible"]
     #[doc = ""]
     #[doc = " Updates the node parameters in the instantiated graph specified by \\p hGraphExec with the"]
@@ -19567,7 +19110,7 @@ extern "C" {
         updateResult_out: *mut CUgraphExecUpdateResult,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Copies attributes from source node to destination node."]
     #[doc = ""]
     #[doc = " Copies attributes from source node \\p src to destination node \\p dst."]
@@ -19586,7 +19129,7 @@ extern "C" {
     #[doc = " ::CUaccessPolicyWindow"]
     pub fn cuGraphKernelNodeCopyAttributes(dst: CUgraphNode, src: CUgraphNode) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Queries node attribute."]
     #[doc = ""]
     #[doc = " Queries attribute \\p attr from node \\p hNode and stores it in corresponding"]
@@ -19610,7 +19153,7 @@ extern "C" {
         value_out: *mut CUkernelNodeAttrValue,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets node attribute."]
     #[doc = ""]
     #[doc = " Sets attribute \\p attr on node \\p hNode from corresponding attribute of"]
@@ -19634,7 +19177,7 @@ extern "C" {
         value: *const CUkernelNodeAttrValue,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Write a DOT file describing graph structure"]
     #[doc = ""]
     #[doc = " Using the provided \\p hGraph, write to \\p path a DOT formatted description of the graph."]
@@ -19656,7 +19199,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Create a user object"]
     #[doc = ""]
     #[doc = " Create a user object with the specified destructor callback and initial reference count. The"]
@@ -19697,7 +19240,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Retain a reference to a user object"]
     #[doc = ""]
     #[doc = " Retains new references to a user object. The new references are owned by the caller."]
@@ -19720,7 +19263,7 @@ extern "C" {
     #[doc = " ::cuGraphCreate"]
     pub fn cuUserObjectRetain(object: CUuserObject, count: ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Release a reference to a user object"]
     #[doc = ""]
     #[doc = " Releases user object references owned by the caller. The object's destructor is invoked if"]
@@ -19747,7 +19290,7 @@ extern "C" {
     #[doc = " ::cuGraphCreate"]
     pub fn cuUserObjectRelease(object: CUuserObject, count: ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Retain a reference to a user object from a graph"]
     #[doc = ""]
     #[doc = " Creates or moves user object references that will be owned by a CUDA graph."]
@@ -19779,7 +19322,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Release a user object reference from a graph"]
     #[doc = ""]
     #[doc = " Releases user object references owned by a graph."]
@@ -19807,7 +19350,7 @@ extern "C" {
         count: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns occupancy of a function"]
     #[doc = ""]
     #[doc = " Returns in \\p *numBlocks the number of the maximum active blocks per"]
@@ -19836,7 +19379,7 @@ extern "C" {
         dynamicSMemSize: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns occupancy of a function"]
     #[doc = ""]
     #[doc = " Returns in \\p *numBlocks the number of the maximum active blocks per"]
@@ -19883,7 +19426,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Suggest a launch configuration with reasonable occupancy"]
     #[doc = ""]
     #[doc = " Returns in \\p *blockSize a reasonable block size that can achieve"]
@@ -19941,7 +19484,7 @@ extern "C" {
         blockSizeLimit: ::std::os::raw::c_int,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Suggest a launch configuration with reasonable occupancy"]
     #[doc = ""]
     #[doc = " An extended version of ::cuOccupancyMaxPotentialBlockSize. In"]
@@ -19994,7 +19537,7 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns dynamic shared memory available per block when launching \\p numBlocks blocks on SM"]
     #[doc = ""]
     #[doc = " Returns in \\p *dynamicSmemSize the maximum size of dynamic shared memory to allow \\p numBlocks blocks per SM."]
@@ -20021,7 +19564,7 @@ extern "C" {
         blockSize: ::std::os::raw::c_int,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Binds an array as a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20055,7 +19598,7 @@ extern "C" {
         Flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Binds a mipmapped array to a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20088,7 +19631,7 @@ extern "C" {
         Flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuTexRefSetAddress_v2(
         ByteOffset: *mut size_t,
         hTexRef: CUtexref,
@@ -20096,7 +19639,7 @@ extern "C" {
         bytes: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuTexRefSetAddress2D_v3(
         hTexRef: CUtexref,
         desc: *const CUDA_ARRAY_DESCRIPTOR,
@@ -20104,7 +19647,7 @@ extern "C" {
         Pitch: size_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the format for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20142,7 +19685,7 @@ extern "C" {
         NumPackedComponents: ::std::os::raw::c_int,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the addressing mode for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20191,7 +19734,7 @@ extern "C" {
         am: CUaddress_mode,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the filtering mode for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20226,7 +19769,7 @@ extern "C" {
     #[doc = " ::cudaBindTextureToArray"]
     pub fn cuTexRefSetFilterMode(hTexRef: CUtexref, fm: CUfilter_mode) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the mipmap filtering mode for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20261,7 +19804,7 @@ extern "C" {
     #[doc = " ::cudaBindTextureToMipmappedArray"]
     pub fn cuTexRefSetMipmapFilterMode(hTexRef: CUtexref, fm: CUfilter_mode) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the mipmap level bias for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20289,7 +19832,7 @@ extern "C" {
     #[doc = " ::cudaBindTextureToMipmappedArray"]
     pub fn cuTexRefSetMipmapLevelBias(hTexRef: CUtexref, bias: f32) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the mipmap min/max mipmap level clamps for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20323,7 +19866,7 @@ extern "C" {
         maxMipmapLevelClamp: f32,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the maximum anisotropy for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20355,7 +19898,7 @@ extern "C" {
         maxAniso: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the border color for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20390,7 +19933,7 @@ extern "C" {
     #[doc = " ::cudaBindTextureToMipmappedArray"]
     pub fn cuTexRefSetBorderColor(hTexRef: CUtexref, pBorderColor: *mut f32) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the flags for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20434,10 +19977,10 @@ extern "C" {
     #[doc = " ::cudaBindTextureToMipmappedArray"]
     pub fn cuTexRefSetFlags(hTexRef: CUtexref, Flags: ::std::os::raw::c_uint) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuTexRefGetAddress_v2(pdptr: *mut CUdeviceptr, hTexRef: CUtexref) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the array bound to a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20463,7 +20006,7 @@ extern "C" {
     #[doc = " ::cuTexRefGetFilterMode, ::cuTexRefGetFlags, ::cuTexRefGetFormat"]
     pub fn cuTexRefGetArray(phArray: *mut CUarray, hTexRef: CUtexref) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the mipmapped array bound to a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20492,7 +20035,7 @@ extern "C" {
         hTexRef: CUtexref,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the addressing mode used by a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20523,7 +20066,7 @@ extern "C" {
         dim: ::std::os::raw::c_int,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the filter-mode used by a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20548,7 +20091,7 @@ extern "C" {
     #[doc = " ::cuTexRefGetFlags, ::cuTexRefGetFormat"]
     pub fn cuTexRefGetFilterMode(pfm: *mut CUfilter_mode, hTexRef: CUtexref) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the format used by a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20579,7 +20122,7 @@ extern "C" {
         hTexRef: CUtexref,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the mipmap filtering mode for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20604,7 +20147,7 @@ extern "C" {
     #[doc = " ::cuTexRefGetFilterMode, ::cuTexRefGetFlags, ::cuTexRefGetFormat"]
     pub fn cuTexRefGetMipmapFilterMode(pfm: *mut CUfilter_mode, hTexRef: CUtexref) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the mipmap level bias for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20629,7 +20172,7 @@ extern "C" {
     #[doc = " ::cuTexRefGetFilterMode, ::cuTexRefGetFlags, ::cuTexRefGetFormat"]
     pub fn cuTexRefGetMipmapLevelBias(pbias: *mut f32, hTexRef: CUtexref) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the min/max mipmap level clamps for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20659,7 +20202,7 @@ extern "C" {
         hTexRef: CUtexref,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the maximum anisotropy for a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20687,7 +20230,7 @@ extern "C" {
         hTexRef: CUtexref,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the border color used by a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20715,7 +20258,7 @@ extern "C" {
     #[doc = " ::cuTexRefSetAddressMode, ::cuTexRefSetBorderColor"]
     pub fn cuTexRefGetBorderColor(pBorderColor: *mut f32, hTexRef: CUtexref) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Gets the flags used by a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20739,7 +20282,7 @@ extern "C" {
     #[doc = " ::cuTexRefGetFilterMode, ::cuTexRefGetFormat"]
     pub fn cuTexRefGetFlags(pFlags: *mut ::std::os::raw::c_uint, hTexRef: CUtexref) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20763,7 +20306,7 @@ extern "C" {
     #[doc = " \\sa ::cuTexRefDestroy"]
     pub fn cuTexRefCreate(pTexRef: *mut CUtexref) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Destroys a texture reference"]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20782,7 +20325,7 @@ extern "C" {
     #[doc = " \\sa ::cuTexRefCreate"]
     pub fn cuTexRefDestroy(hTexRef: CUtexref) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Sets the CUDA array for a surface reference."]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20814,7 +20357,7 @@ extern "C" {
         Flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Passes back the CUDA array bound to a surface reference."]
     #[doc = ""]
     #[doc = " \\deprecated"]
@@ -20836,7 +20379,7 @@ extern "C" {
     #[doc = " \\sa ::cuModuleGetSurfRef, ::cuSurfRefSetArray"]
     pub fn cuSurfRefGetArray(phArray: *mut CUarray, hSurfRef: CUsurfref) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates a texture object"]
     #[doc = ""]
     #[doc = " Creates a texture object and returns it in \\p pTexObject. \\p pResDesc describes"]
@@ -21056,7 +20599,7 @@ extern "C" {
         pResViewDesc: *const CUDA_RESOURCE_VIEW_DESC,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Destroys a texture object"]
     #[doc = ""]
     #[doc = " Destroys the texture object specified by \\p texObject."]
@@ -21075,7 +20618,7 @@ extern "C" {
     #[doc = " ::cudaDestroyTextureObject"]
     pub fn cuTexObjectDestroy(texObject: CUtexObject) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a texture object's resource descriptor"]
     #[doc = ""]
     #[doc = " Returns the resource descriptor for the texture object specified by \\p texObject."]
@@ -21098,7 +20641,7 @@ extern "C" {
         texObject: CUtexObject,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a texture object's texture descriptor"]
     #[doc = ""]
     #[doc = " Returns the texture descriptor for the texture object specified by \\p texObject."]
@@ -21121,7 +20664,7 @@ extern "C" {
         texObject: CUtexObject,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a texture object's resource view descriptor"]
     #[doc = ""]
     #[doc = " Returns the resource view descriptor for the texture object specified by \\p texObject."]
@@ -21145,7 +20688,7 @@ extern "C" {
         texObject: CUtexObject,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Creates a surface object"]
     #[doc = ""]
     #[doc = " Creates a surface object and returns it in \\p pSurfObject. \\p pResDesc describes"]
@@ -21175,7 +20718,7 @@ extern "C" {
         pResDesc: *const CUDA_RESOURCE_DESC,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Destroys a surface object"]
     #[doc = ""]
     #[doc = " Destroys the surface object specified by \\p surfObject."]
@@ -21194,7 +20737,7 @@ extern "C" {
     #[doc = " ::cudaDestroySurfaceObject"]
     pub fn cuSurfObjectDestroy(surfObject: CUsurfObject) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns a surface object's resource descriptor"]
     #[doc = ""]
     #[doc = " Returns the resource descriptor for the surface object specified by \\p surfObject."]
@@ -21217,7 +20760,7 @@ extern "C" {
         surfObject: CUsurfObject,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Queries if a device may directly access a peer device's memory."]
     #[doc = ""]
     #[doc = " Returns in \\p *canAccessPeer a value of 1 if contexts on \\p dev are capable of"]
@@ -21248,7 +20791,7 @@ extern "C" {
         peerDev: CUdevice,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Enables direct access to memory allocations in a peer context."]
     #[doc = ""]
     #[doc = " If both the current context and \\p peerContext are on devices which support unified"]
@@ -21301,7 +20844,7 @@ extern "C" {
     pub fn cuCtxEnablePeerAccess(peerContext: CUcontext, Flags: ::std::os::raw::c_uint)
         -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Disables direct access to memory allocations in a peer context and"]
     #[doc = " unregisters any registered allocations."]
     #[doc = ""]
@@ -21327,7 +20870,7 @@ extern "C" {
     #[doc = " ::cudaDeviceDisablePeerAccess"]
     pub fn cuCtxDisablePeerAccess(peerContext: CUcontext) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Queries attributes of the link between two devices."]
     #[doc = ""]
     #[doc = " Returns in \\p *value the value of the requested attribute \\p attrib of the"]
@@ -21371,7 +20914,7 @@ extern "C" {
         dstDevice: CUdevice,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Unregisters a graphics resource for access by CUDA"]
     #[doc = ""]
     #[doc = " Unregisters the graphics resource \\p resource so it is not accessible by"]
@@ -21400,7 +20943,7 @@ extern "C" {
     #[doc = " ::cudaGraphicsUnregisterResource"]
     pub fn cuGraphicsUnregisterResource(resource: CUgraphicsResource) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Get an array through which to access a subresource of a mapped graphics resource."]
     #[doc = ""]
     #[doc = " Returns in \\p *pArray an array through which the subresource of the mapped"]
@@ -21444,7 +20987,7 @@ extern "C" {
         mipLevel: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Get a mipmapped array through which to access a mapped graphics resource."]
     #[doc = ""]
     #[doc = " Returns in \\p *pMipmappedArray a mipmapped array through which the mapped graphics"]
@@ -21477,20 +21020,20 @@ extern "C" {
         resource: CUgraphicsResource,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuGraphicsResourceGetMappedPointer_v2(
         pDevPtr: *mut CUdeviceptr,
         pSize: *mut size_t,
         resource: CUgraphicsResource,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     pub fn cuGraphicsResourceSetMapFlags_v2(
         resource: CUgraphicsResource,
         flags: ::std::os::raw::c_uint,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Map graphics resources for access by CUDA"]
     #[doc = ""]
     #[doc = " Maps the \\p count graphics resources in \\p resources for access by CUDA."]
@@ -21533,7 +21076,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Unmap graphics resources."]
     #[doc = ""]
     #[doc = " Unmaps the \\p count graphics resources in \\p resources."]
@@ -21573,7 +21116,7 @@ extern "C" {
         hStream: CUstream,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Returns the requested driver API function pointer"]
     #[doc = ""]
     #[doc = " Returns in \\p **pfn the address of the CUDA driver function for the requested"]
@@ -21629,14 +21172,14 @@ extern "C" {
         flags: cuuint64_t,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " @}"]
     pub fn cuGetExportTable(
         ppExportTable: *mut *const ::std::os::raw::c_void,
         pExportTableId: *const CUuuid,
     ) -> CUresult;
 }
-extern "C" {
+cuda_extern! {
     #[doc = " \\brief Blocks until remote writes are visible to the specified scope"]
     #[doc = ""]
     #[doc = " Blocks until GPUDirect RDMA writes to the target context via mappings"]
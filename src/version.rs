@@ -1,12 +1,13 @@
 use std::fmt;
 
 use crate::{
+    backend,
     error::{cuda_error, CudaResult},
-    sys, Cuda,
+    Cuda,
 };
 
 /// A CUDA device or API version
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CudaVersion {
     pub major: u32,
     pub minor: u32,
@@ -46,7 +47,7 @@ impl Cuda {
     /// Gets the local driver version (not to be confused with device compute capability)
     pub fn version() -> CudaResult<CudaVersion> {
         let mut version = 0i32;
-        cuda_error(unsafe { sys::cuDriverGetVersion(&mut version as *mut i32) })?;
+        cuda_error(unsafe { backend::cuDriverGetVersion(&mut version as *mut i32) })?;
         Ok((version as u32).into())
     }
 }
@@ -0,0 +1,280 @@
+use std::{marker::PhantomData, ptr::null_mut, sync::Arc};
+
+use crate::*;
+
+struct PhysicalChunk {
+    handle: sys::CUmemGenericAllocationHandle,
+    size: u64,
+}
+
+/// A growable device buffer backed by virtual memory management.
+/// A virtual address range for up to `max_len` elements is reserved up front, and physical memory is mapped into it as the vector grows.
+/// Unlike reallocating a [`DeviceBox`], growing never copies, and the device address of existing elements never changes.
+pub struct DeviceVec<'a, T: DeviceCopy> {
+    handle: Arc<Handle<'a>>,
+    device: i32,
+    base: u64,
+    reserved: u64,
+    granularity: u64,
+    chunks: Vec<PhysicalChunk>,
+    mapped: u64,
+    len: usize,
+    _p: PhantomData<T>,
+}
+
+impl<'a, T: DeviceCopy> DeviceVec<'a, T> {
+    /// Reserves a virtual address range on the device of the context of `handle` for up to `max_len` elements. No physical memory is allocated until the vector grows.
+    pub fn new(handle: &Arc<Handle<'a>>, max_len: usize) -> CudaResult<Self> {
+        let device = handle.context().device()?;
+        let _current = handle.current()?;
+        let mut granularity: sys::size_t = 0;
+        cuda_error(unsafe {
+            backend::cuMemGetAllocationGranularity(
+                &mut granularity,
+                &Self::allocation_prop(device.handle),
+                sys::CUmemAllocationGranularity_flags_enum_CU_MEM_ALLOC_GRANULARITY_MINIMUM,
+            )
+        })?;
+        let granularity = granularity as u64;
+        let reserved = (max_len as u64)
+            .checked_mul(std::mem::size_of::<T>() as u64)
+            .and_then(|x| x.checked_next_multiple_of(granularity))
+            .expect("overflow in DeviceVec::new");
+        let mut base = 0u64;
+        if reserved > 0 {
+            cuda_error(unsafe {
+                backend::cuMemAddressReserve(&mut base, reserved as sys::size_t, 0, 0, 0)
+            })?;
+        }
+        Ok(DeviceVec {
+            handle: handle.clone(),
+            device: device.handle,
+            base,
+            reserved,
+            granularity,
+            chunks: vec![],
+            mapped: 0,
+            len: 0,
+            _p: PhantomData,
+        })
+    }
+
+    fn allocation_prop(device: i32) -> sys::CUmemAllocationProp {
+        sys::CUmemAllocationProp {
+            type_: sys::CUmemAllocationType_enum_CU_MEM_ALLOCATION_TYPE_PINNED,
+            requestedHandleTypes: sys::CUmemAllocationHandleType_enum_CU_MEM_HANDLE_TYPE_NONE,
+            location: sys::CUmemLocation {
+                type_: sys::CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_DEVICE,
+                id: device,
+            },
+            win32HandleMetaData: null_mut(),
+            allocFlags: sys::CUmemAllocationProp_st__bindgen_ty_1 {
+                compressionType: 0,
+                gpuDirectRDMACapable: 0,
+                usage: 0,
+                reserved: [0; 4],
+            },
+        }
+    }
+
+    /// Creates a physical allocation of `size` bytes and maps it read/write at the end of the mapped range
+    fn map_chunk(&mut self, size: u64) -> CudaResult<()> {
+        let _current = self.handle.current()?;
+        let mut handle: sys::CUmemGenericAllocationHandle = 0;
+        cuda_error(unsafe {
+            backend::cuMemCreate(
+                &mut handle,
+                size as sys::size_t,
+                &Self::allocation_prop(self.device),
+                0,
+            )
+        })?;
+        let ptr = self.base + self.mapped;
+        if let Err(e) =
+            cuda_error(unsafe { backend::cuMemMap(ptr, size as sys::size_t, 0, handle, 0) })
+        {
+            unsafe { backend::cuMemRelease(handle) };
+            return Err(e);
+        }
+        let access = sys::CUmemAccessDesc {
+            location: sys::CUmemLocation {
+                type_: sys::CUmemLocationType_enum_CU_MEM_LOCATION_TYPE_DEVICE,
+                id: self.device,
+            },
+            flags: sys::CUmemAccess_flags_enum_CU_MEM_ACCESS_FLAGS_PROT_READWRITE,
+        };
+        if let Err(e) =
+            cuda_error(unsafe { backend::cuMemSetAccess(ptr, size as sys::size_t, &access, 1) })
+        {
+            unsafe {
+                backend::cuMemUnmap(ptr, size as sys::size_t);
+                backend::cuMemRelease(handle);
+            }
+            return Err(e);
+        }
+        self.chunks.push(PhysicalChunk { handle, size });
+        self.mapped += size;
+        Ok(())
+    }
+
+    /// Ensures that at least `additional` more elements can be pushed without mapping more memory.
+    /// Mapped memory at least doubles when it grows, to amortize the cost of mapping.
+    /// Panics if the resulting length would exceed [`Self::max_capacity`].
+    pub fn reserve(&mut self, additional: usize) -> CudaResult<()> {
+        let required = self
+            .len
+            .checked_add(additional)
+            .filter(|x| *x <= self.max_capacity())
+            .expect("overflow in DeviceVec::reserve") as u64
+            * std::mem::size_of::<T>() as u64;
+        if required <= self.mapped {
+            return Ok(());
+        }
+        let target = required.max(self.mapped * 2);
+        let target = target.next_multiple_of(self.granularity).min(self.reserved);
+        self.map_chunk(target - self.mapped)
+    }
+
+    /// Synchronously appends `data` to the end of the vector, mapping more memory if needed
+    pub fn extend_from_slice(&mut self, data: &[T]) -> CudaResult<()> {
+        self.reserve(data.len())?;
+        let size = std::mem::size_of_val(data);
+        if size > 0 {
+            let _current = self.handle.current()?;
+            cuda_error(unsafe {
+                backend::cuMemcpyHtoD_v2(
+                    self.end_ptr(),
+                    data.as_ptr() as *const _,
+                    size as sys::size_t,
+                )
+            })?;
+        }
+        self.len += data.len();
+        Ok(())
+    }
+
+    /// Asynchronously appends `data` to the end of the vector, mapping more memory if needed.
+    /// The `data` must not be dropped or mutated until `stream.sync` is called.
+    pub fn extend_from_slice_stream<'b>(
+        &mut self,
+        data: &'b [T],
+        stream: &'b mut Stream<'a>,
+    ) -> CudaResult<()> {
+        self.reserve(data.len())?;
+        let size = std::mem::size_of_val(data);
+        if size > 0 {
+            let _current = self.handle.current()?;
+            cuda_error(unsafe {
+                backend::cuMemcpyHtoDAsync_v2(
+                    self.end_ptr(),
+                    data.as_ptr() as *const _,
+                    size as sys::size_t,
+                    stream.inner,
+                )
+            })?;
+        }
+        self.len += data.len();
+        Ok(())
+    }
+
+    fn end_ptr(&self) -> u64 {
+        self.base + (self.len * std::mem::size_of::<T>()) as u64
+    }
+
+    /// Shortens the vector to `len` elements. Has no effect if `len` is not less than the current length.
+    /// Mapped memory is kept, see [`Self::shrink_to_fit`].
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    /// Unmaps and frees physical memory that is no longer needed to hold the current length.
+    /// Memory is released in the same chunks it was mapped in, so some spare capacity may remain.
+    pub fn shrink_to_fit(&mut self) -> CudaResult<()> {
+        let required = (self.len * std::mem::size_of::<T>()) as u64;
+        let _current = self.handle.current()?;
+        while let Some(chunk) = self.chunks.last() {
+            if self.mapped - chunk.size < required {
+                break;
+            }
+            let ptr = self.base + self.mapped - chunk.size;
+            cuda_error(unsafe { backend::cuMemUnmap(ptr, chunk.size as sys::size_t) })?;
+            cuda_error(unsafe { backend::cuMemRelease(chunk.handle) })?;
+            self.mapped -= chunk.size;
+            self.chunks.pop();
+        }
+        Ok(())
+    }
+
+    /// Gets the number of elements in the vector
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the vector's length is 0
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the number of elements the vector can hold without mapping more memory
+    pub fn capacity(&self) -> usize {
+        match std::mem::size_of::<T>() {
+            0 => usize::MAX,
+            size => self.mapped as usize / size,
+        }
+    }
+
+    /// Gets the number of elements the vector can hold within its reserved address range
+    pub fn max_capacity(&self) -> usize {
+        match std::mem::size_of::<T>() {
+            0 => usize::MAX,
+            size => self.reserved as usize / size,
+        }
+    }
+
+    /// Gets the stable device address of the start of the vector. It does not change as the vector grows.
+    pub fn as_raw(&self) -> u64 {
+        self.base
+    }
+
+    /// Gets a [`DeviceSlice`] to the current contents of the vector.
+    /// The [`DeviceSlice`] must not be used after this [`DeviceVec`] is truncated and shrunk, or dropped.
+    pub fn device_ptr(&self) -> DeviceSlice<'a, T> {
+        DeviceSlice {
+            handle: self.handle.clone(),
+            inner: self.base,
+            len: self.len as u64,
+            _p: PhantomData,
+        }
+    }
+
+    /// Gets a reference to the owning handle
+    pub fn handle(&self) -> &Arc<Handle<'a>> {
+        &self.handle
+    }
+}
+
+impl<'a, T: DeviceCopy> Drop for DeviceVec<'a, T> {
+    fn drop(&mut self) {
+        let _current = self.handle.current();
+        let mut offset = 0u64;
+        for chunk in &self.chunks {
+            if let Err(e) = cuda_error(unsafe {
+                backend::cuMemUnmap(self.base + offset, chunk.size as sys::size_t)
+            }) {
+                eprintln!("CUDA: failed unmapping device vector memory: {:?}", e);
+            }
+            if let Err(e) = cuda_error(unsafe { backend::cuMemRelease(chunk.handle) }) {
+                eprintln!("CUDA: failed releasing device vector memory: {:?}", e);
+            }
+            offset += chunk.size;
+        }
+        if self.reserved == 0 {
+            return;
+        }
+        if let Err(e) = cuda_error(unsafe {
+            backend::cuMemAddressFree(self.base, self.reserved as sys::size_t)
+        }) {
+            eprintln!("CUDA: failed freeing device vector address range: {:?}", e);
+        }
+    }
+}
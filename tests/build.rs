@@ -0,0 +1,139 @@
+use cuda_oxide::build::{BuildError, Kernels};
+
+#[test]
+fn generate() {
+    let source = Kernels::new()
+        .ptx("examples/matrix_mul_jit/matrixMul_kernel.ptx")
+        .generate()
+        .unwrap();
+    assert!(source.contains("pub mod matrix_mul_kernel {"));
+    assert!(source.contains("include_str!(\""));
+    assert!(source.contains("pub unsafe fn matrixMul_bs32_64bit("));
+    assert_eq!(
+        source
+            .matches("param_4: impl ::cuda_oxide::KernelArg64,")
+            .count(),
+        2
+    );
+    assert!(source.contains("(param_0, param_1, param_2, param_3, param_4),"));
+}
+
+#[test]
+fn named() {
+    let source = Kernels::new()
+        .ptx_named("mod", "examples/matrix_mul_jit/matrixMul_kernel.ptx")
+        .generate()
+        .unwrap();
+    assert!(source.contains("pub mod mod_ {"));
+}
+
+#[test]
+fn missing_file() {
+    let error = Kernels::new().ptx("missing.ptx").generate().unwrap_err();
+    assert!(matches!(error, BuildError::Io { .. }));
+    assert!(error.to_string().starts_with("missing.ptx: "));
+}
+
+#[cfg(unix)]
+mod nvcc {
+    use cuda_oxide::build::{BuildError, Nvcc, NvccOutput};
+    use std::{os::unix::fs::PermissionsExt, path::PathBuf};
+
+    /// Creates a directory with a `kernel.cu` and a fake nvcc logging its arguments to `log`
+    fn setup(name: &str, script: &str) -> PathBuf {
+        let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("kernel.cu"), "").unwrap();
+        let nvcc = dir.join("nvcc");
+        std::fs::write(
+            &nvcc,
+            format!(
+                "#!/bin/sh\necho \"$@\" >> {}/log\n{}",
+                dir.display(),
+                script
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&nvcc, std::fs::Permissions::from_mode(0o755)).unwrap();
+        dir
+    }
+
+    const FAKE_NVCC: &str = r#"
+if [ "$1" = "-M" ]; then
+    printf 'kernel.o : kernel.cu \\\n  include/common\\ header.h\n'
+    exit 0
+fi
+while [ $# -gt 0 ]; do
+    if [ "$1" = "-o" ]; then out="$2"; fi
+    shift
+done
+printf 'image' > "$out"
+"#;
+
+    #[test]
+    fn ptx() {
+        let dir = setup("nvcc_ptx", FAKE_NVCC);
+        let outputs = Nvcc::new()
+            .nvcc(dir.join("nvcc"))
+            .source(dir.join("kernel.cu"))
+            .arch((7, 0).into())
+            .arch((8, 0).into())
+            .flag("-O3")
+            .define("N", Some("4"))
+            .out_dir(&dir)
+            .compile()
+            .unwrap();
+        assert_eq!(
+            outputs,
+            vec![
+                dir.join("kernel.compute_70.ptx"),
+                dir.join("kernel.compute_80.ptx")
+            ]
+        );
+        for output in &outputs {
+            assert_eq!(std::fs::read(output).unwrap(), b"image\0");
+        }
+        let log = std::fs::read_to_string(dir.join("log")).unwrap();
+        let log: Vec<&str> = log.lines().collect();
+        assert_eq!(log.len(), 3);
+        assert!(log[0].starts_with("-M -O3 -DN=4 "));
+        assert!(log[1].starts_with("--ptx -arch=compute_70 -O3 -DN=4 -o "));
+        assert!(log[2].starts_with("--ptx -arch=compute_80 -O3 -DN=4 -o "));
+    }
+
+    #[test]
+    fn fatbin() {
+        let dir = setup("nvcc_fatbin", FAKE_NVCC);
+        let outputs = Nvcc::new()
+            .nvcc(dir.join("nvcc"))
+            .source(dir.join("kernel.cu"))
+            .output(NvccOutput::Fatbin)
+            .arch((8, 0).into())
+            .arch((7, 0).into())
+            .out_dir(&dir)
+            .compile()
+            .unwrap();
+        assert_eq!(outputs, vec![dir.join("kernel.fatbin")]);
+        assert_eq!(std::fs::read(&outputs[0]).unwrap(), b"image");
+        let log = std::fs::read_to_string(dir.join("log")).unwrap();
+        assert!(log.lines().nth(1).unwrap().starts_with(
+            "--fatbin -gencode=arch=compute_80,code=sm_80 -gencode=arch=compute_70,code=sm_70 -gencode=arch=compute_80,code=compute_80 -o "
+        ));
+    }
+
+    #[test]
+    fn failure() {
+        let dir = setup("nvcc_failure", "echo 'kernel.cu(1): error' >&2\nexit 1\n");
+        let error = Nvcc::new()
+            .nvcc(dir.join("nvcc"))
+            .source(dir.join("kernel.cu"))
+            .out_dir(&dir)
+            .compile()
+            .unwrap_err();
+        match error {
+            BuildError::Nvcc { stderr, .. } => assert_eq!(stderr, "kernel.cu(1): error\n"),
+            error => panic!("unexpected error: {}", error),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Fixtures shared by the integration tests, which run on [`MockBackend`]
+#![allow(dead_code)]
+
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+use cuda_oxide::{backend::MockBackend, *};
+
+/// A module with a kernel `add(u32 *data, u32 value)`, adding `value` to one element of `data` per thread.
+/// The kernel body is empty, [`mock`] runs it as a host closure.
+pub const ADD_PTX: &[u8] = b".version 7.0\n.target sm_80\n.address_size 64\n\
+.visible .entry add(.param .u64 add_param_0, .param .u32 add_param_1)\n{\n\tret;\n}\n\0";
+
+/// Installs the mock backend once for all tests of a binary.
+/// Kernels of loaded PTX modules are interpreted, except `add` of [`ADD_PTX`].
+pub fn mock() -> &'static MockBackend {
+    static MOCK: OnceLock<MockBackend> = OnceLock::new();
+    MOCK.get_or_init(|| {
+        let mock = MockBackend::new().interpret_ptx();
+        mock.kernel("add", &[8, 4], |launch| {
+            let (ptr, value): (u64, u32) = (launch.param(0), launch.param(1));
+            let len = launch.grid_dim.0 .0 * launch.block_dim.0 .0;
+            let data = unsafe { std::slice::from_raw_parts_mut(ptr as *mut u32, len as usize) };
+            data.iter_mut().for_each(|x| *x += value);
+        });
+        mock.install().unwrap();
+        Cuda::init().unwrap();
+        mock
+    })
+}
+
+/// Runs tests one at a time, for tests running the work of every stream with [`MockBackend::flush`]
+pub fn serial() -> MutexGuard<'static, ()> {
+    static SERIAL: Mutex<()> = Mutex::new(());
+    mock();
+    SERIAL.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Creates a context on the mock device
+pub fn context() -> Context {
+    mock();
+    let device = Cuda::list_devices().unwrap().remove(0);
+    Context::new(&device).unwrap()
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use common::{context, mock};
+use cuda_oxide::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, DeviceCopy)]
+#[repr(C)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, DeviceCopy)]
+#[repr(transparent)]
+struct Wrapper<T>(T);
+
+#[derive(Clone, Copy, Debug, PartialEq, DeviceCopy, KernelParameters)]
+#[kernel_parameters(by_value)]
+#[repr(C, align(16))]
+struct Transform {
+    scale: [f32; 2],
+    offset: Point,
+}
+
+#[derive(KernelParameters)]
+struct TransformArgs<'a, 'b> {
+    points: &'b DeviceBox<'a, Point>,
+    enabled: u8,
+    transform: Transform,
+    count: u32,
+}
+
+#[derive(KernelParameters)]
+struct Pair(u16, u64);
+
+/// A kernel `transform(Point *points, u8 enabled, Transform transform, u32 count)`, run as a host closure registered with [`MockBackend::kernel`]
+const TRANSFORM_PTX: &[u8] = b".version 7.0\n.target sm_80\n.address_size 64\n\
+.visible .entry transform(.param .u64 transform_param_0, .param .u8 transform_param_1, \
+.param .align 16 .b8 transform_param_2[16], .param .u32 transform_param_3)\n{\n\tret;\n}\n\0";
+
+const TRANSFORM: Transform = Transform {
+    scale: [2.0, 3.0],
+    offset: Point { x: 1.0, y: -1.0 },
+};
+
+fn offsets_and_sizes(buffer: &KernelParameterBuffer) -> (Vec<usize>, Vec<usize>) {
+    (buffer.offsets().to_vec(), buffer.sizes().to_vec())
+}
+
+#[test]
+fn derive_device_copy() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let points = [
+        Wrapper(Point { x: 1.0, y: 2.0 }),
+        Wrapper(Point { x: 3.0, y: 4.0 }),
+    ];
+    let buffer = DeviceBox::new(&handle, &points).unwrap();
+    assert_eq!(buffer.load().unwrap(), points);
+}
+
+#[test]
+fn derive_flattened_layout() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let points = DeviceBox::<Point>::alloc(&handle, 4).unwrap();
+    let args = TransformArgs {
+        points: &points,
+        enabled: 1,
+        transform: TRANSFORM,
+        count: 4,
+    };
+
+    // each field is its own parameter, at the alignment of its type
+    let mut buffer = KernelParameterBuffer::new();
+    args.params(&mut buffer);
+    assert_eq!(buffer.count(), 4);
+    assert_eq!(
+        offsets_and_sizes(&buffer),
+        (vec![0, 8, 16, 32], vec![8, 1, 16, 4])
+    );
+    assert_eq!(buffer.len(), 36);
+
+    let mut layout = KernelParameterBuffer::new();
+    TransformArgs::layout(&mut layout);
+    assert_eq!(offsets_and_sizes(&layout), offsets_and_sizes(&buffer));
+    assert_eq!(layout.len(), buffer.len());
+
+    buffer.clear();
+    Pair(1, 2).params(&mut buffer);
+    assert_eq!(offsets_and_sizes(&buffer), (vec![0, 8], vec![2, 8]));
+}
+
+#[test]
+fn derive_by_value_layout() {
+    // the struct is a single parameter, aligned as the struct is
+    let mut buffer = KernelParameterBuffer::new();
+    buffer.push(&1u8);
+    TRANSFORM.params(&mut buffer);
+    assert_eq!(offsets_and_sizes(&buffer), (vec![0, 16], vec![1, 16]));
+    assert_eq!(buffer.len(), 32);
+
+    let mut layout = KernelParameterBuffer::new();
+    layout.push(&1u8);
+    Transform::layout(&mut layout);
+    assert_eq!(offsets_and_sizes(&layout), offsets_and_sizes(&buffer));
+}
+
+#[test]
+fn derive_kernel_launch() {
+    mock().kernel("transform", &[8, 1, 16, 4], |launch| {
+        let (ptr, enabled, count): (u64, u8, u32) =
+            (launch.param(0), launch.param(1), launch.param(3));
+        // the struct is passed as is, so its bytes are the bytes of the parameter
+        let transform: Transform = launch.param(2);
+        let points = unsafe { std::slice::from_raw_parts_mut(ptr as *mut Point, count as usize) };
+        if enabled != 0 {
+            for point in points {
+                point.x = point.x * transform.scale[0] + transform.offset.x;
+                point.y = point.y * transform.scale[1] + transform.offset.y;
+            }
+        }
+    });
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let module = Module::load(&handle, TRANSFORM_PTX).unwrap();
+    // the derived layout matches the kernel signature
+    let kernel = module.kernel::<TransformArgs>("transform").unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let points = DeviceBox::new(&handle, &[Point { x: 1.0, y: 1.0 }; 4]).unwrap();
+
+    let args = TransformArgs {
+        points: &points,
+        enabled: 1,
+        transform: TRANSFORM,
+        count: 3,
+    };
+    unsafe {
+        kernel
+            .launch(&mut stream, LaunchConfig::new(1, 1), args)
+            .unwrap()
+    };
+    stream.sync().unwrap();
+    let mut expected = vec![Point { x: 3.0, y: 2.0 }; 4];
+    expected[3] = Point { x: 1.0, y: 1.0 };
+    assert_eq!(points.load().unwrap(), expected);
+}
+
+#[test]
+fn derive_errors() {
+    // enums, unions and by value structs the kernel cannot take as is are rejected at compile time
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
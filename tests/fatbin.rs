@@ -0,0 +1,144 @@
+use std::convert::TryInto;
+
+use cuda_oxide::*;
+
+const FATBIN: &[u8] = include_bytes!("../examples/matrix_mul/matrixMul_kernel.fatbin");
+
+fn assert_padded(data: &[u8], expected: &[u8]) {
+    assert_eq!(&data[..expected.len()], expected);
+    assert!(data[expected.len()..].iter().all(|x| *x == 0));
+}
+
+#[test]
+fn read_nvcc_fatbin() {
+    let fatbin = Fatbin::parse(FATBIN).unwrap();
+    let entries = fatbin.entries();
+    assert_eq!(entries.len(), 2);
+
+    assert_eq!(entries[0].kind, FatbinEntryKind::Cubin);
+    assert_eq!(entries[0].arch, CudaVersion::from((5, 2)));
+    assert_eq!(entries[0].compression, FatbinCompression::None);
+    let cubin = entries[0].data().unwrap();
+    let functions = FunctionInfo::parse_cubin(&cubin).unwrap();
+    assert_eq!(functions.len(), 2);
+    let kernel = functions
+        .iter()
+        .find(|x| x.signature.name == "matrixMul_bs32_64bit")
+        .unwrap();
+    assert_eq!(kernel.signature.params.len(), 5);
+    assert!(kernel.signature.params.iter().all(|x| x.size == 8));
+    assert_eq!(kernel.shared_size, Some(2 * 32 * 32 * 8));
+
+    assert_eq!(entries[1].kind, FatbinEntryKind::Ptx);
+    assert_eq!(entries[1].compression, FatbinCompression::Lz4);
+    let ptx = entries[1].data().unwrap();
+    let ptx = std::str::from_utf8(&ptx).unwrap();
+    assert!(ptx.contains(".target sm_52"));
+    let signatures = KernelSignature::parse_ptx(ptx.trim_end_matches('\0')).unwrap();
+    assert_eq!(signatures.len(), 2);
+}
+
+#[test]
+fn select() {
+    let fatbin = Fatbin::parse(FATBIN).unwrap();
+    assert_eq!(
+        fatbin.select((5, 3).into()).unwrap().kind,
+        FatbinEntryKind::Cubin
+    );
+    assert_eq!(
+        fatbin.select((8, 6).into()).unwrap().kind,
+        FatbinEntryKind::Ptx
+    );
+    let error = fatbin.select((3, 5).into()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "no compatible image for sm_35; available: sm_52, compute_52"
+    );
+}
+
+#[test]
+fn round_trip() {
+    let fatbin = Fatbin::parse(FATBIN).unwrap();
+    let cubin = fatbin.entries()[0].data().unwrap();
+    let ptx = fatbin.entries()[1].data().unwrap();
+
+    let built = FatbinBuilder::new()
+        .add_cubin((5, 2).into(), &cubin)
+        .add_cubin((8, 0).into(), &cubin)
+        .add_ptx((5, 2).into(), &ptx)
+        .build();
+    let parsed = Fatbin::parse(&built).unwrap();
+    let entries = parsed.entries();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].kind, FatbinEntryKind::Cubin);
+    assert_eq!(entries[0].arch, CudaVersion::from((5, 2)));
+    assert_padded(&entries[0].data().unwrap(), &cubin);
+    assert_eq!(entries[1].arch, CudaVersion::from((8, 0)));
+    assert_eq!(entries[2].kind, FatbinEntryKind::Ptx);
+    assert_eq!(entries[2].compression, FatbinCompression::None);
+    assert_padded(&entries[2].data().unwrap(), &ptx);
+
+    assert_eq!(
+        parsed.select((8, 9).into()).unwrap().arch,
+        CudaVersion::from((8, 0))
+    );
+    assert_eq!(
+        parsed.select((6, 1).into()).unwrap().kind,
+        FatbinEntryKind::Ptx
+    );
+}
+
+#[test]
+fn matches_nvcc_entry_header() {
+    // nvcc stores the cubin uncompressed, so rebuilding it must reproduce the original entry exactly
+    let fatbin = Fatbin::parse(FATBIN).unwrap();
+    let cubin = fatbin.entries()[0].data().unwrap();
+    let built = FatbinBuilder::new()
+        .add_cubin((5, 2).into(), &cubin)
+        .build();
+    let entry_len = 64 + cubin.len();
+    assert_eq!(&built[16..16 + entry_len], &FATBIN[16..16 + entry_len]);
+}
+
+#[test]
+fn ptx_is_nul_terminated() {
+    let built = FatbinBuilder::new()
+        .add_ptx((7, 0).into(), b".version 7.0\n.target sm_70\n")
+        .build();
+    let parsed = Fatbin::parse(&built).unwrap();
+    let data = parsed.entries()[0].data().unwrap();
+    assert_padded(&data, b".version 7.0\n.target sm_70\n\0");
+}
+
+#[test]
+fn invalid() {
+    assert!(Fatbin::parse(b"not a fatbin").is_err());
+    let mut built = FatbinBuilder::new()
+        .add_ptx((7, 0).into(), b".version 7.0\n")
+        .build();
+    built.truncate(built.len() - 8);
+    assert!(Fatbin::parse(&built).is_err());
+}
+
+#[test]
+fn invalid_lz4_size() {
+    // patch the uncompressed size of the LZ4 compressed PTX entry, which follows the cubin entry
+    let mut fatbin = FATBIN.to_vec();
+    let read_u64 = |data: &[u8], offset: usize| {
+        u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()) as usize
+    };
+    let cubin_header_size = u32::from_le_bytes(fatbin[20..24].try_into().unwrap()) as usize;
+    let ptx_header = 16 + cubin_header_size + read_u64(&fatbin, 24);
+    let uncompressed_size = ptx_header + 56;
+    let size = read_u64(&fatbin, uncompressed_size);
+
+    for bad_size in [u64::MAX, 1 << 40, size as u64 + 1, size as u64 - 1] {
+        fatbin[uncompressed_size..uncompressed_size + 8].copy_from_slice(&bad_size.to_le_bytes());
+        let parsed = Fatbin::parse(&fatbin).unwrap();
+        assert_eq!(parsed.entries()[1].compression, FatbinCompression::Lz4);
+        assert!(matches!(
+            parsed.entries()[1].data(),
+            Err(FatbinError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,178 @@
+mod common;
+
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context as TaskContext, Poll, Wake, Waker},
+};
+
+use common::{context, mock, serial};
+use cuda_oxide::*;
+
+const PTX: &[u8] = b".version 7.0
+.target sm_80
+.address_size 64
+
+.visible .entry shuffle(.param .u64 shuffle_param_0)
+{
+    .reg .b32 %r<3>;
+
+    mov.u32 %r1, %laneid;
+    shfl.sync.bfly.b32 %r2, %r1, 1, 31, -1;
+    ret;
+}
+\0";
+
+#[derive(Default)]
+struct CountingWaker {
+    woken: AtomicBool,
+    wakes: AtomicUsize,
+}
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.woken.store(true, Ordering::SeqCst);
+        self.wakes.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// A minimal executor: polls `future` to completion, running the mock device whenever it is pending.
+/// Panics if the future is not woken once the device is idle, as it would then never be polled again.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let state = Arc::new(CountingWaker::default());
+    let waker = Waker::from(state.clone());
+    let mut cx = TaskContext::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        mock().flush();
+        assert!(
+            state.woken.swap(false, Ordering::SeqCst),
+            "pending future was not woken"
+        );
+    }
+}
+
+#[test]
+fn store_and_load() {
+    let _serial = serial();
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 4).unwrap();
+
+    let data = vec![1u32, 2, 3, 4];
+    let ptr = data.as_ptr();
+    let store = buffer.store_async(data, &mut stream).unwrap();
+    let returned = block_on(store).unwrap();
+    // the buffer is handed back for reuse
+    assert_eq!(returned.as_ptr(), ptr);
+    assert_eq!(returned, vec![1, 2, 3, 4]);
+
+    let load = buffer.subslice(1, 4).load_async(&mut stream).unwrap();
+    assert_eq!(block_on(load).unwrap(), vec![2, 3, 4]);
+}
+
+#[test]
+fn copy_to() {
+    let _serial = serial();
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let source = DeviceBox::new(&handle, &[5u8; 16]).unwrap();
+    let target = DeviceBox::<u8>::alloc(&handle, 16).unwrap();
+
+    let copy = source.copy_to_async(&target, &mut stream).unwrap();
+    block_on(copy).unwrap();
+    assert_eq!(target.load().unwrap(), vec![5; 16]);
+}
+
+#[test]
+fn single_callback() {
+    let _serial = serial();
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 4).unwrap();
+    buffer.memset_d32_stream(3, &mut stream).unwrap();
+
+    let mut future = stream.future().unwrap();
+    let queued = mock().pending();
+    let state = Arc::new(CountingWaker::default());
+    let waker = Waker::from(state.clone());
+    let mut cx = TaskContext::from_waker(&waker);
+    for _ in 0..5 {
+        assert!(Future::poll(std::pin::Pin::new(&mut future), &mut cx).is_pending());
+    }
+    // polling does not queue more work
+    assert_eq!(mock().pending(), queued);
+
+    mock().flush();
+    assert_eq!(state.wakes.load(Ordering::SeqCst), 1);
+    assert!(matches!(
+        Future::poll(std::pin::Pin::new(&mut future), &mut cx),
+        Poll::Ready(Ok(()))
+    ));
+    assert_eq!(buffer.load().unwrap(), vec![3; 4]);
+}
+
+#[test]
+fn event() {
+    let _serial = serial();
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 4).unwrap();
+    let event = Event::new(&handle).unwrap();
+
+    buffer.memset_d32_stream(8, &mut stream).unwrap();
+    stream.record(&event).unwrap();
+    let future = event.future().unwrap();
+    // work queued after the event is not waited on
+    buffer.memset_d32_stream(9, &mut stream).unwrap();
+
+    block_on(future).unwrap();
+    assert!(event.is_complete().unwrap());
+    stream.sync().unwrap();
+    assert_eq!(buffer.load().unwrap(), vec![9; 4]);
+}
+
+#[test]
+fn dropped_before_completion() {
+    let _serial = serial();
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u64>::alloc(&handle, 1024).unwrap();
+
+    // the data is kept alive until the copy runs
+    let data: Vec<u64> = (0..1024).collect();
+    drop(buffer.store_async(data, &mut stream).unwrap());
+    drop(buffer.load_async(&mut stream).unwrap());
+    stream.sync().unwrap();
+    assert_eq!(buffer.load().unwrap(), (0..1024).collect::<Vec<u64>>());
+}
+
+#[test]
+fn kernel_failure() {
+    let _serial = serial();
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let module = Module::load(&handle, PTX).unwrap();
+    let function = module.get_function("shuffle").unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 32).unwrap();
+
+    unsafe {
+        stream
+            .launch_checked(&function, 1, 32, 0, (&buffer,))
+            .unwrap()
+    };
+    let future = stream.future().unwrap();
+    assert!(matches!(block_on(future), Err(ErrorCode::LaunchFailed)));
+}
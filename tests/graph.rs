@@ -0,0 +1,282 @@
+mod common;
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use common::{context, mock, serial, ADD_PTX};
+use cuda_oxide::*;
+
+#[test]
+fn graph_launch() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let module = Module::load(&handle, ADD_PTX).unwrap();
+    let function = module.get_function("add").unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let source = DeviceBox::new(&handle, &[1u32; 64]).unwrap();
+    let target = DeviceBox::<u32>::alloc(&handle, 64).unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut graph = Graph::new(&handle).unwrap();
+    // added before its dependencies, so it only runs after them if they are respected
+    let copy = unsafe { graph.add_memcpy(&[], &source, &target).unwrap() };
+    let memset = unsafe { graph.add_memset_d32(&[], &target, 5).unwrap() };
+    let add = unsafe {
+        graph
+            .add_kernel(&[], &function, 2, 32, 0, (&source, 2u32))
+            .unwrap()
+    };
+    graph.add_dependency(*memset, *copy).unwrap();
+    graph.add_dependency(*add, *copy).unwrap();
+    let callback_calls = calls.clone();
+    let host = graph
+        .add_host(&[*copy], move || {
+            callback_calls.fetch_add(1, Ordering::SeqCst);
+        })
+        .unwrap();
+    graph.add_empty(&[host]).unwrap();
+    assert_eq!(graph.node_count().unwrap(), 5);
+
+    let exec = graph.instantiate().unwrap();
+    drop(graph);
+    exec.upload(&mut stream).unwrap();
+    exec.launch(&mut stream).unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    stream.sync().unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(target.load().unwrap(), vec![3; 64]);
+
+    exec.launch(&mut stream).unwrap();
+    exec.launch(&mut stream).unwrap();
+    stream.sync().unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert_eq!(source.load().unwrap(), vec![7; 64]);
+    assert_eq!(target.load().unwrap(), vec![7; 64]);
+}
+
+#[test]
+fn graph_child_and_events() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let mut other = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 4).unwrap();
+    let event = Event::new(&handle).unwrap();
+
+    let mut child = Graph::new(&handle).unwrap();
+    unsafe { child.add_memset_d16(&[], &buffer, 3).unwrap() };
+    let mut graph = Graph::new(&handle).unwrap();
+    let node = graph.add_child_graph(&[], &child).unwrap();
+    unsafe { graph.add_event_record(&[node], &event).unwrap() };
+    drop(child);
+
+    let exec = graph.instantiate().unwrap();
+    exec.launch(&mut stream).unwrap();
+    assert!(!event.is_complete().unwrap());
+    // waiting on the event recorded by the graph runs the launch
+    other.wait_event(&event).unwrap();
+    other.sync().unwrap();
+    assert!(event.is_complete().unwrap());
+    assert_eq!(buffer.load().unwrap(), vec![0x00030003; 4]);
+}
+
+#[test]
+fn graph_cycle() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut graph = Graph::new(&handle).unwrap();
+    let a = graph.add_empty(&[]).unwrap();
+    let b = graph.add_empty(&[a]).unwrap();
+    graph.add_dependency(b, a).unwrap();
+    assert!(matches!(graph.instantiate(), Err(ErrorCode::InvalidValue)));
+}
+
+#[test]
+fn graph_exec_drop_waits_for_launches() {
+    let _serial = serial();
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut graph = Graph::new(&handle).unwrap();
+    let callback_calls = calls.clone();
+    graph
+        .add_host(&[], move || {
+            callback_calls.fetch_add(1, Ordering::SeqCst);
+        })
+        .unwrap();
+    let exec = graph.instantiate().unwrap();
+    drop(graph);
+    exec.launch(&mut stream).unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    // the callback is still enqueued, so it must run before the exec releases it
+    drop(exec);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    mock().flush();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn graph_exec_set_parameters() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let module = Module::load(&handle, ADD_PTX).unwrap();
+    let function = module.get_function("add").unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let a = DeviceBox::new(&handle, &[1u32; 32]).unwrap();
+    let b = DeviceBox::new(&handle, &[2u32; 32]).unwrap();
+    let c = DeviceBox::<u32>::alloc(&handle, 32).unwrap();
+
+    let mut graph = Graph::new(&handle).unwrap();
+    let add = unsafe {
+        graph
+            .add_kernel(&[], &function, 1, 32, 0, (&a, 1u32))
+            .unwrap()
+    };
+    let copy = unsafe { graph.add_memcpy(&[*add], &a, &c).unwrap() };
+    let memset = unsafe { graph.add_memset_d32(&[*copy], &a, 0).unwrap() };
+    let mut exec = graph.instantiate().unwrap();
+
+    unsafe {
+        exec.set_kernel_parameters(&add, (&b, 10u32)).unwrap();
+        exec.set_memcpy(&copy, &b, &c).unwrap();
+        exec.set_memset(&memset, &b, 4).unwrap();
+    }
+    exec.launch(&mut stream).unwrap();
+    stream.sync().unwrap();
+    assert_eq!(a.load().unwrap(), vec![1; 32]);
+    assert_eq!(b.load().unwrap(), vec![4; 32]);
+    assert_eq!(c.load().unwrap(), vec![12; 32]);
+}
+
+#[test]
+fn graph_exec_update() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 4).unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let build = |value: u32, increment: usize| {
+        let mut graph = Graph::new(&handle).unwrap();
+        let memset = unsafe { graph.add_memset_d32(&[], &buffer, value).unwrap() };
+        let callback_calls = calls.clone();
+        graph
+            .add_host(&[*memset], move || {
+                callback_calls.fetch_add(increment, Ordering::SeqCst);
+            })
+            .unwrap();
+        graph
+    };
+    let mut exec = build(1, 1).instantiate().unwrap();
+    exec.launch(&mut stream).unwrap();
+    // the launch keeps using the callback it was enqueued with, which the update must not release
+    exec.update(&build(2, 10)).unwrap();
+    stream.sync().unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(buffer.load().unwrap(), vec![1; 4]);
+
+    exec.launch(&mut stream).unwrap();
+    stream.sync().unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 11);
+    assert_eq!(buffer.load().unwrap(), vec![2; 4]);
+
+    let mut graph = Graph::new(&handle).unwrap();
+    let node = graph.add_empty(&[]).unwrap();
+    graph.add_empty(&[node]).unwrap();
+    match exec.update(&graph) {
+        Err(GraphExecUpdateError::Rejected {
+            reason,
+            node: Some(error),
+        }) => {
+            assert_eq!(reason, GraphExecUpdateReason::NodeTypeChanged);
+            assert_eq!(error, node);
+        }
+        e => panic!("unexpected update result {:?}", e),
+    }
+    graph.add_empty(&[]).unwrap();
+    assert!(matches!(
+        exec.update(&graph),
+        Err(GraphExecUpdateError::Rejected {
+            reason: GraphExecUpdateReason::TopologyChanged,
+            ..
+        })
+    ));
+
+    // a rejected update leaves the exec unchanged
+    exec.launch(&mut stream).unwrap();
+    stream.sync().unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 21);
+}
+
+#[test]
+fn stream_capture() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let module = Module::load(&handle, ADD_PTX).unwrap();
+    let function = module.get_function("add").unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let a = DeviceBox::<u32>::alloc(&handle, 64).unwrap();
+    let b = DeviceBox::<u32>::alloc(&handle, 64).unwrap();
+
+    let mut capture = stream.begin_capture(StreamCaptureMode::Global).unwrap();
+    assert_eq!(
+        capture.capture_status().unwrap(),
+        StreamCaptureStatus::Active
+    );
+    a.store_stream_buf(vec![1; 64], &mut capture).unwrap();
+    unsafe { capture.launch(&function, 2, 32, 0, (&a, 2u32)).unwrap() };
+    a.copy_to_stream(&b, &mut capture).unwrap();
+    a.memset_d32_stream(0, &mut capture).unwrap();
+    let graph = capture.end_capture().unwrap();
+    assert_eq!(stream.capture_status().unwrap(), StreamCaptureStatus::None);
+    assert_eq!(graph.node_count().unwrap(), 4);
+    // captured work only runs when the graph is launched
+    stream.sync().unwrap();
+    assert_eq!(b.load().unwrap(), vec![0; 64]);
+
+    let exec = graph.instantiate().unwrap();
+    drop(graph);
+    exec.launch(&mut stream).unwrap();
+    exec.launch(&mut stream).unwrap();
+    stream.sync().unwrap();
+    assert_eq!(a.load().unwrap(), vec![0; 64]);
+    assert_eq!(b.load().unwrap(), vec![3; 64]);
+}
+
+#[test]
+fn stream_capture_invalidated() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 4).unwrap();
+    let event = Event::new(&handle).unwrap();
+
+    let mut capture = stream.begin_capture(StreamCaptureMode::Relaxed).unwrap();
+    buffer.memset_d32_stream(1, &mut capture).unwrap();
+    assert!(matches!(
+        capture.record(&event),
+        Err(ErrorCode::StreamCaptureUnsupported)
+    ));
+    assert_eq!(
+        capture.capture_status().unwrap(),
+        StreamCaptureStatus::Invalidated
+    );
+    assert!(matches!(
+        buffer.memset_d32_stream(2, &mut capture),
+        Err(ErrorCode::StreamCaptureInvalidated)
+    ));
+    assert!(matches!(
+        capture.end_capture(),
+        Err(ErrorCode::StreamCaptureInvalidated)
+    ));
+
+    // the stream runs work as usual once the capture ends
+    assert_eq!(stream.capture_status().unwrap(), StreamCaptureStatus::None);
+    buffer.memset_d32_stream(3, &mut stream).unwrap();
+    stream.sync().unwrap();
+    assert_eq!(buffer.load().unwrap(), vec![3; 4]);
+}
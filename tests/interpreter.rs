@@ -0,0 +1,247 @@
+mod common;
+
+use common::{context, mock};
+use cuda_oxide::{backend::InterpretError, *};
+
+const MATRIX_MUL: &[u8] = include_bytes!("../examples/matrix_mul_jit/matrixMul_kernel.ptx");
+
+/// Sums `n` floats into `out`, one partial sum per block, counting the blocks in `blocks`
+const BLOCK_SUM: &[u8] = b".version 7.0
+.target sm_80
+.address_size 64
+
+.global .align 4 .u32 blocks;
+
+.visible .entry block_sum(
+    .param .u64 block_sum_param_0,
+    .param .u64 block_sum_param_1,
+    .param .u32 block_sum_param_2
+)
+{
+    .reg .pred %p<3>;
+    .reg .b32 %r<9>;
+    .reg .f32 %f<4>;
+    .reg .b64 %rd<5>;
+    .shared .align 4 .b8 partial[1024];
+
+    ld.param.u64 %rd1, [block_sum_param_0];
+    ld.param.u64 %rd2, [block_sum_param_1];
+    ld.param.u32 %r1, [block_sum_param_2];
+    cvta.to.global.u64 %rd1, %rd1;
+    cvta.to.global.u64 %rd2, %rd2;
+    mov.u32 %r2, %tid.x;
+    mov.u32 %r3, %ntid.x;
+    mov.u32 %r4, %ctaid.x;
+    mad.lo.s32 %r5, %r4, %r3, %r2;
+    mov.f32 %f1, 0f00000000;
+    setp.ge.u32 %p1, %r5, %r1;
+    @%p1 bra $L__store;
+    mul.wide.u32 %rd3, %r5, 4;
+    add.s64 %rd4, %rd1, %rd3;
+    ld.global.f32 %f1, [%rd4];
+$L__store:
+    mov.u32 %r6, partial;
+    shl.b32 %r7, %r2, 2;
+    add.s32 %r6, %r6, %r7;
+    st.shared.f32 [%r6], %f1;
+    bar.sync 0;
+    shr.u32 %r3, %r3, 1;
+$L__loop:
+    setp.eq.u32 %p1, %r3, 0;
+    @%p1 bra $L__done;
+    setp.ge.u32 %p2, %r2, %r3;
+    @%p2 bra $L__skip;
+    shl.b32 %r7, %r3, 2;
+    add.s32 %r7, %r6, %r7;
+    ld.shared.f32 %f2, [%r7];
+    ld.shared.f32 %f1, [%r6];
+    add.f32 %f1, %f1, %f2;
+    st.shared.f32 [%r6], %f1;
+$L__skip:
+    bar.sync 0;
+    shr.u32 %r3, %r3, 1;
+    bra.uni $L__loop;
+$L__done:
+    setp.ne.u32 %p1, %r2, 0;
+    @%p1 bra $L__end;
+    ld.shared.f32 %f1, [partial];
+    atom.global.add.f32 %f3, [%rd2], %f1;
+    atom.global.add.u32 %r8, [blocks], 1;
+$L__end:
+    ret;
+}
+
+.visible .entry shuffle(.param .u64 shuffle_param_0)
+{
+    .reg .b32 %r<3>;
+
+    mov.u32 %r1, %laneid;
+    shfl.sync.bfly.b32 %r2, %r1, 1, 31, -1;
+    ret;
+}
+
+.visible .entry overflow(.param .u64 overflow_param_0)
+{
+    .reg .b32 %r<2>;
+    .reg .b64 %rd<4>;
+
+    ld.param.u64 %rd1, [overflow_param_0];
+    mov.u32 %r1, %tid.x;
+    mul.wide.u32 %rd2, %r1, 4;
+    add.s64 %rd3, %rd1, %rd2;
+    st.global.u32 [%rd3], %r1;
+    ret;
+}
+\0";
+
+#[test]
+fn matrix_mul() {
+    const BLOCK_SIZE: u32 = 16;
+    const A_WIDTH: usize = 48;
+    const A_HEIGHT: usize = 32;
+    const B_WIDTH: usize = 32;
+
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let linker = Linker::new(&handle, (8, 0).into(), LinkerOptions::default())
+        .unwrap()
+        .add("matrixMul_kernel.ptx", LinkerInputType::Ptx, MATRIX_MUL)
+        .unwrap();
+    let module = linker.build_module().unwrap();
+    let function = module.get_function("matrixMul_bs16_64bit").unwrap();
+
+    let a: Vec<f64> = (0..A_WIDTH * A_HEIGHT)
+        .map(|x| (x % 7) as f64 * 0.5)
+        .collect();
+    let b: Vec<f64> = (0..B_WIDTH * A_WIDTH)
+        .map(|x| (x % 5) as f64 - 1.25)
+        .collect();
+    let device_a = DeviceBox::new(&handle, &a[..]).unwrap();
+    let device_b = DeviceBox::new(&handle, &b[..]).unwrap();
+    let device_c = DeviceBox::<f64>::alloc(&handle, (B_WIDTH * A_HEIGHT) as u64).unwrap();
+
+    let mut stream = Stream::new(&handle).unwrap();
+    unsafe {
+        stream
+            .launch_checked(
+                &function,
+                (B_WIDTH as u32 / BLOCK_SIZE, A_HEIGHT as u32 / BLOCK_SIZE),
+                (BLOCK_SIZE, BLOCK_SIZE),
+                0,
+                (&device_c, &device_a, &device_b, A_WIDTH, B_WIDTH),
+            )
+            .unwrap()
+    };
+    stream.sync().unwrap();
+
+    let c = device_c.load().unwrap();
+    for row in 0..A_HEIGHT {
+        for column in 0..B_WIDTH {
+            let expected: f64 = (0..A_WIDTH)
+                .map(|i| a[row * A_WIDTH + i] * b[i * B_WIDTH + column])
+                .sum();
+            let actual = c[row * B_WIDTH + column];
+            assert!(
+                (actual - expected).abs() < 1e-9,
+                "c[{}][{}] = {}, expected {}",
+                row,
+                column,
+                actual,
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn shared_memory_and_atomics() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let module = Module::load(&handle, BLOCK_SUM).unwrap();
+    let function = module.get_function("block_sum").unwrap();
+
+    let input: Vec<f32> = (0..1000).map(|x| x as f32).collect();
+    let device_input = DeviceBox::new(&handle, &input[..]).unwrap();
+    let output = DeviceBox::new(&handle, &[0.0f32]).unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    unsafe {
+        stream
+            .launch_checked(&function, 4, 256, 0, (&device_input, &output, 1000u32))
+            .unwrap()
+    };
+    stream.sync().unwrap();
+
+    assert_eq!(output.load().unwrap(), vec![input.iter().sum::<f32>()]);
+    let blocks = module.get_global("blocks").unwrap();
+    assert_eq!(blocks.len(), 4);
+    assert_eq!(blocks.cast::<u32>().load().unwrap(), vec![4]);
+}
+
+/// Failures are checked in one test, as [`MockBackend::last_error`] is shared by the tests of this binary
+#[test]
+fn failures() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let module = Module::load(&handle, BLOCK_SUM).unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 64).unwrap();
+
+    let shuffle = module.get_function("shuffle").unwrap();
+    unsafe {
+        stream
+            .launch_checked(&shuffle, 1, 32, 0, (&buffer,))
+            .unwrap()
+    };
+    assert!(matches!(stream.sync(), Err(ErrorCode::LaunchFailed)));
+    match mock().last_error() {
+        Some(InterpretError::Unsupported {
+            kernel,
+            instruction,
+        }) => {
+            assert_eq!(kernel, "shuffle");
+            assert_eq!(instruction, "shfl.sync.bfly.b32 %r2, %r1, 1, 31, -1");
+        }
+        e => panic!("unexpected error {:?}", e),
+    }
+    // the failure is reported once
+    stream.sync().unwrap();
+
+    let overflow = module.get_function("overflow").unwrap();
+    unsafe {
+        stream
+            .launch_checked(&overflow, 1, 65, 0, (&buffer,))
+            .unwrap()
+    };
+    assert!(matches!(
+        handle.context().synchronize(),
+        Err(ErrorCode::LaunchFailed)
+    ));
+    match mock().last_error() {
+        Some(InterpretError::InvalidAccess {
+            kernel,
+            address,
+            block,
+            thread,
+            ..
+        }) => {
+            assert_eq!(kernel, "overflow");
+            assert_eq!(address, buffer.as_raw() + 64 * 4);
+            assert_eq!(block, (0, 0, 0));
+            assert_eq!(thread, (64, 0, 0));
+        }
+        e => panic!("unexpected error {:?}", e),
+    }
+    // threads before the faulting one ran
+    assert_eq!(buffer.load().unwrap(), (0..64).collect::<Vec<u32>>());
+
+    let ptx = b".version 7.0\n.target sm_80\n.address_size 64\n\
+.visible .entry broken(.param .u64 broken_param_0)\n{\n\tmov.u32 %r1, 1;\n\tret;\n}\n\0";
+    assert!(matches!(
+        Module::load(&handle, ptx),
+        Err(ErrorCode::InvalidPtx)
+    ));
+    assert!(matches!(
+        mock().last_error(),
+        Some(InterpretError::Parse(_))
+    ));
+}
@@ -0,0 +1,263 @@
+mod common;
+
+use common::{context, serial, ADD_PTX};
+use cuda_oxide::*;
+
+#[test]
+fn pool_alloc_free_ordering() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let device = Cuda::list_devices().unwrap().remove(0);
+    let mut stream = Stream::new(&handle).unwrap();
+    let mut pool = MemPool::new(&device).unwrap();
+    pool.set_release_threshold(u64::MAX).unwrap();
+    assert_eq!(pool.release_threshold().unwrap(), u64::MAX);
+
+    let buffer = DeviceBox::<u32>::alloc_async_from_pool(&pool, &mut stream, 256).unwrap();
+    buffer.memset_d32_stream(1, &mut stream).unwrap();
+    assert_eq!(pool.used_memory().unwrap(), 1024);
+    drop(buffer);
+    // the free is ordered after the memset, so the memory is in use until the stream runs it
+    assert_eq!(pool.used_memory().unwrap(), 1024);
+    stream.sync().unwrap();
+    assert_eq!(pool.used_memory().unwrap(), 0);
+    // freed memory stays reserved for reuse, up to the release threshold
+    assert_eq!(pool.reserved_memory().unwrap(), 1024);
+    pool.trim_to(512).unwrap();
+    assert_eq!(pool.reserved_memory().unwrap(), 512);
+
+    pool.set_release_threshold(0).unwrap();
+    let buffer = DeviceBox::<u32>::alloc_async_from_pool(&pool, &mut stream, 512).unwrap();
+    assert_eq!(pool.reserved_memory().unwrap(), 2048);
+    // memory in use is never released
+    pool.trim_to(0).unwrap();
+    assert_eq!(pool.reserved_memory().unwrap(), 2048);
+    drop(buffer);
+    stream.sync().unwrap();
+    assert_eq!(pool.reserved_memory().unwrap(), 0);
+}
+
+#[test]
+fn device_mem_pool() {
+    let _serial = serial();
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let device = Cuda::list_devices().unwrap().remove(0);
+    let mut stream = Stream::new(&handle).unwrap();
+    let default = device.default_mem_pool().unwrap();
+    assert_eq!(
+        device.mem_pool().unwrap().used_memory().unwrap(),
+        default.used_memory().unwrap()
+    );
+
+    let pool = MemPool::new(&device).unwrap();
+    device.set_mem_pool(&pool).unwrap();
+    let buffer = DeviceBox::<u8>::alloc_async(&mut stream, 100).unwrap();
+    assert_eq!(pool.used_memory().unwrap(), 100);
+    assert_eq!(device.mem_pool().unwrap().used_memory().unwrap(), 100);
+    drop(buffer);
+    stream.sync().unwrap();
+    assert_eq!(pool.used_memory().unwrap(), 0);
+
+    // dropping the current pool makes the default pool current again
+    drop(pool);
+    let used = default.used_memory().unwrap();
+    let buffer = DeviceBox::<u8>::alloc_async(&mut stream, 100).unwrap();
+    assert_eq!(default.used_memory().unwrap(), used + 100);
+    drop(buffer);
+    stream.sync().unwrap();
+    assert_eq!(default.used_memory().unwrap(), used);
+}
+
+#[test]
+fn host_box() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let module = Module::load(&handle, ADD_PTX).unwrap();
+    let function = module.get_function("add").unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 32).unwrap();
+
+    let input = HostBox::new(&handle, &[4u32; 32]).unwrap();
+    let mut output = HostBox::filled(&handle, 0u32, 32, HostAllocOptions::default()).unwrap();
+    assert_eq!(input.byte_len(), 128);
+    buffer.store_stream_host(&input, &mut stream).unwrap();
+    unsafe { buffer.load_stream_host(&mut output, &mut stream).unwrap() };
+    stream.sync().unwrap();
+    assert_eq!(&output[..], &[4; 32]);
+
+    // kernels access mapped memory through its device pointer
+    let options = HostAllocOptions {
+        mapped: true,
+        ..Default::default()
+    };
+    let mapped = HostBox::new_with_options(&handle, &[1u32; 32], options).unwrap();
+    unsafe {
+        stream
+            .launch(&function, 1, 32, 0, (mapped.device_ptr().unwrap(), 2u32))
+            .unwrap()
+    };
+    stream.sync().unwrap();
+    assert_eq!(&mapped[..], &[3; 32]);
+
+    let empty = HostBox::<u32>::new(&handle, &[]).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn host_registration() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let module = Module::load(&handle, ADD_PTX).unwrap();
+    let function = module.get_function("add").unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::new(&handle, &[5u32; 32]).unwrap();
+
+    let mut data = vec![0u32; 32];
+    let options = HostRegisterOptions {
+        mapped: true,
+        ..Default::default()
+    };
+    let mut registration = HostRegistration::new(&handle, &mut data, options).unwrap();
+    unsafe {
+        buffer
+            .load_stream_host(&mut registration, &mut stream)
+            .unwrap()
+    };
+    stream.sync().unwrap();
+    assert_eq!(&registration[..], &[5; 32]);
+    let device_ptr = registration.device_ptr().unwrap();
+    unsafe {
+        stream
+            .launch(&function, 1, 32, 0, (device_ptr, 1u32))
+            .unwrap()
+    };
+    stream.sync().unwrap();
+    drop(registration);
+    assert_eq!(data, vec![6; 32]);
+
+    // the memory is unregistered once the registration is dropped, so it can be registered again
+    HostRegistration::new(&handle, &mut data, HostRegisterOptions::default()).unwrap();
+}
+
+#[test]
+fn managed_box() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let device = Cuda::list_devices().unwrap().remove(0);
+    let module = Module::load(&handle, ADD_PTX).unwrap();
+    let function = module.get_function("add").unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+
+    let mut managed = ManagedBox::new(&handle, &[1u32; 32]).unwrap();
+    assert_eq!(managed.byte_len(), 128);
+    managed.advise(MemAdvice::SetReadMostly, None).unwrap();
+    managed
+        .advise(MemAdvice::SetPreferredLocation, Some(&device))
+        .unwrap();
+    stream.attach_mem(&managed, MemAttach::Single).unwrap();
+    managed.prefetch_to(&device, &mut stream).unwrap();
+    unsafe {
+        stream
+            .launch(&function, 1, 32, 0, (&managed, 2u32))
+            .unwrap()
+    };
+    managed.prefetch_to_host(&mut stream).unwrap();
+    stream.sync().unwrap();
+    // the host and the device access the buffer through the same address
+    assert_eq!(&managed[..], &[3; 32]);
+    managed[0] = 7;
+    assert_eq!(managed.device_ptr().load().unwrap()[0], 7);
+
+    let filled = ManagedBox::filled(&handle, 9u8, 3).unwrap();
+    assert_eq!(&filled[..], &[9; 3]);
+}
+
+/// The granularity of the mock backend's virtual memory management, in `u32` elements
+const GRANULARITY: usize = (2 << 20) / 4;
+
+#[test]
+fn device_vec_growth() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut vec = DeviceVec::<u32>::new(&handle, 2 * GRANULARITY + 1).unwrap();
+    let base = vec.as_raw();
+    // the reserved range is rounded up to the granularity, but nothing is mapped yet
+    assert_eq!(vec.max_capacity(), 3 * GRANULARITY);
+    assert_eq!(vec.capacity(), 0);
+
+    vec.extend_from_slice(&[1; 10]).unwrap();
+    assert_eq!(vec.capacity(), GRANULARITY);
+    // growing at least doubles the mapped memory
+    vec.extend_from_slice(&vec![2; GRANULARITY]).unwrap();
+    assert_eq!(vec.capacity(), 2 * GRANULARITY);
+    assert_eq!(vec.len(), GRANULARITY + 10);
+    // but never beyond the reserved range
+    vec.reserve(GRANULARITY).unwrap();
+    assert_eq!(vec.capacity(), 3 * GRANULARITY);
+    assert_eq!(vec.as_raw(), base);
+
+    // the contents span two mapped chunks
+    let contents = vec.device_ptr().load().unwrap();
+    assert_eq!(contents.len(), GRANULARITY + 10);
+    assert!(contents[..10].iter().all(|x| *x == 1));
+    assert!(contents[10..].iter().all(|x| *x == 2));
+}
+
+#[test]
+fn device_vec_shrink() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let mut vec = DeviceVec::<u32>::new(&handle, 4 * GRANULARITY).unwrap();
+    let data = vec![3; GRANULARITY];
+    vec.extend_from_slice_stream(&data[..10], &mut stream)
+        .unwrap();
+    vec.extend_from_slice_stream(&data, &mut stream).unwrap();
+    vec.extend_from_slice_stream(&data, &mut stream).unwrap();
+    stream.sync().unwrap();
+    assert_eq!(vec.capacity(), 4 * GRANULARITY);
+
+    // memory is unmapped in the chunks it was mapped in
+    vec.truncate(5);
+    assert_eq!(vec.capacity(), 4 * GRANULARITY);
+    vec.shrink_to_fit().unwrap();
+    assert_eq!(vec.capacity(), GRANULARITY);
+    assert_eq!(vec.device_ptr().load().unwrap(), vec![3; 5]);
+    // the unmapped memory can no longer be accessed
+    let unmapped = unsafe {
+        DeviceSlice::<u32>::from_raw_parts(handle.clone(), vec.as_raw() + 4 * GRANULARITY as u64, 1)
+    };
+    assert!(matches!(unmapped.load(), Err(ErrorCode::InvalidValue)));
+
+    // it is mapped again as the vector grows, at the same address
+    let base = vec.as_raw();
+    vec.extend_from_slice(&vec![4; GRANULARITY]).unwrap();
+    assert_eq!(vec.capacity(), 2 * GRANULARITY);
+    assert_eq!(vec.as_raw(), base);
+    assert_eq!(unmapped.load().unwrap(), vec![4]);
+
+    vec.truncate(0);
+    vec.shrink_to_fit().unwrap();
+    assert_eq!(vec.capacity(), 0);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn peer_copy() {
+    let mut other = context();
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let other_handle = other.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+
+    let source = DeviceBox::new(&handle, &[1u32, 2, 3, 4]).unwrap();
+    let target = DeviceBox::<u32>::alloc(&other_handle, 4).unwrap();
+    source.copy_to(&target).unwrap();
+    assert_eq!(target.load().unwrap(), vec![1, 2, 3, 4]);
+
+    let target = DeviceBox::<u32>::alloc(&other_handle, 4).unwrap();
+    source.copy_to_stream(&target, &mut stream).unwrap();
+    stream.sync().unwrap();
+    assert_eq!(target.load().unwrap(), vec![1, 2, 3, 4]);
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use common::{context, mock, ADD_PTX};
+use cuda_oxide::{backend::MockBackend, *};
+
+#[test]
+fn device() {
+    mock();
+    let devices = Cuda::list_devices().unwrap();
+    assert_eq!(devices.len(), 1);
+    assert_eq!(devices[0].name().unwrap(), "cuda-oxide mock device");
+    assert_eq!(
+        devices[0].compute_capability().unwrap(),
+        CudaVersion::from((8, 0))
+    );
+    assert_eq!(devices[0].memory_size().unwrap(), 1 << 30);
+}
+
+#[test]
+fn copy_and_memset() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let a = DeviceBox::new(&handle, &[1u32, 2, 3, 4]).unwrap();
+    let b = DeviceBox::<u32>::alloc(&handle, 4).unwrap();
+    assert_eq!(b.load().unwrap(), vec![0; 4]);
+    a.copy_to(&b).unwrap();
+    assert_eq!(b.load().unwrap(), vec![1, 2, 3, 4]);
+    b.subslice(1, 3).memset_d32(7).unwrap();
+    assert_eq!(b.load().unwrap(), vec![1, 7, 7, 4]);
+    b.memset_d8(1).unwrap();
+    assert_eq!(b.load().unwrap(), vec![0x01010101; 4]);
+}
+
+#[test]
+fn stream_deferred() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 4).unwrap();
+    buffer.memset_d32_stream(5, &mut stream).unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let callback_calls = calls.clone();
+    stream
+        .callback(move || {
+            callback_calls.fetch_add(1, Ordering::SeqCst);
+        })
+        .unwrap();
+
+    assert!(!stream.is_synced().unwrap());
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    stream.sync().unwrap();
+    assert!(stream.is_synced().unwrap());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(buffer.load().unwrap(), vec![5; 4]);
+}
+
+#[test]
+fn kernel_launch() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let module = Module::load(&handle, ADD_PTX).unwrap();
+    let function = module.get_function("add").unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::new(&handle, &[1u32; 64]).unwrap();
+
+    unsafe { stream.launch(&function, 2, 32, 0, (&buffer, 2u32)).unwrap() };
+    unsafe {
+        stream
+            .launch_checked(&function, 2, 32, 0, (&buffer, 3u32))
+            .unwrap()
+    };
+    unsafe {
+        stream
+            .launch_packed(&function, 2, 32, 0, (&buffer, 4u32))
+            .unwrap()
+    };
+    stream.sync().unwrap();
+    assert_eq!(buffer.load().unwrap(), vec![10; 64]);
+
+    assert!(matches!(
+        module.get_function("missing"),
+        Err(ErrorCode::NotFound)
+    ));
+    assert!(matches!(
+        unsafe { stream.launch(&function, 1, 2048, 0, (&buffer, 1u32)) },
+        Err(ErrorCode::InvalidValue)
+    ));
+}
+
+#[test]
+fn events() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let mut other = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 4).unwrap();
+    let event = Event::new(&handle).unwrap();
+
+    buffer.memset_d32_stream(9, &mut stream).unwrap();
+    stream.record(&event).unwrap();
+    assert!(!event.is_complete().unwrap());
+
+    // waiting on the event runs the recording stream up to it
+    other.wait_event(&event).unwrap();
+    other.sync().unwrap();
+    assert!(event.is_complete().unwrap());
+    assert!(stream.is_synced().unwrap());
+
+    let start = Event::new(&handle).unwrap();
+    stream.record(&start).unwrap();
+    stream.record(&event).unwrap();
+    event.sync().unwrap();
+    assert!(event.elapsed_since(&start).is_ok());
+    assert_eq!(buffer.load().unwrap(), vec![9; 4]);
+}
+
+#[test]
+fn backend_installed_once() {
+    mock();
+    assert!(matches!(
+        Cuda::set_backend(Box::new(MockBackend::new())),
+        Err(ErrorCode::IllegalState)
+    ));
+}
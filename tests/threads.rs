@@ -0,0 +1,190 @@
+mod common;
+
+use std::thread;
+
+use common::{context, mock, ADD_PTX};
+use cuda_oxide::{backend::Backend, *};
+
+/// Checks that no context is left current on the calling thread
+fn assert_no_current_context() {
+    assert_eq!(
+        unsafe { mock().cuCtxSynchronize() },
+        sys::cudaError_enum_CUDA_ERROR_INVALID_CONTEXT
+    );
+}
+
+/// Gets the context current on the calling thread, leaving it current
+fn current_context() -> sys::CUcontext {
+    let mut context = std::ptr::null_mut();
+    unsafe {
+        assert_eq!(
+            mock().cuCtxPopCurrent_v2(&mut context),
+            sys::cudaError_enum_CUDA_SUCCESS
+        );
+        assert_eq!(
+            mock().cuCtxPushCurrent_v2(context),
+            sys::cudaError_enum_CUDA_SUCCESS
+        );
+    }
+    context
+}
+
+fn assert_send<T: Send>() {}
+
+fn assert_sync<T: Sync>() {}
+
+#[test]
+fn send_and_sync() {
+    assert_send::<Context>();
+    assert_sync::<Context>();
+    assert_send::<std::sync::Arc<Handle<'static>>>();
+    assert_send::<DeviceBox<'static, u32>>();
+    assert_sync::<DeviceBox<'static, u32>>();
+    assert_send::<DeviceSlice<'static, u32>>();
+    assert_send::<Stream<'static>>();
+    assert_send::<Module<'static>>();
+    assert_sync::<Module<'static>>();
+    assert_send::<Event<'static>>();
+    assert_send::<CudaFuture<'static, Vec<u32>>>();
+}
+
+#[test]
+fn worker_pool() {
+    let mut context = context();
+    assert_no_current_context();
+    let handle = context.enter().unwrap();
+    let module = Module::load(&handle, ADD_PTX).unwrap();
+
+    thread::scope(|scope| {
+        for worker in 0..8u32 {
+            let handle = handle.clone();
+            let module = &module;
+            scope.spawn(move || {
+                let function = module.get_function("add").unwrap();
+                let mut stream = Stream::new(&handle).unwrap();
+                for job in 0..16u32 {
+                    let buffer = DeviceBox::new(&handle, &[worker; 64]).unwrap();
+                    unsafe {
+                        stream
+                            .launch_checked(&function, 2, 32, 0, (&buffer, job))
+                            .unwrap()
+                    };
+                    stream.sync().unwrap();
+                    assert_eq!(buffer.load().unwrap(), vec![worker + job; 64]);
+                }
+                assert_no_current_context();
+            });
+        }
+    });
+    assert_no_current_context();
+}
+
+#[test]
+fn move_between_threads() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let buffer = DeviceBox::<u32>::alloc(&handle, 4).unwrap();
+    let event = Event::new(&handle).unwrap();
+    buffer.memset_d32_stream(3, &mut stream).unwrap();
+    stream.record(&event).unwrap();
+
+    // work queued on one thread completes on another, and buffers come back
+    let (buffer, event) = thread::scope(|scope| {
+        scope
+            .spawn(move || {
+                event.sync().unwrap();
+                assert_eq!(buffer.load().unwrap(), vec![3; 4]);
+                buffer.memset_d32_stream(4, &mut stream).unwrap();
+                stream.record(&event).unwrap();
+                (buffer, event)
+            })
+            .join()
+            .unwrap()
+    });
+    event.sync().unwrap();
+    assert_eq!(buffer.load().unwrap(), vec![4; 4]);
+}
+
+#[test]
+fn push_current() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    {
+        let _current = handle.context().push_current().unwrap();
+        assert_eq!(
+            unsafe { mock().cuCtxSynchronize() },
+            sys::cudaError_enum_CUDA_SUCCESS
+        );
+        // operations restore the context that was current before them
+        DeviceBox::new(&handle, &[1u8]).unwrap();
+        assert_eq!(
+            unsafe { mock().cuCtxSynchronize() },
+            sys::cudaError_enum_CUDA_SUCCESS
+        );
+    }
+    assert_no_current_context();
+}
+
+#[test]
+fn scoped_threads_restore_current() {
+    let mut context = context();
+    let handle = context.enter().unwrap();
+    let _current = handle.context().push_current().unwrap();
+    let current = current_context();
+
+    // creating a context leaves the current one in place
+    let mut other = common::context();
+    assert_eq!(current_context(), current);
+    let other_handle = other.enter().unwrap();
+
+    let module = Module::load(&handle, ADD_PTX).unwrap();
+    let buffer = DeviceBox::new(&handle, &[0u32; 64]).unwrap();
+    let mut stream = Stream::new(&handle).unwrap();
+    let mut stream = thread::scope(|scope| {
+        let worker_handle = handle.clone();
+        let (module, buffer) = (&module, &buffer);
+        let worker = scope.spawn(move || {
+            let function = module.get_function("add").unwrap();
+            for _ in 0..16 {
+                let scratch = DeviceBox::new(&worker_handle, &[1u32; 64]).unwrap();
+                scratch.copy_to_stream(buffer, &mut stream).unwrap();
+                unsafe {
+                    stream
+                        .launch_checked(&function, 2, 32, 0, (buffer, 1u32))
+                        .unwrap()
+                };
+                stream.sync().unwrap();
+                assert_eq!(buffer.load().unwrap(), vec![2; 64]);
+            }
+            assert_no_current_context();
+            stream
+        });
+        let other_worker = scope.spawn(move || {
+            let mut stream = Stream::new(&other_handle).unwrap();
+            for i in 0..16u32 {
+                let buffer = DeviceBox::<u32>::alloc(&other_handle, 64).unwrap();
+                buffer.memset_d32_stream(i, &mut stream).unwrap();
+                stream.sync().unwrap();
+                assert_eq!(buffer.load().unwrap(), vec![i; 64]);
+            }
+            assert_no_current_context();
+        });
+
+        // operations on this thread meanwhile restore the context pushed here
+        for i in 0..16u32 {
+            let local = DeviceBox::new(&handle, &[i; 16]).unwrap();
+            assert_eq!(local.load().unwrap(), vec![i; 16]);
+            assert_eq!(current_context(), current);
+        }
+        other_worker.join().unwrap();
+        worker.join().unwrap()
+    });
+
+    assert_eq!(current_context(), current);
+    // the stream is usable again on this thread
+    buffer.memset_d32_stream(7, &mut stream).unwrap();
+    stream.sync().unwrap();
+    assert_eq!(buffer.load().unwrap(), vec![7; 64]);
+    assert_eq!(current_context(), current);
+}
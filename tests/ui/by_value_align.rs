@@ -0,0 +1,10 @@
+use cuda_oxide::{DeviceCopy, KernelParameters};
+
+#[derive(Clone, Copy, DeviceCopy, KernelParameters)]
+#[kernel_parameters(by_value)]
+#[repr(C, align(32))]
+struct Args {
+    data: [u32; 8],
+}
+
+fn main() {}
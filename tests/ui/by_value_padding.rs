@@ -0,0 +1,11 @@
+use cuda_oxide::{DeviceCopy, KernelParameters};
+
+#[derive(Clone, Copy, DeviceCopy, KernelParameters)]
+#[kernel_parameters(by_value)]
+#[repr(C)]
+struct Args {
+    enabled: u8,
+    count: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use cuda_oxide::KernelParameters;
+
+#[derive(Clone, Copy, KernelParameters)]
+#[kernel_parameters(by_value)]
+struct Args {
+    scale: f32,
+    count: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use cuda_oxide::{DeviceCopy, KernelParameters};
+
+#[derive(Clone, Copy, DeviceCopy, KernelParameters)]
+#[kernel_parameters(by_value)]
+#[repr(C)]
+struct Args {
+    data: [u8; 4097],
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use cuda_oxide::DeviceCopy;
+
+#[derive(Clone, Copy, DeviceCopy)]
+#[repr(u8)]
+enum Mode {
+    Fast,
+    Exact,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use cuda_oxide::DeviceCopy;
+
+#[derive(Clone, Copy, DeviceCopy)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use cuda_oxide::DeviceCopy;
+
+#[derive(Clone, Copy, DeviceCopy)]
+#[repr(C)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
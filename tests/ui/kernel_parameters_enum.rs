@@ -0,0 +1,9 @@
+use cuda_oxide::KernelParameters;
+
+#[derive(KernelParameters)]
+enum Args {
+    Scale(f32),
+    Offset(f32),
+}
+
+fn main() {}